@@ -0,0 +1,750 @@
+//! Generates typed bindings for every function in the abi of the forge artifacts in
+//! `contracts/out`: the function name and signature, a `params` builder taking the inputs as
+//! rust types, an `Args` struct holding them and a `decode` reading the return values. Each
+//! contract also gets a `*FunctionsInput` enum with a variant per function, the matching
+//! `*FunctionsOutput` and the `ContractFunctionProcessor` impl running the calls, so the
+//! wrappers follow the abi whenever the contracts submodule is rebuilt.
+//! The output is included by `src/utils/functions/generated.rs`.
+//!
+//! Missing artifacts fail the build. Run `forge build` in `contracts/` first.
+
+use serde_json::Value;
+use std::collections::HashSet;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Artifacts to generate bindings for, the same contracts the deployer knows about, with the
+/// `CradleContractIds` field of the deployed instance. Contracts deployed per asset, pool,
+/// listing or account have none and take the contract id with every call instead.
+const CONTRACTS: &[(&str, Option<&str>)] = &[
+    ("AccessController", Some("access_controller_contract_id")),
+    ("AssetFactory", Some("asset_factory")),
+    ("AssetLendingPool", None),
+    ("AssetManager", None),
+    ("BridgedAssetIssuer", None),
+    ("CradleAccount", None),
+    (
+        "CradleAccountFactory",
+        Some("cradle_account_factory_contract_id"),
+    ),
+    (
+        "CradleListingFactory",
+        Some("cradle_listing_factory_contract_id"),
+    ),
+    ("CradleNativeListing", None),
+    (
+        "CradleOrderBookSettler",
+        Some("cradle_order_book_settler_contract_id"),
+    ),
+    ("LendingPoolFactory", Some("asset_lending_pool_factory")),
+    ("NativeAssetIssuer", None),
+];
+
+const RESERVED: &[&str] = &[
+    "params", "result", "as", "async", "await", "box", "break", "const", "continue", "crate",
+    "dyn", "else", "enum", "extern", "false", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "match", "mod", "move", "mut", "override", "pub", "ref", "return", "self", "static",
+    "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while", "yield",
+];
+
+/// Fields `Args` has besides the abi inputs.
+const ARGS_FIELDS: &[&str] = &["contract_id", "payable_amount"];
+
+fn main() {
+    let artifacts = Path::new("contracts").join("out");
+
+    // A path that doesn't exist would make cargo run this script on every build.
+    if artifacts.exists() {
+        println!("cargo:rerun-if-changed={}", artifacts.display());
+    }
+
+    let mut code = String::new();
+    let mut functions = Vec::new();
+
+    for (name, contract_id) in CONTRACTS {
+        let artifact = artifacts
+            .join(format!("{}.sol", name))
+            .join(format!("{}.json", name));
+        if artifact.exists() {
+            println!("cargo:rerun-if-changed={}", artifact.display());
+        }
+
+        match load_abi(&artifact) {
+            Ok(abi) => code.push_str(&contract_module(name, *contract_id, &abi, &mut functions)),
+            Err(e) => panic!(
+                "no abi for {}: {}. Run `forge build` in contracts/ to build the artifacts",
+                name, e
+            ),
+        }
+    }
+
+    writeln!(
+        code,
+        "/// Artifacts of the deployed contracts, the ones bindings are generated for."
+    )
+    .unwrap();
+    let names: Vec<&str> = CONTRACTS.iter().map(|(name, _)| *name).collect();
+    writeln!(code, "pub const CONTRACTS: &[&str] = &{:?};", names).unwrap();
+    writeln!(
+        code,
+        "/// Signature and json abi inputs of every function above."
+    )
+    .unwrap();
+    writeln!(code, "pub const FUNCTIONS: &[(&str, &str)] = &[").unwrap();
+    for function in functions {
+        writeln!(code, "    ({0}::SIGNATURE, {0}::INPUTS),", function).unwrap();
+    }
+    writeln!(code, "];").unwrap();
+
+    let out = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set"));
+    fs::write(out.join("contracts.rs"), code).expect("failed to write generated contracts");
+}
+
+fn load_abi(path: &Path) -> Result<Vec<Value>, String> {
+    let data = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let artifact: Value = serde_json::from_str(&data).map_err(|e| e.to_string())?;
+
+    artifact
+        .get("abi")
+        .and_then(|abi| abi.as_array())
+        .cloned()
+        .ok_or_else(|| format!("no abi in {}", path.display()))
+}
+
+struct Param {
+    name: String,
+    kind: String,
+}
+
+/// What the contract enums need to know about a generated function module.
+struct Binding {
+    module: String,
+    variant: String,
+    query: bool,
+    payable: bool,
+    decoded: bool,
+}
+
+fn contract_module(
+    name: &str,
+    contract_id: Option<&str>,
+    abi: &[Value],
+    functions: &mut Vec<String>,
+) -> String {
+    let mut modules = HashSet::new();
+    let mut bindings = Vec::new();
+    let mut code = String::new();
+
+    let contract = snake_case(name);
+    writeln!(code, "pub mod {} {{", contract).unwrap();
+    writeln!(code, "    #[allow(unused_imports)]").unwrap();
+    writeln!(code, "    use crate::utils::functions::generated::*;\n").unwrap();
+    writeln!(code, "    pub const ARTIFACT: &str = {:?};", name).unwrap();
+
+    for item in abi.iter().filter(|item| item["type"] == "function") {
+        let function = item["name"].as_str().unwrap_or_default();
+
+        let base = identifier(snake_case(function));
+        let mut module = base.clone();
+        let mut index = 2;
+        while !modules.insert(module.clone()) {
+            module = format!("{}_{}", base, index);
+            index += 1;
+        }
+
+        let (function_code, binding) =
+            function_module(&module, function, item, contract_id.is_none());
+        code.push_str(&function_code);
+        bindings.extend(binding);
+        functions.push(format!("{}::{}", contract, module));
+    }
+
+    if !bindings.is_empty() {
+        code.push_str(&contract_enums(name, contract_id, &bindings));
+    }
+
+    writeln!(code, "}}\n").unwrap();
+
+    code
+}
+
+/// The `*FunctionsInput` and `*FunctionsOutput` enums of a contract and the processor running
+/// each variant as a query or a transaction, going by the abi state mutability.
+fn contract_enums(name: &str, contract_id: Option<&str>, bindings: &[Binding]) -> String {
+    let input = format!("{}FunctionsInput", name);
+    let output = format!("{}FunctionsOutput", name);
+    let mut code = String::new();
+
+    writeln!(code).unwrap();
+    writeln!(
+        code,
+        "    /// Every function of `{}` whose arguments the sdk can encode.",
+        name
+    )
+    .unwrap();
+    writeln!(code, "    #[derive(Serialize, Deserialize, Clone, Debug)]").unwrap();
+    writeln!(code, "    pub enum {} {{", input).unwrap();
+    for binding in bindings {
+        writeln!(
+            code,
+            "        {}({}::Args),",
+            binding.variant, binding.module
+        )
+        .unwrap();
+    }
+    writeln!(code, "    }}\n").unwrap();
+
+    writeln!(code, "    #[derive(Serialize, Deserialize, Clone, Debug)]").unwrap();
+    writeln!(code, "    pub enum {} {{", output).unwrap();
+    for binding in bindings {
+        let returned = if binding.decoded {
+            format!("{}::Output", binding.module)
+        } else {
+            "()".to_string()
+        };
+        writeln!(
+            code,
+            "        {}(FunctionCallOutput<{}>),",
+            binding.variant, returned
+        )
+        .unwrap();
+    }
+    writeln!(code, "    }}\n").unwrap();
+
+    writeln!(code, "    impl {} {{", input).unwrap();
+    writeln!(code, "        /// Solidity name of the function called.").unwrap();
+    writeln!(
+        code,
+        "        pub fn function_name(&self) -> &'static str {{"
+    )
+    .unwrap();
+    writeln!(code, "            match self {{").unwrap();
+    for binding in bindings {
+        writeln!(
+            code,
+            "                Self::{}(_) => {}::NAME,",
+            binding.variant, binding.module
+        )
+        .unwrap();
+    }
+    writeln!(code, "            }}").unwrap();
+    writeln!(code, "        }}\n").unwrap();
+
+    let queries: Vec<String> = bindings
+        .iter()
+        .filter(|binding| binding.query)
+        .map(|binding| format!("Self::{}(_)", binding.variant))
+        .collect();
+    writeln!(
+        code,
+        "        /// Whether the call only queries, submitting no transaction."
+    )
+    .unwrap();
+    writeln!(code, "        pub fn is_query(&self) -> bool {{").unwrap();
+    if queries.is_empty() {
+        writeln!(code, "            false").unwrap();
+    } else {
+        writeln!(code, "            matches!(self, {})", queries.join(" | ")).unwrap();
+    }
+    writeln!(code, "        }}\n").unwrap();
+
+    writeln!(
+        code,
+        "        /// The call as a request to the deployed contract, before any gas policy."
+    )
+    .unwrap();
+    match contract_id {
+        Some(field) => {
+            writeln!(
+                code,
+                "        pub fn request(&self, wallet: &ActionWallet) \
+                 -> CradleResult<ContractCallRequest> {{"
+            )
+            .unwrap();
+            writeln!(
+                code,
+                "            let contract_id = wallet.get_contract_ids()?.{};",
+                field
+            )
+            .unwrap();
+            writeln!(
+                code,
+                "            let (params, payable_amount) = match self {{"
+            )
+            .unwrap();
+        }
+        None => {
+            writeln!(
+                code,
+                "        pub fn request(&self, _wallet: &ActionWallet) \
+                 -> CradleResult<ContractCallRequest> {{"
+            )
+            .unwrap();
+            writeln!(
+                code,
+                "            let (contract_id, params, payable_amount) = match self {{"
+            )
+            .unwrap();
+        }
+    }
+    for binding in bindings {
+        let target = match contract_id {
+            Some(_) => "",
+            None => "args.contract_id.to_contract_id()?, ",
+        };
+        let payable_amount = if binding.payable {
+            "args.payable_amount"
+        } else {
+            "0"
+        };
+        writeln!(
+            code,
+            "                Self::{}(args) => ({}args.params(), {}),",
+            binding.variant, target, payable_amount
+        )
+        .unwrap();
+    }
+    writeln!(code, "            }};\n").unwrap();
+    writeln!(code, "            Ok(call_request(").unwrap();
+    writeln!(code, "                contract_id,").unwrap();
+    writeln!(code, "                self.function_name(),").unwrap();
+    writeln!(code, "                &params,").unwrap();
+    writeln!(code, "                self.is_query(),").unwrap();
+    writeln!(code, "                payable_amount,").unwrap();
+    writeln!(code, "            ))").unwrap();
+    writeln!(code, "        }}").unwrap();
+    writeln!(code, "    }}\n").unwrap();
+
+    writeln!(
+        code,
+        "    impl ContractFunctionProcessor<{}> for {} {{",
+        output, input
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "        async fn process(&self, wallet: &mut ActionWallet) -> CradleResult<{}> {{",
+        output
+    )
+    .unwrap();
+    writeln!(code, "            let request = self.request(wallet)?;\n").unwrap();
+    writeln!(code, "            Ok(match self {{").unwrap();
+    for binding in bindings {
+        let call = match (binding.query, binding.decoded) {
+            (true, true) => format!("query(wallet, &request, {}::decode)", binding.module),
+            (true, false) => "query(wallet, &request, |_| Ok(()))".to_string(),
+            (false, true) => format!(
+                "execute_decoded(wallet, &request, {}::decode)",
+                binding.module
+            ),
+            (false, false) => "execute(wallet, &request)".to_string(),
+        };
+        writeln!(
+            code,
+            "                Self::{0}(_) => {1}::{0}({2}.await?),",
+            binding.variant, output, call
+        )
+        .unwrap();
+    }
+    writeln!(code, "            }})").unwrap();
+    writeln!(code, "        }}").unwrap();
+    writeln!(code, "    }}").unwrap();
+
+    code
+}
+
+fn function_module(
+    module: &str,
+    function: &str,
+    item: &Value,
+    with_contract_id: bool,
+) -> (String, Option<Binding>) {
+    let inputs = params(&item["inputs"], "arg");
+    let outputs = params(&item["outputs"], "value");
+    let mutability = item["stateMutability"].as_str().unwrap_or_default();
+    let payable = mutability == "payable";
+    let query = mutability == "view" || mutability == "pure";
+
+    let types = |params: &[Param]| -> String {
+        params
+            .iter()
+            .map(|p| p.kind.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    let signature = format!("{}({})", function, types(&inputs));
+
+    let mut code = String::new();
+
+    writeln!(code).unwrap();
+    if outputs.is_empty() {
+        writeln!(code, "    /// `{}`", signature).unwrap();
+    } else {
+        writeln!(
+            code,
+            "    /// `{} returns ({})`",
+            signature,
+            types(&outputs)
+        )
+        .unwrap();
+    }
+    writeln!(code, "    pub mod {} {{", module).unwrap();
+    writeln!(code, "        #[allow(unused_imports)]").unwrap();
+    writeln!(code, "        use crate::utils::functions::generated::*;\n").unwrap();
+    writeln!(code, "        pub const NAME: &str = {:?};", function).unwrap();
+    writeln!(code, "        pub const SIGNATURE: &str = {:?};", signature).unwrap();
+    writeln!(code, "        pub const PAYABLE: bool = {};", payable).unwrap();
+    let abi_inputs = item
+        .get("inputs")
+        .cloned()
+        .unwrap_or(Value::Array(Vec::new()));
+    writeln!(
+        code,
+        "        pub const INPUTS: &str = {:?};",
+        abi_inputs.to_string()
+    )
+    .unwrap();
+
+    let encoders: Option<Vec<(String, String, &str, &str)>> = inputs
+        .iter()
+        .map(|param| {
+            let (rust_type, field_type, encode) = input_type(&param.kind)?;
+            Some((
+                format!("{}: {}", param.name, rust_type),
+                encode.replace("{}", &param.name),
+                rust_type,
+                field_type,
+            ))
+        })
+        .collect();
+
+    let mut with_args = false;
+    if let Some(encoders) = encoders {
+        let args: Vec<&str> = encoders.iter().map(|(arg, ..)| arg.as_str()).collect();
+
+        writeln!(code).unwrap();
+        if args.len() > 7 {
+            writeln!(code, "        #[allow(clippy::too_many_arguments)]").unwrap();
+        }
+        writeln!(
+            code,
+            "        pub fn params({}) -> ContractFunctionParameters {{",
+            args.join(", ")
+        )
+        .unwrap();
+        if encoders.is_empty() {
+            writeln!(code, "            ContractFunctionParameters::new()").unwrap();
+        } else {
+            writeln!(
+                code,
+                "            let mut params = ContractFunctionParameters::new();"
+            )
+            .unwrap();
+            for (_, encode, ..) in &encoders {
+                writeln!(code, "            {};", encode).unwrap();
+            }
+            writeln!(code, "            params").unwrap();
+        }
+        writeln!(code, "        }}").unwrap();
+
+        writeln!(code).unwrap();
+        writeln!(
+            code,
+            "        /// Arguments of `{}`, named as in the abi.",
+            function
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "        #[derive(Serialize, Deserialize, Clone, Debug)]"
+        )
+        .unwrap();
+        writeln!(code, "        pub struct Args {{").unwrap();
+        if with_contract_id {
+            writeln!(code, "            /// The deployed contract to call.").unwrap();
+            writeln!(code, "            pub contract_id: CradleAddress,").unwrap();
+        }
+        for (param, (.., owned)) in inputs.iter().zip(&encoders) {
+            writeln!(code, "            pub {}: {},", param.name, owned).unwrap();
+        }
+        if payable {
+            writeln!(
+                code,
+                "            /// Hbar sent with the call, in tinybars."
+            )
+            .unwrap();
+            writeln!(code, "            #[serde(default)]").unwrap();
+            writeln!(code, "            pub payable_amount: i64,").unwrap();
+        }
+        writeln!(code, "        }}\n").unwrap();
+
+        let fields: Vec<String> = inputs
+            .iter()
+            .zip(&encoders)
+            .map(|(param, (_, _, rust_type, _))| {
+                if rust_type.starts_with('&') {
+                    format!("&self.{}", param.name)
+                } else {
+                    format!("self.{}", param.name)
+                }
+            })
+            .collect();
+        writeln!(code, "        impl Args {{").unwrap();
+        writeln!(
+            code,
+            "            pub fn params(&self) -> ContractFunctionParameters {{"
+        )
+        .unwrap();
+        writeln!(code, "                params({})", fields.join(", ")).unwrap();
+        writeln!(code, "            }}").unwrap();
+        writeln!(code, "        }}").unwrap();
+
+        with_args = true;
+    }
+
+    let decoders: Option<Vec<(&str, String)>> = outputs
+        .iter()
+        .enumerate()
+        .map(|(index, param)| {
+            let (rust_type, decode) = output_type(&param.kind)?;
+            Some((rust_type, decode.replace("{}", &index.to_string())))
+        })
+        .collect();
+
+    let mut decoded = false;
+    if let Some(decoders) = decoders.filter(|decoders| !decoders.is_empty()) {
+        writeln!(code).unwrap();
+        let value = if decoders.len() == 1 {
+            writeln!(code, "        pub type Output = {};\n", decoders[0].0).unwrap();
+            decoders[0].1.clone()
+        } else {
+            writeln!(
+                code,
+                "        /// Return values of `{}`, named as in the abi.",
+                function
+            )
+            .unwrap();
+            writeln!(
+                code,
+                "        #[derive(Serialize, Deserialize, Clone, Debug)]"
+            )
+            .unwrap();
+            writeln!(code, "        pub struct Output {{").unwrap();
+            for (param, (rust_type, _)) in outputs.iter().zip(&decoders) {
+                writeln!(code, "            pub {}: {},", param.name, rust_type).unwrap();
+            }
+            writeln!(code, "        }}\n").unwrap();
+
+            let fields: Vec<String> = outputs
+                .iter()
+                .zip(&decoders)
+                .map(|(param, (_, decode))| format!("{}: {}", param.name, decode))
+                .collect();
+            format!("Output {{ {} }}", fields.join(", "))
+        };
+
+        writeln!(code, "        #[allow(clippy::needless_question_mark)]").unwrap();
+        writeln!(
+            code,
+            "        pub fn decode(result: &ContractFunctionResult) -> CradleResult<Output> {{"
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "            let missing = |index| missing_value(SIGNATURE, index);"
+        )
+        .unwrap();
+        writeln!(code, "            Ok({})", value).unwrap();
+        writeln!(code, "        }}").unwrap();
+
+        decoded = true;
+    }
+
+    writeln!(code, "    }}").unwrap();
+
+    let binding = with_args.then(|| Binding {
+        module: module.to_string(),
+        variant: pascal_case(module),
+        query,
+        payable,
+        decoded,
+    });
+
+    (code, binding)
+}
+
+fn params(params: &Value, fallback: &str) -> Vec<Param> {
+    let params = params.as_array().map(|p| p.as_slice()).unwrap_or(&[]);
+    let mut names = HashSet::new();
+
+    params
+        .iter()
+        .enumerate()
+        .map(|(index, param)| {
+            let mut name = snake_case(param["name"].as_str().unwrap_or_default());
+            if name.is_empty() {
+                name = format!("{}{}", fallback, index);
+            }
+            name = identifier(name);
+            if !names.insert(name.clone()) {
+                name = format!("{}_{}", name, index);
+                names.insert(name.clone());
+            }
+
+            Param {
+                name,
+                kind: canonical(param),
+            }
+        })
+        .collect()
+}
+
+/// The abi type as it appears in signatures, tuples spelled out by their components.
+fn canonical(param: &Value) -> String {
+    let kind = param["type"].as_str().unwrap_or_default();
+
+    match kind.strip_prefix("tuple") {
+        Some(suffix) => {
+            let components = param["components"]
+                .as_array()
+                .map(|c| c.as_slice())
+                .unwrap_or(&[]);
+            let inner: Vec<String> = components.iter().map(canonical).collect();
+            format!("({}){}", inner.join(","), suffix)
+        }
+        None => kind.to_string(),
+    }
+}
+
+/// The rust argument type of an abi input, the type of its `Args` field and the statement
+/// adding it to `params`, with `{}` standing for the argument. `None` for types the sdk can't
+/// encode.
+fn input_type(kind: &str) -> Option<(&'static str, &'static str, &'static str)> {
+    let input = match kind {
+        "address" => (
+            "&CradleAddress",
+            "CradleAddress",
+            "params.add_address(&{}.to_evm_address())",
+        ),
+        "address[]" => (
+            "&[CradleAddress]",
+            "Vec<CradleAddress>",
+            "add_address_array(&mut params, {})",
+        ),
+        "bool" => ("bool", "bool", "params.add_bool({})"),
+        "string" => ("&str", "String", "params.add_string({})"),
+        "string[]" => ("&[String]", "Vec<String>", "params.add_string_array({})"),
+        "bytes" => ("&[u8]", "Vec<u8>", "params.add_bytes({})"),
+        "bytes32" => ("&[u8; 32]", "[u8; 32]", "params.add_bytes32({})"),
+        "uint8" => ("u8", "u8", "params.add_uint8({})"),
+        "uint32" => ("u32", "u32", "params.add_uint32({})"),
+        "uint64" => ("u64", "u64", "params.add_uint64({})"),
+        "uint64[]" => ("&[u64]", "Vec<u64>", "params.add_uint64_array({})"),
+        "uint256" => ("&U256", "U256", "params.add_uint256({}.to_biguint())"),
+        "uint256[]" => ("&[U256]", "Vec<U256>", "add_uint256_array(&mut params, {})"),
+        "int8" => ("i8", "i8", "params.add_int8({})"),
+        "int32" => ("i32", "i32", "params.add_int32({})"),
+        "int64" => ("i64", "i64", "params.add_int64({})"),
+        "int256" => ("&BigInt", "BigInt", "params.add_int256({}.clone())"),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+/// The rust type of an abi return value and the expression reading it from `result`, with `{}`
+/// standing for its index. `None` for types the sdk can't decode.
+fn output_type(kind: &str) -> Option<(&'static str, String)> {
+    let get = |getter: &str| format!("result.{}({{}}).ok_or_else(|| missing({{}}))?", getter);
+    let bits = |prefix: &str| -> Option<usize> {
+        kind.strip_prefix(prefix).map(|b| {
+            if b.is_empty() {
+                Some(256)
+            } else {
+                b.parse().ok()
+            }
+        })?
+    };
+
+    let output = match kind {
+        "address" => (
+            "CradleAddress",
+            format!(
+                "CradleAddress::from_solidity_address(&{})?",
+                get("get_address")
+            ),
+        ),
+        "address[]" => (
+            "Vec<CradleAddress>",
+            "address_array(result, {}).ok_or_else(|| missing({}))?".to_string(),
+        ),
+        "bool" => ("bool", get("get_bool")),
+        "string" => ("String", format!("{}.to_string()", get("get_str"))),
+        "string[]" => (
+            "Vec<String>",
+            format!(
+                "{}.into_iter().map(String::from).collect()",
+                get("get_str_array")
+            ),
+        ),
+        "bytes" => ("Vec<u8>", format!("{}.to_vec()", get("get_bytes"))),
+        "bytes32" => ("[u8; 32]", format!("*{}", get("get_bytes32"))),
+        _ => match (bits("uint"), bits("int")) {
+            (Some(8), _) => ("u8", get("get_u8")),
+            (Some(size), _) if size <= 32 => ("u32", get("get_u32")),
+            (Some(size), _) if size <= 64 => ("u64", get("get_u64")),
+            (Some(_), _) => ("U256", format!("U256::try_from({})?", get("get_u256"))),
+            (_, Some(size)) if size <= 64 => ("i64", get("get_i64")),
+            (_, Some(_)) => ("BigInt", get("get_i256")),
+            _ => return None,
+        },
+    };
+
+    Some(output)
+}
+
+/// Keywords and the names the generated functions use themselves get a trailing underscore.
+fn identifier(name: String) -> String {
+    if RESERVED.contains(&name.as_str()) || ARGS_FIELDS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+fn pascal_case(snake: &str) -> String {
+    snake
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.trim_start_matches('_').chars().collect();
+    let mut snake = String::new();
+
+    for (index, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_lower = chars.get(index + 1).is_some_and(|n| n.is_lowercase());
+
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
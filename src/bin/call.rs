@@ -0,0 +1,31 @@
+use anyhow::Result;
+use clap::Parser;
+use contract_integrator::cli::commands::CallArgs;
+use contract_integrator::utils::functions::dynamic::DynamicCallArgs;
+use contract_integrator::wallet::wallet::{ActionWallet, ActionWalletArgs};
+
+/// Calls any contract function through its artifact abi, e.g.
+/// `call --contract-id 0.0.1234 --artifact AssetLendingPool --function setReserveFactor
+/// --args '[1000]'`
+#[derive(Parser)]
+struct CallCli {
+    #[clap(flatten)]
+    call: CallArgs,
+    #[clap(flatten)]
+    wallet: ActionWalletArgs,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenv::dotenv().ok();
+    let cli = CallCli::parse();
+
+    let call_args = DynamicCallArgs::try_from(cli.call)?;
+    let mut wallet = ActionWallet::from_args(cli.wallet)?;
+
+    let output = wallet.call_dynamic(call_args).await?;
+
+    println!("{}", serde_json::to_string_pretty(&output)?);
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use clap::Parser;
+use contract_integrator::cli::CradleCli;
+use std::process::ExitCode;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    dotenv::dotenv().ok();
+    let cli = CradleCli::parse();
+
+    match cli.wallet() {
+        Ok(mut wallet) => cli.run(&mut wallet).await,
+        Err(error) => cli.fail(error),
+    }
+}
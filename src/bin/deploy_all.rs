@@ -2,18 +2,17 @@ use anyhow::Result;
 use chrono::Utc;
 use contract_integrator::utils::contract::Contract;
 use contract_integrator::utils::functions::asset_factory::{
-    AssetFactoryFunctionInput, AssetFactoryFunctionOutput, CreateAssetArgs,
+    AssetFactoryFunctionsInput, AssetFactoryFunctionsOutput, create_asset,
 };
 use contract_integrator::utils::functions::{ContractCallInput, ContractCallOutput};
 use contract_integrator::wallet::wallet::ActionWallet;
 use dialoguer::{Confirm, Input};
-use hedera::ContractId;
+use hedera::{ContractId, Hbar};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::str::FromStr;
 
 #[derive(Debug, Clone)]
 struct DeploymentStep {
@@ -483,19 +482,10 @@ async fn main() -> Result<()> {
 }
 
 async fn create_tokens(deployed_ids: &mut HashMap<String, String>) -> Result<(String, String)> {
-    let mut wallet = ActionWallet::from_env();
+    let mut wallet = ActionWallet::from_env()?;
 
-    // Get Access Controller contract ID from deployed contracts or env
-    let acl_contract_str: String = deployed_ids
-        .get("ACCESS_CONTROLLER_CONTRACT_ID")
-        .cloned()
-        .unwrap_or_else(|| env::var("ACCESS_CONTROLLER_CONTRACT_ID").unwrap_or_default());
-
-    // Parse string to ContractId and convert to Solidity address
-    let acl_contract_id = ContractId::from_str(&acl_contract_str)?;
-    let acl_contract_solidity = acl_contract_id.to_solidity_address()?;
-
-    let allow_list: u64 = 1;
+    // The factory pays the token creation out of the hbar sent with the call
+    let payable_amount = Hbar::new(50).to_tinybars();
 
     // Create Base Asset
     println!("┌─ Creating Base Asset Token ────────────────┐");
@@ -505,23 +495,23 @@ async fn create_tokens(deployed_ids: &mut HashMap<String, String>) -> Result<(St
     println!("  ⏳ Creating Base Asset...");
     let base_asset_result = wallet
         .execute(ContractCallInput::AssetFactory(
-            AssetFactoryFunctionInput::CreateAsset(CreateAssetArgs {
+            AssetFactoryFunctionsInput::CreateAsset(create_asset::Args {
                 name: base_name,
                 symbol: base_symbol,
-                acl_contract: acl_contract_solidity.clone(),
-                allow_list,
+                payable_amount,
             }),
         ))
         .await?;
 
     let base_asset_address =
-        if let ContractCallOutput::AssetFactory(AssetFactoryFunctionOutput::CreateAsset(output)) =
+        if let ContractCallOutput::AssetFactory(AssetFactoryFunctionsOutput::CreateAsset(output)) =
             base_asset_result
         {
             println!("  ✓ Base Asset created!");
             println!("  📋 Transaction ID: {}", output.transaction_id);
 
-            let asset_manager_address = output.output.as_ref().unwrap().asset_manager.clone();
+            let asset_manager_address =
+                output.output.as_ref().unwrap().asset_manager.to_solidity_address();
             println!("  🛠️  Asset Manager Address: {}", asset_manager_address);
 
             unsafe {
@@ -529,7 +519,7 @@ async fn create_tokens(deployed_ids: &mut HashMap<String, String>) -> Result<(St
                 deployed_ids.insert("BASE_ASSET_MANAGER".to_string(), asset_manager_address);
             }
 
-            output.output.unwrap().token
+            output.output.unwrap().token.to_solidity_address()
         } else {
             anyhow::bail!("Unexpected response from Asset Factory")
         };
@@ -544,23 +534,23 @@ async fn create_tokens(deployed_ids: &mut HashMap<String, String>) -> Result<(St
     println!("  ⏳ Creating Yield Asset...");
     let yield_asset_result = wallet
         .execute(ContractCallInput::AssetFactory(
-            AssetFactoryFunctionInput::CreateAsset(CreateAssetArgs {
+            AssetFactoryFunctionsInput::CreateAsset(create_asset::Args {
                 name: yield_name,
                 symbol: yield_symbol,
-                acl_contract: acl_contract_solidity.clone(),
-                allow_list,
+                payable_amount,
             }),
         ))
         .await?;
 
     let yield_asset_address =
-        if let ContractCallOutput::AssetFactory(AssetFactoryFunctionOutput::CreateAsset(output)) =
+        if let ContractCallOutput::AssetFactory(AssetFactoryFunctionsOutput::CreateAsset(output)) =
             yield_asset_result
         {
             println!("  ✓ Yield Asset created!");
             println!("  📋 Transaction ID: {}", output.transaction_id);
 
-            let asset_manager_address = output.output.as_ref().unwrap().asset_manager.clone();
+            let asset_manager_address =
+                output.output.as_ref().unwrap().asset_manager.to_solidity_address();
             println!("  🛠️  Asset Manager Address: {}", asset_manager_address);
 
             unsafe {
@@ -568,7 +558,7 @@ async fn create_tokens(deployed_ids: &mut HashMap<String, String>) -> Result<(St
                 deployed_ids.insert("YIELD_ASSET_MANAGER".to_string(), asset_manager_address);
             }
 
-            output.output.unwrap().token
+            output.output.unwrap().token.to_solidity_address()
         } else {
             anyhow::bail!("Unexpected response from Asset Factory")
         };
@@ -0,0 +1,139 @@
+use anyhow::{Result, anyhow};
+use clap::{Parser, Subcommand};
+use contract_integrator::utils::functions::ContractCallInput;
+use contract_integrator::wallet::keys::ThresholdKey;
+use contract_integrator::wallet::offline::{FreezeOptions, OfflineTransaction};
+use contract_integrator::wallet::signer::SignerArgs;
+use contract_integrator::wallet::wallet::{ActionWallet, ActionWalletArgs};
+use hedera::AccountId;
+use std::fs;
+use std::path::PathBuf;
+use time::OffsetDateTime;
+
+/// Offline signing of admin calls in three steps, each on its own machine if need be:
+///
+/// `offline freeze --call grant.json --out grant.tx.json --valid-start 1767225600`
+/// on a machine that knows the operator account but not its key,
+///
+/// `offline sign --file grant.tx.json` on the air-gapped machine holding the key, and
+///
+/// `offline submit --file grant.tx.json` anywhere online, before the transaction expires.
+///
+/// With `--required-signatures 2:<key>,<key>,<key>` the file is passed from signer to signer,
+/// and submit waits for two of them.
+#[derive(Parser)]
+struct OfflineArgs {
+    #[clap(subcommand)]
+    command: OfflineCommand,
+}
+
+#[derive(Subcommand)]
+enum OfflineCommand {
+    /// Builds and freezes the transaction of a json encoded `ContractCallInput`.
+    Freeze {
+        #[clap(long)]
+        call: PathBuf,
+        #[clap(long)]
+        out: PathBuf,
+        #[clap(long, env)]
+        operator_account_id: String,
+        #[clap(long, env)]
+        network: String,
+        /// Unix timestamp from which the transaction is valid, for three minutes. Defaults to
+        /// now.
+        #[clap(long)]
+        valid_start: Option<i64>,
+        /// Node to submit to, repeatable. Defaults to every node of the network.
+        #[clap(long = "node")]
+        nodes: Vec<AccountId>,
+        /// Threshold key whose signatures are collected, as `<threshold>:<key>,<key>,...`.
+        #[clap(long)]
+        required_signatures: Option<ThresholdKey>,
+    },
+    /// Checks the transaction, shows the call decoded from its bytes and adds a signature.
+    Sign {
+        #[clap(long)]
+        file: PathBuf,
+        #[clap(flatten)]
+        signer: SignerArgs,
+    },
+    /// Submits the signed transaction and prints the decoded output.
+    Submit {
+        #[clap(long)]
+        file: PathBuf,
+        #[clap(flatten)]
+        wallet: ActionWalletArgs,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenv::dotenv().ok();
+
+    match OfflineArgs::parse().command {
+        OfflineCommand::Freeze {
+            call,
+            out,
+            operator_account_id,
+            network,
+            valid_start,
+            nodes,
+            required_signatures,
+        } => {
+            let call: ContractCallInput = serde_json::from_str(&fs::read_to_string(call)?)?;
+            let valid_start = valid_start
+                .map(OffsetDateTime::from_unix_timestamp)
+                .transpose()
+                .map_err(|e| anyhow!("Invalid --valid-start: {}", e))?;
+
+            let wallet = ActionWallet::offline(operator_account_id, network.parse()?)?;
+            let offline = wallet
+                .freeze(
+                    call,
+                    FreezeOptions {
+                        valid_start,
+                        node_account_ids: (!nodes.is_empty()).then_some(nodes),
+                        required_signatures,
+                        ..FreezeOptions::default()
+                    },
+                )
+                .await?;
+
+            offline.save(&out)?;
+            println!(
+                "Froze {} as {} into {}",
+                offline.function_name,
+                offline.transaction_id,
+                out.display()
+            );
+        }
+        OfflineCommand::Sign { file, signer } => {
+            let mut offline = OfflineTransaction::load(&file)?;
+
+            println!("Network: {}", offline.network);
+            println!("Transaction: {}", offline.transaction_id);
+            println!("Contract: {}", offline.contract_id);
+            // Decoded from the bytes being signed, not from the recorded call
+            let (function, arguments) = offline.decoded_call()?;
+            println!("Function: {}", function);
+            println!("Arguments: {}", serde_json::to_string_pretty(&arguments)?);
+
+            offline.sign(signer.signer()?)?;
+            offline.save(&file)?;
+            println!("Signed by {}", offline.signed_by.join(", "));
+            match offline.missing_signatures()? {
+                0 => println!("Ready to submit"),
+                missing => println!("{} more signatures needed", missing),
+            }
+        }
+        OfflineCommand::Submit { file, wallet } => {
+            let offline = OfflineTransaction::load(&file)?;
+            let wallet = ActionWallet::from_args(wallet)?;
+
+            let output = wallet.submit_signed(&offline).await?;
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+    }
+
+    Ok(())
+}
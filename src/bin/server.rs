@@ -0,0 +1,19 @@
+use anyhow::Result;
+use clap::Parser;
+use contract_integrator::server::{ServerArgs, router};
+use tokio::net::TcpListener;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenv::dotenv().ok();
+    let args = ServerArgs::parse();
+
+    let address = args.address;
+    let state = args.state()?;
+
+    let listener = TcpListener::bind(address).await?;
+    println!("Listening on {}", address);
+    axum::serve(listener, router(state)).await?;
+
+    Ok(())
+}
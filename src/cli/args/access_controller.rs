@@ -0,0 +1,103 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::functions::access_controller::{
+    AccessControllerFunctionsInput, clear_level, get_level, grant_access, grant_access_batch,
+    has_access, revoke_access, rotate_level0_key,
+};
+use clap::{Args, Subcommand};
+
+#[derive(Args, Clone, Debug)]
+pub struct AccessControllerArgs {
+    #[clap(long)]
+    pub level: u64,
+    #[clap(long)]
+    pub account: CradleAddress,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GrantAccessBatchArgs {
+    #[clap(long)]
+    pub level: u64,
+    #[clap(long, value_delimiter = ',', required = true)]
+    pub accounts: Vec<CradleAddress>,
+}
+
+impl From<GrantAccessBatchArgs> for grant_access_batch::Args {
+    fn from(args: GrantAccessBatchArgs) -> Self {
+        Self {
+            level: args.level,
+            accounts: args.accounts,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct ClearLevelArgs {
+    #[clap(long)]
+    pub level: u64,
+}
+
+impl From<ClearLevelArgs> for clear_level::Args {
+    fn from(args: ClearLevelArgs) -> Self {
+        Self { level: args.level }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GetLevelArgs {
+    #[clap(long)]
+    pub level: u64,
+}
+
+impl From<GetLevelArgs> for get_level::Args {
+    fn from(args: GetLevelArgs) -> Self {
+        Self { level: args.level }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct RotateAdminArgs {
+    #[clap(long)]
+    pub old_key: CradleAddress,
+    #[clap(long)]
+    pub new_key: CradleAddress,
+}
+
+impl From<RotateAdminArgs> for rotate_level0_key::Args {
+    fn from(args: RotateAdminArgs) -> Self {
+        Self {
+            old_key: args.old_key,
+            new_key: args.new_key,
+        }
+    }
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum AccessControllerCommand {
+    HasAccess(AccessControllerArgs),
+    GrantAccess(AccessControllerArgs),
+    RevokeAccess(AccessControllerArgs),
+    GrantAccessBatch(GrantAccessBatchArgs),
+    ClearLevel(ClearLevelArgs),
+    GetLevel(GetLevelArgs),
+    RotateAdmin(RotateAdminArgs),
+}
+
+impl From<AccessControllerCommand> for AccessControllerFunctionsInput {
+    fn from(command: AccessControllerCommand) -> Self {
+        match command {
+            AccessControllerCommand::HasAccess(AccessControllerArgs { level, account }) => {
+                Self::HasAccess(has_access::Args { level, account })
+            }
+            AccessControllerCommand::GrantAccess(AccessControllerArgs { level, account }) => {
+                Self::GrantAccess(grant_access::Args { level, account })
+            }
+            AccessControllerCommand::RevokeAccess(AccessControllerArgs { level, account }) => {
+                Self::RevokeAccess(revoke_access::Args { level, account })
+            }
+            AccessControllerCommand::GrantAccessBatch(args) => Self::GrantAccessBatch(args.into()),
+            AccessControllerCommand::ClearLevel(args) => Self::ClearLevel(args.into()),
+            AccessControllerCommand::GetLevel(args) => Self::GetLevel(args.into()),
+            AccessControllerCommand::RotateAdmin(args) => Self::RotateLevel0Key(args.into()),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::utils::functions::asset_factory::{AssetFactoryFunctionsInput, create_asset};
+use clap::{Args, Subcommand};
+
+#[derive(Args, Clone, Debug)]
+pub struct CreateAssetArgs {
+    #[clap(long)]
+    pub name: String,
+    #[clap(long)]
+    pub symbol: String,
+    /// Hbar sent with the call, in tinybars. The factory pays the token creation from it.
+    #[clap(long, default_value_t = 5_000_000_000)]
+    pub payable_amount: i64,
+}
+
+impl From<CreateAssetArgs> for create_asset::Args {
+    fn from(args: CreateAssetArgs) -> Self {
+        Self {
+            name: args.name,
+            symbol: args.symbol,
+            payable_amount: args.payable_amount,
+        }
+    }
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum AssetFactoryCommand {
+    CreateAsset(CreateAssetArgs),
+}
+
+impl From<AssetFactoryCommand> for AssetFactoryFunctionsInput {
+    fn from(command: AssetFactoryCommand) -> Self {
+        match command {
+            AssetFactoryCommand::CreateAsset(args) => Self::CreateAsset(args.into()),
+        }
+    }
+}
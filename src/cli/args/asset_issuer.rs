@@ -0,0 +1,138 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::U256;
+use crate::utils::functions::{bridged_asset_issuer, native_asset_issuer};
+use clap::{Args, Subcommand};
+
+#[derive(Args, Clone, Debug)]
+pub struct CreateAssetArgs {
+    #[clap(long)]
+    pub name: String,
+    #[clap(long)]
+    pub symbol: String,
+    #[clap(long)]
+    pub acl_contract: CradleAddress,
+    #[clap(long)]
+    pub allow_list: u64,
+    /// Hbar sent with the call, in tinybars. The issuer pays the token creation from it.
+    #[clap(long, default_value_t = 2_000_000_000)]
+    pub payable_amount: i64,
+    #[clap(long = "issuer")]
+    pub contract_id: CradleAddress,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct LockReservesArgs {
+    #[clap(long)]
+    pub user: CradleAddress,
+    #[clap(long)]
+    pub amount: U256,
+    #[clap(long = "issuer")]
+    pub contract_id: CradleAddress,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct ReleaseAssetArgs {
+    #[clap(long)]
+    pub user: CradleAddress,
+    #[clap(long)]
+    pub symbol: String,
+    #[clap(long)]
+    pub mint_amount: U256,
+    #[clap(long)]
+    pub unlock_amount: U256,
+    #[clap(long = "issuer")]
+    pub contract_id: CradleAddress,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct LockAssetArgs {
+    #[clap(long)]
+    pub user: CradleAddress,
+    #[clap(long)]
+    pub asset: CradleAddress,
+    #[clap(long)]
+    pub amount: U256,
+    #[clap(long = "issuer")]
+    pub contract_id: CradleAddress,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct ReleaseReservesArgs {
+    #[clap(long)]
+    pub user: CradleAddress,
+    #[clap(long)]
+    pub symbol: String,
+    #[clap(long)]
+    pub burn_amount: U256,
+    #[clap(long)]
+    pub release_amount: U256,
+    #[clap(long = "issuer")]
+    pub contract_id: CradleAddress,
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum AssetIssuerCommand {
+    CreateAsset(CreateAssetArgs),
+    LockReserves(LockReservesArgs),
+    ReleaseAsset(ReleaseAssetArgs),
+    LockAsset(LockAssetArgs),
+    ReleaseReserves(ReleaseReservesArgs),
+}
+
+// The bridged and native issuers share their abi but get separate generated bindings.
+macro_rules! issuer_input {
+    ($issuer:ident, $input:ident) => {
+        impl From<AssetIssuerCommand> for $issuer::$input {
+            fn from(command: AssetIssuerCommand) -> Self {
+                use $issuer::*;
+
+                match command {
+                    AssetIssuerCommand::CreateAsset(args) => {
+                        Self::CreateAsset(create_asset::Args {
+                            contract_id: args.contract_id,
+                            name: args.name,
+                            symbol: args.symbol,
+                            acl_contract: args.acl_contract,
+                            allow_list: args.allow_list,
+                            payable_amount: args.payable_amount,
+                        })
+                    }
+                    AssetIssuerCommand::LockReserves(args) => {
+                        Self::LockReserves(lock_reserves::Args {
+                            contract_id: args.contract_id,
+                            user: args.user,
+                            amount: args.amount,
+                        })
+                    }
+                    AssetIssuerCommand::ReleaseAsset(args) => {
+                        Self::ReleaseAsset(release_asset::Args {
+                            contract_id: args.contract_id,
+                            user: args.user,
+                            symbol: args.symbol,
+                            mint_amount: args.mint_amount,
+                            unlock_amount: args.unlock_amount,
+                        })
+                    }
+                    AssetIssuerCommand::LockAsset(args) => Self::LockAsset(lock_asset::Args {
+                        contract_id: args.contract_id,
+                        user: args.user,
+                        asset: args.asset,
+                        amount: args.amount,
+                    }),
+                    AssetIssuerCommand::ReleaseReserves(args) => {
+                        Self::ReleaseReserves(release_reserves::Args {
+                            contract_id: args.contract_id,
+                            user: args.user,
+                            symbol: args.symbol,
+                            burn_amount: args.burn_amount,
+                            release_amount: args.release_amount,
+                        })
+                    }
+                }
+            }
+        }
+    };
+}
+
+issuer_input!(bridged_asset_issuer, BridgedAssetIssuerFunctionsInput);
+issuer_input!(native_asset_issuer, NativeAssetIssuerFunctionsInput);
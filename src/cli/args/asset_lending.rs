@@ -0,0 +1,388 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::U256;
+use crate::utils::functions::asset_lending_pool::*;
+use clap::{Args, Subcommand};
+
+#[derive(Args, Clone, Debug)]
+pub struct CalculateCurrentDebtArgs {
+    #[clap(long)]
+    pub user_principal: U256,
+    #[clap(long)]
+    pub user_borrow_index: U256,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<CalculateCurrentDebtArgs> for calculate_current_debt::Args {
+    fn from(args: CalculateCurrentDebtArgs) -> Self {
+        Self {
+            user_principal: args.user_principal,
+            user_borrow_index: args.user_borrow_index,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct CalculateCurrentDepositArgs {
+    #[clap(long)]
+    pub user_shares: U256,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<CalculateCurrentDepositArgs> for calculate_current_deposit::Args {
+    fn from(args: CalculateCurrentDepositArgs) -> Self {
+        Self {
+            user_shares: args.user_shares,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct CalculateHealthFactorArgs {
+    #[clap(long)]
+    pub collateral_value: U256,
+    #[clap(long)]
+    pub borrowed_value: U256,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<CalculateHealthFactorArgs> for calculate_health_factor::Args {
+    fn from(args: CalculateHealthFactorArgs) -> Self {
+        Self {
+            collateral_value: args.collateral_value,
+            borrowed_value: args.borrowed_value,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct UpdateOracleArgs {
+    #[clap(long)]
+    pub asset: CradleAddress,
+    #[clap(long)]
+    pub multiplier: U256,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<UpdateOracleArgs> for update_oracle::Args {
+    fn from(args: UpdateOracleArgs) -> Self {
+        Self {
+            asset: args.asset,
+            multiplier: args.multiplier,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GetAssetMultiplierArgs {
+    #[clap(long)]
+    pub asset: CradleAddress,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<GetAssetMultiplierArgs> for get_multiplier::Args {
+    fn from(args: GetAssetMultiplierArgs) -> Self {
+        Self {
+            asset: args.asset,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GetUserDepositPositon {
+    #[clap(long)]
+    pub user: CradleAddress,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<GetUserDepositPositon> for get_user_deposit_position::Args {
+    fn from(args: GetUserDepositPositon) -> Self {
+        Self {
+            user: args.user,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GetUserBorrowPosition {
+    #[clap(long)]
+    pub user: CradleAddress,
+    #[clap(long)]
+    pub collateral_asset: CradleAddress,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<GetUserBorrowPosition> for get_user_borrow_position::Args {
+    fn from(args: GetUserBorrowPosition) -> Self {
+        Self {
+            user: args.user,
+            collateral_asset: args.collateral_asset,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GetMaxBorrowAmount {
+    #[clap(long)]
+    pub collateral_amount: U256,
+    #[clap(long)]
+    pub collateral_asset: CradleAddress,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<GetMaxBorrowAmount> for get_max_borrow_amount::Args {
+    fn from(args: GetMaxBorrowAmount) -> Self {
+        Self {
+            collateral_amount: args.collateral_amount,
+            collateral_asset: args.collateral_asset,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct IsPositionLiquidatableArgs {
+    #[clap(long)]
+    pub user: CradleAddress,
+    #[clap(long)]
+    pub collateral_asset: CradleAddress,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<IsPositionLiquidatableArgs> for is_position_liquidatable::Args {
+    fn from(args: IsPositionLiquidatableArgs) -> Self {
+        Self {
+            user: args.user,
+            collateral_asset: args.collateral_asset,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct DepositArgs {
+    #[clap(long)]
+    pub user: CradleAddress,
+    #[clap(long)]
+    pub amount: U256,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<DepositArgs> for deposit::Args {
+    fn from(args: DepositArgs) -> Self {
+        Self {
+            user: args.user,
+            amount: args.amount,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct WithdrawArgs {
+    #[clap(long)]
+    pub user: CradleAddress,
+    #[clap(long)]
+    pub yield_token_amount: U256,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<WithdrawArgs> for withdraw::Args {
+    fn from(args: WithdrawArgs) -> Self {
+        Self {
+            user: args.user,
+            yield_token_amount: args.yield_token_amount,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct BorrowArgs {
+    #[clap(long)]
+    pub user: CradleAddress,
+    #[clap(long)]
+    pub collateral_amount: U256,
+    #[clap(long)]
+    pub collateral_asset: CradleAddress,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<BorrowArgs> for borrow::Args {
+    fn from(args: BorrowArgs) -> Self {
+        Self {
+            user: args.user,
+            collateral_amount: args.collateral_amount,
+            collateral_asset: args.collateral_asset,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct RepayArgs {
+    #[clap(long)]
+    pub user: CradleAddress,
+    #[clap(long)]
+    pub collateralized_asset: CradleAddress,
+    #[clap(long)]
+    pub repay_amount: U256,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<RepayArgs> for repay::Args {
+    fn from(args: RepayArgs) -> Self {
+        Self {
+            user: args.user,
+            collateralized_asset: args.collateralized_asset,
+            repay_amount: args.repay_amount,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct LiquidateArgs {
+    #[clap(long)]
+    pub liquidator: CradleAddress,
+    #[clap(long)]
+    pub borrower: CradleAddress,
+    #[clap(long)]
+    pub dept_to_cover: U256,
+    #[clap(long)]
+    pub collateral_asset: CradleAddress,
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+impl From<LiquidateArgs> for liquidate::Args {
+    fn from(args: LiquidateArgs) -> Self {
+        Self {
+            liquidator: args.liquidator,
+            borrower: args.borrower,
+            debt_to_cover: args.dept_to_cover,
+            collateral_asset: args.collateral_asset,
+            contract_id: args.contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct PoolArgs {
+    #[clap(long = "pool")]
+    pub contract_id: CradleAddress,
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum LendingCommand {
+    GetUtilization(PoolArgs),
+    GetBorrowRate(PoolArgs),
+    GetSupplyRate(PoolArgs),
+    UpdateBorrowIndex(PoolArgs),
+    UpdateSupplyIndex(PoolArgs),
+    UpdateIndices(PoolArgs),
+    CalculateCurrentDebt(CalculateCurrentDebtArgs),
+    CalculateCurrentDeposit(CalculateCurrentDepositArgs),
+    CalculateHealthFactor(CalculateHealthFactorArgs),
+    UpdateOracle(UpdateOracleArgs),
+    GetAssetMultiplier(GetAssetMultiplierArgs),
+    GetUserDepositPosition(GetUserDepositPositon),
+    GetUserBorrowPosition(GetUserBorrowPosition),
+    GetMaxBorrowAmount(GetMaxBorrowAmount),
+    IsPositionLiquidatable(IsPositionLiquidatableArgs),
+    GetPoolStats(PoolArgs),
+    Deposit(DepositArgs),
+    Withdraw(WithdrawArgs),
+    Borrow(BorrowArgs),
+    Repay(RepayArgs),
+    Liquidate(LiquidateArgs),
+    GetReserveAccount(PoolArgs),
+    GetTreasuryAccount(PoolArgs),
+}
+
+impl From<LendingCommand> for AssetLendingPoolFunctionsInput {
+    fn from(command: LendingCommand) -> Self {
+        use AssetLendingPoolFunctionsInput as Input;
+
+        match command {
+            LendingCommand::GetUtilization(pool) => Input::GetUtilization(get_utilization::Args {
+                contract_id: pool.contract_id,
+            }),
+            LendingCommand::GetBorrowRate(pool) => Input::GetBorrowRate(get_borrow_rate::Args {
+                contract_id: pool.contract_id,
+            }),
+            LendingCommand::GetSupplyRate(pool) => Input::GetSupplyRate(get_supply_rate::Args {
+                contract_id: pool.contract_id,
+            }),
+            LendingCommand::UpdateBorrowIndex(pool) => {
+                Input::UpdateBorrowIndex(update_borrow_index::Args {
+                    contract_id: pool.contract_id,
+                })
+            }
+            LendingCommand::UpdateSupplyIndex(pool) => {
+                Input::UpdateSupplyIndex(update_supply_index::Args {
+                    contract_id: pool.contract_id,
+                })
+            }
+            LendingCommand::UpdateIndices(pool) => Input::UpdateIndices(update_indices::Args {
+                contract_id: pool.contract_id,
+            }),
+            LendingCommand::CalculateCurrentDebt(args) => Input::CalculateCurrentDebt(args.into()),
+            LendingCommand::CalculateCurrentDeposit(args) => {
+                Input::CalculateCurrentDeposit(args.into())
+            }
+            LendingCommand::CalculateHealthFactor(args) => {
+                Input::CalculateHealthFactor(args.into())
+            }
+            LendingCommand::UpdateOracle(args) => Input::UpdateOracle(args.into()),
+            LendingCommand::GetAssetMultiplier(args) => Input::GetMultiplier(args.into()),
+            LendingCommand::GetUserDepositPosition(args) => {
+                Input::GetUserDepositPosition(args.into())
+            }
+            LendingCommand::GetUserBorrowPosition(args) => {
+                Input::GetUserBorrowPosition(args.into())
+            }
+            LendingCommand::GetMaxBorrowAmount(args) => Input::GetMaxBorrowAmount(args.into()),
+            LendingCommand::IsPositionLiquidatable(args) => {
+                Input::IsPositionLiquidatable(args.into())
+            }
+            LendingCommand::GetPoolStats(pool) => Input::GetPoolStats(get_pool_stats::Args {
+                contract_id: pool.contract_id,
+            }),
+            LendingCommand::Deposit(args) => Input::Deposit(args.into()),
+            LendingCommand::Withdraw(args) => Input::Withdraw(args.into()),
+            LendingCommand::Borrow(args) => Input::Borrow(args.into()),
+            LendingCommand::Repay(args) => Input::Repay(args.into()),
+            LendingCommand::Liquidate(args) => Input::Liquidate(args.into()),
+            LendingCommand::GetReserveAccount(pool) => {
+                Input::GetReserveAccount(get_reserve_account::Args {
+                    contract_id: pool.contract_id,
+                })
+            }
+            LendingCommand::GetTreasuryAccount(pool) => {
+                Input::GetTreasuryAccount(get_treasury_account::Args {
+                    contract_id: pool.contract_id,
+                })
+            }
+        }
+    }
+}
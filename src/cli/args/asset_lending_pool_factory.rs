@@ -0,0 +1,76 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::functions::lending_pool_factory::{
+    LendingPoolFactoryFunctionsInput, create_pool, get_pool,
+};
+use clap::{Args, Subcommand};
+
+#[derive(Args, Clone, Debug)]
+pub struct CreatePoolArgs {
+    #[clap(long)]
+    pub ltv: u64,
+    #[clap(long)]
+    pub optimal_utilization: u64,
+    #[clap(long)]
+    pub base_rate: u64,
+    #[clap(long)]
+    pub slope1: u64,
+    #[clap(long)]
+    pub slope2: u64,
+    #[clap(long)]
+    pub liquidation_threshold: u64,
+    #[clap(long)]
+    pub liquidation_discount: u64,
+    #[clap(long)]
+    pub reserve_factor: u64,
+    #[clap(long)]
+    pub lending: CradleAddress,
+    #[clap(long)]
+    pub yield_contract: CradleAddress,
+    #[clap(long)]
+    pub lending_pool: String,
+}
+
+impl From<CreatePoolArgs> for create_pool::Args {
+    fn from(args: CreatePoolArgs) -> Self {
+        Self {
+            ltv: args.ltv,
+            optimal_utilization: args.optimal_utilization,
+            base_rate: args.base_rate,
+            slope1: args.slope1,
+            slope2: args.slope2,
+            liquidation_threshold: args.liquidation_threshold,
+            liquidation_discount: args.liquidation_discount,
+            reserve_factor: args.reserve_factor,
+            lending: args.lending,
+            yield_contract: args.yield_contract,
+            lending_pool: args.lending_pool,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GetPoolByName {
+    #[clap(long)]
+    pub name: String,
+}
+
+impl From<GetPoolByName> for get_pool::Args {
+    fn from(args: GetPoolByName) -> Self {
+        Self { name: args.name }
+    }
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum AssetLendingPoolFactoryCommand {
+    CreatePool(CreatePoolArgs),
+    GetPool(GetPoolByName),
+}
+
+impl From<AssetLendingPoolFactoryCommand> for LendingPoolFactoryFunctionsInput {
+    fn from(command: AssetLendingPoolFactoryCommand) -> Self {
+        match command {
+            AssetLendingPoolFactoryCommand::CreatePool(args) => Self::CreatePool(args.into()),
+            AssetLendingPoolFactoryCommand::GetPool(args) => Self::GetPool(args.into()),
+        }
+    }
+}
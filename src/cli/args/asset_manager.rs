@@ -0,0 +1,158 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::functions::ContractCallInput;
+use crate::utils::functions::asset_manager::*;
+use clap::{Args, Subcommand};
+
+#[derive(Args, Clone, Debug)]
+pub struct MintArgs {
+    #[clap(long)]
+    pub asset_contract: CradleAddress,
+    #[clap(long)]
+    pub amount: u64,
+}
+
+impl From<MintArgs> for mint::Args {
+    fn from(args: MintArgs) -> Self {
+        Self {
+            contract_id: args.asset_contract,
+            amount: args.amount,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct BurnArgs {
+    #[clap(long)]
+    pub asset_contract: CradleAddress,
+    #[clap(long)]
+    pub amount: u64,
+}
+
+impl From<BurnArgs> for burn::Args {
+    fn from(args: BurnArgs) -> Self {
+        Self {
+            contract_id: args.asset_contract,
+            amount: args.amount,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct WipeArgs {
+    #[clap(long)]
+    pub asset_contract: CradleAddress,
+    #[clap(long)]
+    pub account: CradleAddress,
+    #[clap(long)]
+    pub amount: u64,
+}
+
+impl From<WipeArgs> for wipe::Args {
+    fn from(args: WipeArgs) -> Self {
+        Self {
+            contract_id: args.asset_contract,
+            account: args.account,
+            amount: args.amount,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct AirdropArgs {
+    #[clap(long)]
+    pub asset_contract: CradleAddress,
+    #[clap(long)]
+    pub target: CradleAddress,
+    #[clap(long)]
+    pub amount: u64,
+}
+
+impl From<AirdropArgs> for airdrop_tokens::Args {
+    fn from(args: AirdropArgs) -> Self {
+        Self {
+            contract_id: args.asset_contract,
+            target: args.target,
+            amount: args.amount,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct TransferArgs {
+    #[clap(long)]
+    pub asset_contract: CradleAddress,
+    #[clap(long)]
+    pub target: CradleAddress,
+    #[clap(long)]
+    pub amount: u64,
+}
+
+impl From<TransferArgs> for transfer_tokens::Args {
+    fn from(args: TransferArgs) -> Self {
+        Self {
+            contract_id: args.asset_contract,
+            target: args.target,
+            amount: args.amount,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GrantKycArgs {
+    #[clap(long)]
+    pub asset_manager: CradleAddress,
+    #[clap(long)]
+    pub target: CradleAddress,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct SelfAssociateArgs {
+    #[clap(long)]
+    pub asset_contract: CradleAddress,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct TokenAssociateArgs {
+    #[clap(long)]
+    pub token: CradleAddress,
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum AssetManagerCommand {
+    Mint(MintArgs),
+    Burn(BurnArgs),
+    Wipe(WipeArgs),
+    Airdrop(AirdropArgs),
+    /// Associates the operator account with the token of the asset manager.
+    SelfAssociate(SelfAssociateArgs),
+    GrantKyc(GrantKycArgs),
+    Transfer(TransferArgs),
+    /// Associates the operator account with a token directly, not through a contract.
+    TokenAssociate(TokenAssociateArgs),
+}
+
+impl From<AssetManagerCommand> for ContractCallInput {
+    fn from(command: AssetManagerCommand) -> Self {
+        use AssetManagerFunctionsInput as Input;
+
+        let input = match command {
+            AssetManagerCommand::Mint(args) => Input::Mint(args.into()),
+            AssetManagerCommand::Burn(args) => Input::Burn(args.into()),
+            AssetManagerCommand::Wipe(args) => Input::Wipe(args.into()),
+            AssetManagerCommand::Airdrop(args) => Input::AirdropTokens(args.into()),
+            AssetManagerCommand::SelfAssociate(args) => {
+                Input::SelfAssociate(self_associate::Args {
+                    contract_id: args.asset_contract,
+                })
+            }
+            AssetManagerCommand::GrantKyc(args) => Input::GrantKyc(grant_kyc::Args {
+                contract_id: args.asset_manager,
+                account: args.target,
+            }),
+            AssetManagerCommand::Transfer(args) => Input::TransferTokens(args.into()),
+            AssetManagerCommand::TokenAssociate(args) => return Self::TokenAssociate(args.token),
+        };
+
+        Self::AssetManager(input)
+    }
+}
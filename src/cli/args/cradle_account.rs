@@ -0,0 +1,298 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::U256;
+use crate::utils::functions::cradle_account::*;
+use clap::{ArgAction, Args, Subcommand};
+
+#[derive(Args, Clone, Debug)]
+pub struct AssociateTokenArgs {
+    #[clap(long)]
+    pub token: CradleAddress,
+    #[clap(long = "account")]
+    pub account_contract_id: CradleAddress,
+}
+
+impl From<AssociateTokenArgs> for associate_token::Args {
+    fn from(args: AssociateTokenArgs) -> Self {
+        Self {
+            token: args.token,
+            contract_id: args.account_contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct WithdrawArgs {
+    #[clap(long)]
+    pub asset: CradleAddress,
+    #[clap(long)]
+    pub amount: u64,
+    #[clap(long)]
+    pub to: CradleAddress,
+    #[clap(long = "account")]
+    pub account_contract_id: CradleAddress,
+}
+
+impl From<WithdrawArgs> for withdraw::Args {
+    fn from(args: WithdrawArgs) -> Self {
+        Self {
+            asset: args.asset,
+            amount: args.amount,
+            to: args.to,
+            contract_id: args.account_contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct UpdateBridgingStatusArgs {
+    #[clap(long, action = ArgAction::Set)]
+    pub new_status: bool,
+    #[clap(long = "account")]
+    pub account_contract_id: CradleAddress,
+}
+
+impl From<UpdateBridgingStatusArgs> for update_bridging_status::Args {
+    fn from(args: UpdateBridgingStatusArgs) -> Self {
+        Self {
+            new_status: args.new_status,
+            contract_id: args.account_contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct TransferAssetArgs {
+    #[clap(long)]
+    pub asset: CradleAddress,
+    #[clap(long)]
+    pub amount: U256,
+    #[clap(long)]
+    pub to: CradleAddress,
+    #[clap(long = "account")]
+    pub account_contract_id: CradleAddress,
+}
+
+impl From<TransferAssetArgs> for transfer_asset::Args {
+    fn from(args: TransferAssetArgs) -> Self {
+        Self {
+            asset: args.asset,
+            amount: args.amount,
+            to: args.to,
+            contract_id: args.account_contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GetTradableBalanceArgs {
+    #[clap(long)]
+    pub asset: CradleAddress,
+    #[clap(long = "account")]
+    pub account_contract_id: CradleAddress,
+}
+
+impl From<GetTradableBalanceArgs> for get_tradable_balance::Args {
+    fn from(args: GetTradableBalanceArgs) -> Self {
+        Self {
+            asset: args.asset,
+            contract_id: args.account_contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct LockAssetArgs {
+    #[clap(long)]
+    pub asset: CradleAddress,
+    #[clap(long)]
+    pub amount: U256,
+    #[clap(long = "account")]
+    pub account_contract_id: CradleAddress,
+}
+
+impl From<LockAssetArgs> for lock_asset::Args {
+    fn from(args: LockAssetArgs) -> Self {
+        Self {
+            asset: args.asset,
+            amount: args.amount,
+            contract_id: args.account_contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct UnLockAssetArgs {
+    #[clap(long)]
+    pub asset: CradleAddress,
+    #[clap(long)]
+    pub amount: U256,
+    #[clap(long = "account")]
+    pub account_contract_id: CradleAddress,
+}
+
+impl From<UnLockAssetArgs> for unlock_asset::Args {
+    fn from(args: UnLockAssetArgs) -> Self {
+        Self {
+            asset: args.asset,
+            amount: args.amount,
+            contract_id: args.account_contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct AddLoanLockArgs {
+    #[clap(long)]
+    pub lender: CradleAddress,
+    #[clap(long)]
+    pub collateral: CradleAddress,
+    #[clap(long)]
+    pub loan_amount: U256,
+    #[clap(long)]
+    pub collateral_amount: U256,
+    #[clap(long)]
+    pub borrow_index: U256,
+    #[clap(long = "account")]
+    pub account_contract_id: CradleAddress,
+}
+
+impl From<AddLoanLockArgs> for add_loan_lock::Args {
+    fn from(args: AddLoanLockArgs) -> Self {
+        Self {
+            lender: args.lender,
+            collateral: args.collateral,
+            loan_amount: args.loan_amount,
+            collateral_amount: args.collateral_amount,
+            borrow_index: args.borrow_index,
+            contract_id: args.account_contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GetLoanAmountArgs {
+    #[clap(long)]
+    pub lender: CradleAddress,
+    #[clap(long)]
+    pub collateral: CradleAddress,
+    #[clap(long = "account")]
+    pub account_contract_id: CradleAddress,
+}
+
+impl From<GetLoanAmountArgs> for get_loan_amount::Args {
+    fn from(args: GetLoanAmountArgs) -> Self {
+        Self {
+            lender: args.lender,
+            collateral: args.collateral,
+            contract_id: args.account_contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GetCollateralArgs {
+    #[clap(long)]
+    pub lender: CradleAddress,
+    #[clap(long)]
+    pub collateral: CradleAddress,
+    #[clap(long = "account")]
+    pub account_contract_id: CradleAddress,
+}
+
+impl From<GetCollateralArgs> for get_collateral::Args {
+    fn from(args: GetCollateralArgs) -> Self {
+        Self {
+            lender: args.lender,
+            collateral: args.collateral,
+            contract_id: args.account_contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GetLoanBlockIndexArgs {
+    #[clap(long)]
+    pub lender: CradleAddress,
+    #[clap(long)]
+    pub collateral: CradleAddress,
+    #[clap(long = "account")]
+    pub account_contract_id: CradleAddress,
+}
+
+impl From<GetLoanBlockIndexArgs> for get_loan_block_index::Args {
+    fn from(args: GetLoanBlockIndexArgs) -> Self {
+        Self {
+            lender: args.lender,
+            collateral: args.collateral,
+            contract_id: args.account_contract_id,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct RemoveLoanLockArgs {
+    #[clap(long)]
+    pub lender: CradleAddress,
+    #[clap(long)]
+    pub collateral: CradleAddress,
+    #[clap(long)]
+    pub loan_amount: U256,
+    #[clap(long)]
+    pub collateral_amount: U256,
+    #[clap(long)]
+    pub borrow_index: U256,
+    #[clap(long = "account")]
+    pub account_contract_id: CradleAddress,
+}
+
+impl From<RemoveLoanLockArgs> for remove_loan_lock::Args {
+    fn from(args: RemoveLoanLockArgs) -> Self {
+        Self {
+            lender: args.lender,
+            collateral: args.collateral,
+            loan_amount: args.loan_amount,
+            collateral_amount: args.collateral_amount,
+            borrow_index: args.borrow_index,
+            contract_id: args.account_contract_id,
+        }
+    }
+}
+
+// No deposit: deposits are made by the account owner from the frontend.
+#[derive(Subcommand, Clone, Debug)]
+pub enum CradleAccountCommand {
+    AssociateToken(AssociateTokenArgs),
+    Withdraw(WithdrawArgs),
+    UpdateBridgingStatus(UpdateBridgingStatusArgs),
+    TransferAsset(TransferAssetArgs),
+    GetTradableBalance(GetTradableBalanceArgs),
+    LockAsset(LockAssetArgs),
+    #[clap(name = "unlock-asset")]
+    UnLockAsset(UnLockAssetArgs),
+    AddLoanLock(AddLoanLockArgs),
+    GetLoanAmount(GetLoanAmountArgs),
+    GetCollateral(GetCollateralArgs),
+    GetLoanBlockIndex(GetLoanBlockIndexArgs),
+    RemoveLoanLock(RemoveLoanLockArgs),
+}
+
+impl From<CradleAccountCommand> for CradleAccountFunctionsInput {
+    fn from(command: CradleAccountCommand) -> Self {
+        match command {
+            CradleAccountCommand::AssociateToken(args) => Self::AssociateToken(args.into()),
+            CradleAccountCommand::Withdraw(args) => Self::Withdraw(args.into()),
+            CradleAccountCommand::UpdateBridgingStatus(args) => {
+                Self::UpdateBridgingStatus(args.into())
+            }
+            CradleAccountCommand::TransferAsset(args) => Self::TransferAsset(args.into()),
+            CradleAccountCommand::GetTradableBalance(args) => Self::GetTradableBalance(args.into()),
+            CradleAccountCommand::LockAsset(args) => Self::LockAsset(args.into()),
+            CradleAccountCommand::UnLockAsset(args) => Self::UnlockAsset(args.into()),
+            CradleAccountCommand::AddLoanLock(args) => Self::AddLoanLock(args.into()),
+            CradleAccountCommand::GetLoanAmount(args) => Self::GetLoanAmount(args.into()),
+            CradleAccountCommand::GetCollateral(args) => Self::GetCollateral(args.into()),
+            CradleAccountCommand::GetLoanBlockIndex(args) => Self::GetLoanBlockIndex(args.into()),
+            CradleAccountCommand::RemoveLoanLock(args) => Self::RemoveLoanLock(args.into()),
+        }
+    }
+}
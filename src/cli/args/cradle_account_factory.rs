@@ -0,0 +1,75 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::functions::cradle_account_factory::*;
+use clap::{Args, Subcommand};
+
+#[derive(Args, Clone, Debug)]
+pub struct CreateAccountInputArgs {
+    #[clap(long)]
+    pub controller: String,
+    #[clap(long)]
+    pub account_allow_list: u64,
+}
+
+impl From<CreateAccountInputArgs> for create_account::Args {
+    fn from(args: CreateAccountInputArgs) -> Self {
+        Self {
+            controller: args.controller,
+            account_allow_list: args.account_allow_list,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct CreateAccountForUserInputArgs {
+    #[clap(long)]
+    pub controller: String,
+    #[clap(long)]
+    pub user: CradleAddress,
+    #[clap(long)]
+    pub account_allow_list: u64,
+}
+
+impl From<CreateAccountForUserInputArgs> for create_account_for_user::Args {
+    fn from(args: CreateAccountForUserInputArgs) -> Self {
+        Self {
+            controller: args.controller,
+            user: args.user,
+            account_allow_list: args.account_allow_list,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GetAccountByControllerInputArgs {
+    #[clap(long)]
+    pub controller: String,
+}
+
+impl From<GetAccountByControllerInputArgs> for get_account_by_controller::Args {
+    fn from(args: GetAccountByControllerInputArgs) -> Self {
+        Self {
+            controller: args.controller,
+        }
+    }
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum CradleAccountFactoryCommand {
+    CreateAccount(CreateAccountInputArgs),
+    CreateAccountForUser(CreateAccountForUserInputArgs),
+    GetAccountByController(GetAccountByControllerInputArgs),
+}
+
+impl From<CradleAccountFactoryCommand> for CradleAccountFactoryFunctionsInput {
+    fn from(command: CradleAccountFactoryCommand) -> Self {
+        match command {
+            CradleAccountFactoryCommand::CreateAccount(args) => Self::CreateAccount(args.into()),
+            CradleAccountFactoryCommand::CreateAccountForUser(args) => {
+                Self::CreateAccountForUser(args.into())
+            }
+            CradleAccountFactoryCommand::GetAccountByController(args) => {
+                Self::GetAccountByController(args.into())
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::U256;
+use crate::utils::functions::cradle_native_listing::*;
+use clap::{Args, Subcommand, ValueEnum};
+
+#[derive(ValueEnum, Clone, Debug)]
+pub enum ListingStatus {
+    Pending,
+    Open,
+    Closed,
+    Paused,
+    Cancelled,
+}
+
+/// The listing contract takes the status as its enum index.
+impl From<ListingStatus> for u8 {
+    fn from(status: ListingStatus) -> Self {
+        match status {
+            ListingStatus::Pending => 0,
+            ListingStatus::Open => 1,
+            ListingStatus::Closed => 2,
+            ListingStatus::Paused => 3,
+            ListingStatus::Cancelled => 4,
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct PurchaseInputArgs {
+    #[clap(long)]
+    pub buyer: CradleAddress,
+    #[clap(long)]
+    pub amount: U256,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct ReturnAssetInputArgs {
+    #[clap(long)]
+    pub account: CradleAddress,
+    #[clap(long)]
+    pub amount: U256,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct WithdrawToBeneficiaryInputArgs {
+    #[clap(long)]
+    pub amount: U256,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct ListingArgs<Rest: Args> {
+    #[clap(long = "listing")]
+    pub contract_id: CradleAddress,
+    #[clap(flatten)]
+    pub rest: Rest,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct ListingStatusArgs {
+    #[clap(long, value_enum)]
+    pub status: ListingStatus,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct NoArgs {}
+
+#[derive(Args, Clone, Debug)]
+pub struct FeeArgs {
+    #[clap(long)]
+    pub amount: U256,
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum ListingCommand {
+    UpdateListingStatus(ListingArgs<ListingStatusArgs>),
+    Purchase(ListingArgs<PurchaseInputArgs>),
+    ReturnAsset(ListingArgs<ReturnAssetInputArgs>),
+    WithdrawToBeneficiary(ListingArgs<WithdrawToBeneficiaryInputArgs>),
+    GetListingStats(ListingArgs<NoArgs>),
+    GetFee(ListingArgs<FeeArgs>),
+}
+
+impl From<ListingCommand> for CradleNativeListingFunctionsInput {
+    fn from(command: ListingCommand) -> Self {
+        use CradleNativeListingFunctionsInput as Input;
+
+        match command {
+            ListingCommand::UpdateListingStatus(args) => {
+                Input::UpdateListingStatus(update_listing_status::Args {
+                    contract_id: args.contract_id,
+                    status: args.rest.status.into(),
+                })
+            }
+            ListingCommand::Purchase(args) => Input::Purchase(purchase::Args {
+                contract_id: args.contract_id,
+                buyer: args.rest.buyer,
+                amount: args.rest.amount,
+            }),
+            ListingCommand::ReturnAsset(args) => Input::ReturnAsset(return_asset::Args {
+                contract_id: args.contract_id,
+                account: args.rest.account,
+                amount: args.rest.amount,
+            }),
+            ListingCommand::WithdrawToBeneficiary(args) => {
+                Input::WithdrawToBeneficiary(withdraw_to_beneficiary::Args {
+                    contract_id: args.contract_id,
+                    amount: args.rest.amount,
+                })
+            }
+            ListingCommand::GetListingStats(args) => {
+                Input::GetListingStats(get_listing_stats::Args {
+                    contract_id: args.contract_id,
+                })
+            }
+            ListingCommand::GetFee(args) => Input::GetFee(get_fee::Args {
+                contract_id: args.contract_id,
+                amount: args.rest.amount,
+            }),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::U256;
+use crate::utils::functions::cradle_listing_factory::{
+    CradleListingFactoryFunctionsInput, create_listing,
+};
+use clap::{Args, Subcommand};
+
+#[derive(Args, Clone, Debug)]
+pub struct CreateListing {
+    #[clap(long)]
+    pub fee_collector_address: CradleAddress,
+    #[clap(long)]
+    pub reserve_account: CradleAddress,
+    #[clap(long)]
+    pub max_supply: U256,
+    #[clap(long)]
+    pub listing_asset: CradleAddress,
+    #[clap(long)]
+    pub purchase_asset: CradleAddress,
+    #[clap(long)]
+    pub purchase_price: U256,
+    #[clap(long)]
+    pub beneficiary_address: CradleAddress,
+    #[clap(long)]
+    pub shadow_asset: CradleAddress,
+}
+
+impl From<CreateListing> for create_listing::Args {
+    fn from(args: CreateListing) -> Self {
+        Self {
+            fee_collector: args.fee_collector_address,
+            reserve_account: args.reserve_account,
+            max_supply: args.max_supply,
+            listing_asset: args.listing_asset,
+            purchase_asset: args.purchase_asset,
+            purchase_price: args.purchase_price,
+            beneficiary: args.beneficiary_address,
+            shadow_asset: args.shadow_asset,
+        }
+    }
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum CradleListingFactoryCommand {
+    CreateListing(CreateListing),
+}
+
+impl From<CradleListingFactoryCommand> for CradleListingFactoryFunctionsInput {
+    fn from(command: CradleListingFactoryCommand) -> Self {
+        match command {
+            CradleListingFactoryCommand::CreateListing(args) => Self::CreateListing(args.into()),
+        }
+    }
+}
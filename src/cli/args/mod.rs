@@ -0,0 +1,11 @@
+pub mod access_controller;
+pub mod asset_factory;
+pub mod asset_issuer;
+pub mod asset_lending;
+pub mod asset_lending_pool_factory;
+pub mod asset_manager;
+pub mod cradle_account;
+pub mod cradle_account_factory;
+pub mod cradle_native_listing;
+pub mod listing_factory;
+pub mod orderbook_settler;
@@ -0,0 +1,48 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::U256;
+use crate::utils::functions::cradle_order_book_settler::{
+    CradleOrderBookSettlerFunctionsInput, settle_order,
+};
+use clap::{Args, Subcommand};
+
+#[derive(Args, Clone, Debug)]
+pub struct SettleOrderInputArgs {
+    #[clap(long)]
+    pub bidder: CradleAddress,
+    #[clap(long)]
+    pub asker: CradleAddress,
+    #[clap(long)]
+    pub bid_asset: CradleAddress,
+    #[clap(long)]
+    pub ask_asset: CradleAddress,
+    #[clap(long)]
+    pub bid_asset_amount: U256,
+    #[clap(long)]
+    pub ask_asset_amount: U256,
+}
+
+impl From<SettleOrderInputArgs> for settle_order::Args {
+    fn from(args: SettleOrderInputArgs) -> Self {
+        Self {
+            bidder: args.bidder,
+            asker: args.asker,
+            bid_asset: args.bid_asset,
+            ask_asset: args.ask_asset,
+            bid_asset_amount: args.bid_asset_amount,
+            ask_asset_amount: args.ask_asset_amount,
+        }
+    }
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum OrderBookSettlerCommand {
+    SettleOrder(SettleOrderInputArgs),
+}
+
+impl From<OrderBookSettlerCommand> for CradleOrderBookSettlerFunctionsInput {
+    fn from(command: OrderBookSettlerCommand) -> Self {
+        match command {
+            OrderBookSettlerCommand::SettleOrder(args) => Self::SettleOrder(args.into()),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::cli::args::access_controller::AccessControllerCommand;
+use crate::cli::args::asset_factory::AssetFactoryCommand;
+use crate::cli::args::asset_issuer::AssetIssuerCommand;
+use crate::cli::args::asset_lending::LendingCommand;
+use crate::cli::args::asset_lending_pool_factory::AssetLendingPoolFactoryCommand;
+use crate::cli::args::asset_manager::AssetManagerCommand;
+use crate::cli::args::cradle_account::CradleAccountCommand;
+use crate::cli::args::cradle_account_factory::CradleAccountFactoryCommand;
+use crate::cli::args::cradle_native_listing::ListingCommand;
+use crate::cli::args::listing_factory::CradleListingFactoryCommand;
+use crate::cli::args::orderbook_settler::OrderBookSettlerCommand;
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::utils::functions::ContractCallInput;
+use crate::utils::functions::dynamic::DynamicCallArgs;
+use clap::{Args, Subcommand};
+use serde_json::Value;
+
+/// One subcommand per `ContractCallInput` variant, with a subcommand per function below it.
+#[derive(Subcommand, Clone, Debug)]
+pub enum ContractCommand {
+    #[clap(subcommand)]
+    Access(AccessControllerCommand),
+    #[clap(subcommand)]
+    AccountFactory(CradleAccountFactoryCommand),
+    #[clap(subcommand)]
+    BridgedIssuer(AssetIssuerCommand),
+    #[clap(subcommand)]
+    NativeIssuer(AssetIssuerCommand),
+    #[clap(subcommand)]
+    Lending(LendingCommand),
+    #[clap(subcommand)]
+    Account(CradleAccountCommand),
+    #[clap(subcommand)]
+    AssetManager(AssetManagerCommand),
+    #[clap(subcommand)]
+    AssetFactory(AssetFactoryCommand),
+    #[clap(subcommand)]
+    Settler(OrderBookSettlerCommand),
+    #[clap(subcommand)]
+    PoolFactory(AssetLendingPoolFactoryCommand),
+    #[clap(subcommand)]
+    ListingFactory(CradleListingFactoryCommand),
+    #[clap(subcommand)]
+    Listing(ListingCommand),
+    /// Any function of a compiled contract, through its artifact abi.
+    Call(CallArgs),
+}
+
+impl ContractCommand {
+    pub fn into_input(self) -> CradleResult<ContractCallInput> {
+        let input = match self {
+            ContractCommand::Access(command) => ContractCallInput::AccessController(command.into()),
+            ContractCommand::AccountFactory(command) => {
+                ContractCallInput::CradleAccountFactory(command.into())
+            }
+            ContractCommand::BridgedIssuer(command) => {
+                ContractCallInput::BridgedAssetIssuer(command.into())
+            }
+            ContractCommand::NativeIssuer(command) => {
+                ContractCallInput::NativeAssetIssuer(command.into())
+            }
+            ContractCommand::Lending(command) => {
+                ContractCallInput::AssetLendingPool(command.into())
+            }
+            ContractCommand::Account(command) => ContractCallInput::CradleAccount(command.into()),
+            ContractCommand::AssetManager(command) => command.into(),
+            ContractCommand::AssetFactory(command) => {
+                ContractCallInput::AssetFactory(command.into())
+            }
+            ContractCommand::Settler(command) => {
+                ContractCallInput::OrderBookSettler(command.into())
+            }
+            ContractCommand::PoolFactory(command) => {
+                ContractCallInput::AssetLendingPoolFactory(command.into())
+            }
+            ContractCommand::ListingFactory(command) => {
+                ContractCallInput::CradleListingFactory(command.into())
+            }
+            ContractCommand::Listing(command) => {
+                ContractCallInput::CradleNativeListing(command.into())
+            }
+            ContractCommand::Call(args) => ContractCallInput::Dynamic(args.try_into()?),
+        };
+
+        Ok(input)
+    }
+}
+
+/// Same arguments as the `call` binary.
+#[derive(Args, Clone, Debug)]
+pub struct CallArgs {
+    #[clap(long)]
+    pub contract_id: String,
+    #[clap(long)]
+    pub artifact: String,
+    #[clap(long)]
+    pub function: String,
+    /// Json array of arguments, in abi order.
+    #[clap(long, default_value = "[]")]
+    pub args: String,
+    /// Hbar sent with the call, in tinybars.
+    #[clap(long)]
+    pub payable_amount: Option<i64>,
+}
+
+impl TryFrom<CallArgs> for DynamicCallArgs {
+    type Error = CradleError;
+
+    fn try_from(call_args: CallArgs) -> CradleResult<Self> {
+        let args = match serde_json::from_str::<Value>(&call_args.args) {
+            Ok(Value::Array(args)) => args,
+            Ok(other) => {
+                return Err(CradleError::config(format!(
+                    "--args must be a json array, got {}",
+                    other
+                )));
+            }
+            Err(error) => return Err(CradleError::config(format!("--args: {}", error))),
+        };
+
+        Ok(DynamicCallArgs {
+            contract_id: call_args.contract_id,
+            artifact: call_args.artifact,
+            function: call_args.function,
+            args,
+            payable_amount: call_args.payable_amount,
+        })
+    }
+}
@@ -0,0 +1,167 @@
+use anyhow::Result;
+use dialoguer::{Input, Select};
+use crate::utils::address::CradleAddress;
+use crate::utils::functions::access_controller::{
+    AccessControllerFunctionsInput, AccessControllerFunctionsOutput, clear_level, get_level,
+    grant_access, grant_access_batch, has_access, revoke_access, rotate_level0_key,
+};
+use crate::utils::functions::{ContractCallInput, ContractCallOutput};
+use crate::wallet::wallet::ActionWallet;
+
+pub async fn run(wallet: &mut ActionWallet) -> Result<()> {
+    // Select which function to call
+    let function_selection = Select::new()
+        .with_prompt("Select Access Controller Function")
+        .items(&[
+            "Has Access",
+            "Grant Access",
+            "Revoke Access",
+            "Grant Access Batch",
+            "Clear Level",
+            "Get Level",
+            "Rotate Admin",
+        ])
+        .interact()?;
+
+    let input = match function_selection {
+        0 => {
+            // HasAccess
+            let level: u64 = Input::new()
+                .with_prompt("Access Level")
+                .interact()?;
+            let account: CradleAddress = Input::new()
+                .with_prompt("Account Address")
+                .interact()?;
+
+            ContractCallInput::AccessController(AccessControllerFunctionsInput::HasAccess(
+                has_access::Args { level, account },
+            ))
+        }
+        1 => {
+            // GrantAccess
+            let level: u64 = Input::new()
+                .with_prompt("Access Level to Grant")
+                .interact()?;
+            let account: CradleAddress = Input::new()
+                .with_prompt("Account Address")
+                .interact()?;
+
+            ContractCallInput::AccessController(AccessControllerFunctionsInput::GrantAccess(
+                grant_access::Args { level, account },
+            ))
+        }
+        2 => {
+            // RevokeAccess
+            let level: u64 = Input::new()
+                .with_prompt("Access Level to Revoke")
+                .interact()?;
+            let account: CradleAddress = Input::new()
+                .with_prompt("Account Address")
+                .interact()?;
+
+            ContractCallInput::AccessController(AccessControllerFunctionsInput::RevokeAccess(
+                revoke_access::Args { level, account },
+            ))
+        }
+        3 => {
+            // GrantAccessBatch
+            let level: u64 = Input::new()
+                .with_prompt("Access Level to Grant")
+                .interact()?;
+            let accounts_input: String = Input::new()
+                .with_prompt("Accounts (comma-separated)")
+                .interact()?;
+            let accounts = accounts_input
+                .split(',')
+                .map(|s| s.parse())
+                .collect::<Result<Vec<CradleAddress>, _>>()?;
+
+            ContractCallInput::AccessController(
+                AccessControllerFunctionsInput::GrantAccessBatch(grant_access_batch::Args {
+                    level,
+                    accounts,
+                }),
+            )
+        }
+        4 => {
+            // ClearLevel
+            let level: u64 = Input::new()
+                .with_prompt("Level to Clear")
+                .interact()?;
+
+            ContractCallInput::AccessController(AccessControllerFunctionsInput::ClearLevel(
+                clear_level::Args { level },
+            ))
+        }
+        5 => {
+            // GetLevel
+            let level: u64 = Input::new()
+                .with_prompt("Level to Query")
+                .interact()?;
+
+            ContractCallInput::AccessController(AccessControllerFunctionsInput::GetLevel(
+                get_level::Args { level },
+            ))
+        }
+        6 => {
+            // RotateAdmin
+            let old_key: CradleAddress = Input::new()
+                .with_prompt("Old Admin Key")
+                .interact()?;
+            let new_key: CradleAddress = Input::new()
+                .with_prompt("New Admin Key")
+                .interact()?;
+
+            ContractCallInput::AccessController(AccessControllerFunctionsInput::RotateLevel0Key(
+                rotate_level0_key::Args { old_key, new_key },
+            ))
+        }
+        _ => panic!("Invalid selection"),
+    };
+
+    // Execute the contract call
+    let res = wallet.execute(input).await?;
+
+    // Handle the response
+    match res {
+        ContractCallOutput::AccessController(output) => {
+            match output {
+                AccessControllerFunctionsOutput::HasAccess(result) => {
+                    println!("✓ Access Check Complete");
+                    if let Some(has_access) = result.output {
+                        println!("Has Access: {}", has_access);
+                    }
+                }
+                AccessControllerFunctionsOutput::GrantAccess(result) => {
+                    println!("✓ Access Granted");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AccessControllerFunctionsOutput::RevokeAccess(result) => {
+                    println!("✓ Access Revoked");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AccessControllerFunctionsOutput::GrantAccessBatch(result) => {
+                    println!("✓ Batch Access Granted");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AccessControllerFunctionsOutput::ClearLevel(result) => {
+                    println!("✓ Level Cleared");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AccessControllerFunctionsOutput::GetLevel(result) => {
+                    println!("✓ Level Retrieved");
+                    for account in result.output.unwrap_or_default() {
+                        println!("Account: {}", account);
+                    }
+                }
+                AccessControllerFunctionsOutput::RotateLevel0Key(result) => {
+                    println!("✓ Admin Rotated");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+            }
+        }
+        _ => println!("Unexpected contract response"),
+    }
+
+    Ok(())
+}
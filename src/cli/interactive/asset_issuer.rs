@@ -0,0 +1,210 @@
+use std::env;
+use anyhow::Result;
+use dialoguer::{Input, Select};
+use hedera::ContractId;
+use crate::utils::address::CradleAddress;
+use crate::cli::args::asset_issuer::{
+    AssetIssuerCommand, CreateAssetArgs, LockReservesArgs, ReleaseAssetArgs, LockAssetArgs,
+    ReleaseReservesArgs,
+};
+use crate::utils::functions::bridged_asset_issuer::BridgedAssetIssuerFunctionsOutput;
+use crate::utils::functions::native_asset_issuer::NativeAssetIssuerFunctionsOutput;
+use crate::utils::functions::{ContractCallInput, ContractCallOutput};
+use crate::utils::script_utils::prompt_token_amount;
+use crate::wallet::wallet::ActionWallet;
+
+pub async fn run(wallet: &mut ActionWallet) -> Result<()> {
+    // Select which asset issuer contract to interact with
+    let issuer_selection = Select::new()
+        .with_prompt("Select Asset Issuer Contract")
+        .items(&["Bridged Asset Issuer", "Native Asset Issuer"])
+        .interact()?;
+
+    let issuer_contract_id: ContractId = if issuer_selection == 0 {
+        env::var("BRIDGED_ASSET_ISSUER_CONTRACT_ID")?.parse()?
+    } else {
+        env::var("NATIVE_ASSET_ISSUER_CONTRACT_ID")?.parse()?
+    };
+
+    // Select which function to call
+    let function_selection = Select::new()
+        .with_prompt("Select Function")
+        .items(&[
+            "Create Asset",
+            "Lock Reserves",
+            "Release Asset",
+            "Lock Asset",
+            "Release Reserves",
+        ])
+        .interact()?;
+
+    let command = match function_selection {
+        0 => {
+            // CreateAsset
+            let name: String = Input::new()
+                .with_prompt("Asset Name")
+                .interact()?;
+            let symbol: String = Input::new()
+                .with_prompt("Asset Symbol")
+                .interact()?;
+            let acl_contract: ContractId = Input::new()
+                .with_prompt("ACL Contract ID")
+                .default(env::var("ACCESS_CONTROLLER_CONTRACT_ID").unwrap_or_default().parse()?)
+                .interact()?;
+            let allow_list: u64 = Input::new()
+                .with_prompt("Allow List Value")
+                .default(1)
+                .interact()?;
+            let payable_amount: i64 = Input::new()
+                .with_prompt("Payable Amount (tinybars)")
+                .default(2_000_000_000)
+                .interact()?;
+
+            AssetIssuerCommand::CreateAsset(CreateAssetArgs {
+                name,
+                symbol,
+                acl_contract: acl_contract.into(),
+                allow_list,
+                payable_amount,
+                contract_id: issuer_contract_id.into(),
+            })
+        }
+        1 => {
+            // LockReserves
+            let user: CradleAddress = Input::new()
+                .with_prompt("User Address")
+                .interact()?;
+            let amount = prompt_token_amount(wallet, "Amount to Lock", None).await?;
+
+            AssetIssuerCommand::LockReserves(LockReservesArgs {
+                user,
+                amount,
+                contract_id: issuer_contract_id.into(),
+            })
+        }
+        2 => {
+            // ReleaseAsset
+            let user: CradleAddress = Input::new()
+                .with_prompt("User Address")
+                .interact()?;
+            let symbol: String = Input::new()
+                .with_prompt("Asset Symbol")
+                .interact()?;
+            let mint_amount = prompt_token_amount(wallet, "Mint Amount", None).await?;
+            let unlock_amount = prompt_token_amount(wallet, "Unlock Amount", None).await?;
+
+            AssetIssuerCommand::ReleaseAsset(ReleaseAssetArgs {
+                user,
+                symbol,
+                mint_amount,
+                unlock_amount,
+                contract_id: issuer_contract_id.into(),
+            })
+        }
+        3 => {
+            // LockAsset
+            let user: CradleAddress = Input::new()
+                .with_prompt("User Address")
+                .interact()?;
+            let asset: CradleAddress = Input::new()
+                .with_prompt("Asset Address")
+                .interact()?;
+            let amount = prompt_token_amount(wallet, "Amount to Lock", Some(&asset)).await?;
+
+            AssetIssuerCommand::LockAsset(LockAssetArgs {
+                user,
+                asset,
+                amount,
+                contract_id: issuer_contract_id.into(),
+            })
+        }
+        4 => {
+            // ReleaseReserves
+            let user: CradleAddress = Input::new()
+                .with_prompt("User Address")
+                .interact()?;
+            let symbol: String = Input::new()
+                .with_prompt("Asset Symbol")
+                .interact()?;
+            let burn_amount = prompt_token_amount(wallet, "Burn Amount", None).await?;
+            let release_amount = prompt_token_amount(wallet, "Release Amount", None).await?;
+
+            AssetIssuerCommand::ReleaseReserves(ReleaseReservesArgs {
+                user,
+                symbol,
+                burn_amount,
+                release_amount,
+                contract_id: issuer_contract_id.into(),
+            })
+        }
+        _ => panic!("Invalid selection"),
+    };
+
+    let input = if issuer_selection == 0 {
+        ContractCallInput::BridgedAssetIssuer(command.into())
+    } else {
+        ContractCallInput::NativeAssetIssuer(command.into())
+    };
+
+    // Execute the contract call
+    let res = wallet.execute(input).await?;
+
+    // Handle the response based on the function called
+    match res {
+        ContractCallOutput::BridgedAssetIssuer(output) => {
+            match output {
+                BridgedAssetIssuerFunctionsOutput::CreateAsset(result) => {
+                    println!("✓ Asset Created");
+                    println!("ASSET MANAGER ADDRESS: {}", result.output.as_ref().unwrap().asset_manager);
+                    println!("TOKEN ADDRESS: {}", result.output.as_ref().unwrap().token);
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                BridgedAssetIssuerFunctionsOutput::LockReserves(result) => {
+                    println!("✓ Reserves Locked");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                BridgedAssetIssuerFunctionsOutput::ReleaseAsset(result) => {
+                    println!("✓ Asset Released");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                BridgedAssetIssuerFunctionsOutput::LockAsset(result) => {
+                    println!("✓ Asset Locked");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                BridgedAssetIssuerFunctionsOutput::ReleaseReserves(result) => {
+                    println!("✓ Reserves Released");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+            }
+        }
+        ContractCallOutput::NativeAssetIssuer(output) => {
+            match output {
+                NativeAssetIssuerFunctionsOutput::CreateAsset(result) => {
+                    println!("✓ Asset Created");
+                    println!("Asset Manager Address: {}", result.output.as_ref().unwrap().asset_manager);
+                    println!("Token Address: {}", result.output.as_ref().unwrap().token);
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                NativeAssetIssuerFunctionsOutput::LockReserves(result) => {
+                    println!("✓ Reserves Locked");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                NativeAssetIssuerFunctionsOutput::ReleaseAsset(result) => {
+                    println!("✓ Asset Released");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                NativeAssetIssuerFunctionsOutput::LockAsset(result) => {
+                    println!("✓ Asset Locked");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                NativeAssetIssuerFunctionsOutput::ReleaseReserves(result) => {
+                    println!("✓ Reserves Released");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+            }
+        }
+        _ => println!("Unexpected contract response"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,483 @@
+use anyhow::Result;
+use dialoguer::{Input, Select};
+use uuid::Uuid;
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::{TokenAmount, U256};
+use crate::utils::functions::asset_lending_pool::*;
+use crate::utils::functions::{ContractCallInput, ContractCallOutput};
+use crate::utils::script_utils::{prompt_decimals, prompt_token_amount};
+use crate::wallet::wallet::ActionWallet;
+
+pub async fn run(wallet: &mut ActionWallet) -> Result<()> {
+    // Select which function to call
+    let function_selection = Select::new()
+        .with_prompt("Select Asset Lending Pool Function")
+        .items(&[
+            "Get Utilization",
+            "Get Borrow Rate",
+            "Get Supply Rate",
+            "Update Borrow Index",
+            "Update Supply Index",
+            "Update Indices",
+            "Calculate Current Debt",
+            "Calculate Current Deposit",
+            "Calculate Health Factor",
+            "Update Oracle",
+            "Get Asset Multiplier",
+            "Get User Deposit Position",
+            "Get User Borrow Position",
+            "Get Max Borrow Amount",
+            "Is Position Liquidatable",
+            "Get Pool Stats",
+            "Deposit",
+            "Withdraw",
+            "Borrow",
+            "Repay",
+            "Liquidate",
+            "GetTreasuryAccount",
+            "GetReserveAccount"
+        ])
+        .interact()?;
+
+    let contract_id: CradleAddress = Input::new().with_prompt("Enter contract id").interact()?;
+
+    let input = match function_selection {
+        0 => ContractCallInput::AssetLendingPool(
+            AssetLendingPoolFunctionsInput::GetUtilization(get_utilization::Args { contract_id }),
+        ),
+        1 => ContractCallInput::AssetLendingPool(
+            AssetLendingPoolFunctionsInput::GetBorrowRate(get_borrow_rate::Args { contract_id }),
+        ),
+        2 => ContractCallInput::AssetLendingPool(
+            AssetLendingPoolFunctionsInput::GetSupplyRate(get_supply_rate::Args { contract_id }),
+        ),
+        3 => ContractCallInput::AssetLendingPool(
+            AssetLendingPoolFunctionsInput::UpdateBorrowIndex(update_borrow_index::Args {
+                contract_id,
+            }),
+        ),
+        4 => ContractCallInput::AssetLendingPool(
+            AssetLendingPoolFunctionsInput::UpdateSupplyIndex(update_supply_index::Args {
+                contract_id,
+            }),
+        ),
+        5 => ContractCallInput::AssetLendingPool(
+            AssetLendingPoolFunctionsInput::UpdateIndices(update_indices::Args { contract_id }),
+        ),
+        6 => {
+            let user_principal =
+                prompt_token_amount(wallet, "User Principal", None).await?;
+            let user_borrow_index: U256 = Input::new()
+                .with_prompt("User Borrow Index")
+                .interact()?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::CalculateCurrentDebt(calculate_current_debt::Args {
+                    user_principal,
+                    user_borrow_index,
+                    contract_id
+                }),
+            )
+        }
+        7 => {
+            let user_shares = prompt_token_amount(wallet, "User Shares", None).await?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::CalculateCurrentDeposit(
+                    calculate_current_deposit::Args { user_shares, contract_id },
+                ),
+            )
+        }
+        8 => {
+            let collateral_value: U256 = Input::new()
+                .with_prompt("Collateral Value")
+                .interact()?;
+            let borrowed_value: U256 = Input::new()
+                .with_prompt("Borrowed Value")
+                .interact()?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::CalculateHealthFactor(
+                    calculate_health_factor::Args {
+                        collateral_value,
+                        borrowed_value,
+                        contract_id
+                    },
+                ),
+            )
+        }
+        9 => {
+            let asset: CradleAddress = Input::new()
+                .with_prompt("Asset Address")
+                .interact()?;
+            let multiplier: U256 = Input::new()
+                .with_prompt("Multiplier Value")
+                .interact()?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::UpdateOracle(update_oracle::Args {
+                    asset,
+                    multiplier,
+                    contract_id
+                }),
+            )
+        }
+        10 => {
+            let asset: CradleAddress = Input::new()
+                .with_prompt("Asset Address")
+                .interact()?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::GetMultiplier(get_multiplier::Args {
+                    asset,
+                    contract_id
+                }),
+            )
+        }
+        11 => {
+            let user: CradleAddress = Input::new()
+                .with_prompt("User Address")
+                .interact()?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::GetUserDepositPosition(
+                    get_user_deposit_position::Args { user, contract_id },
+                ),
+            )
+        }
+        12 => {
+            let user: CradleAddress = Input::new()
+                .with_prompt("User Address")
+                .interact()?;
+            let collateral_asset: CradleAddress = Input::new()
+                .with_prompt("Collateral Asset Address")
+                .interact()?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::GetUserBorrowPosition(
+                    get_user_borrow_position::Args {
+                        user,
+                        collateral_asset,
+                        contract_id
+                    },
+                ),
+            )
+        }
+        13 => {
+            let collateral_asset: CradleAddress = Input::new()
+                .with_prompt("Collateral Asset Address")
+                .interact()?;
+            let collateral_amount =
+                prompt_token_amount(wallet, "Collateral Amount", Some(&collateral_asset)).await?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::GetMaxBorrowAmount(get_max_borrow_amount::Args {
+                    collateral_amount,
+                    collateral_asset,
+                    contract_id
+                }),
+            )
+        }
+        14 => {
+            let user: CradleAddress = Input::new()
+                .with_prompt("User Address")
+                .interact()?;
+            let collateral_asset: CradleAddress = Input::new()
+                .with_prompt("Collateral Asset Address")
+                .interact()?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::IsPositionLiquidatable(
+                    is_position_liquidatable::Args {
+                        user,
+                        collateral_asset,
+                        contract_id
+                    },
+                ),
+            )
+        }
+        15 => ContractCallInput::AssetLendingPool(
+            AssetLendingPoolFunctionsInput::GetPoolStats(get_pool_stats::Args { contract_id }),
+        ),
+        16 => {
+            let user: CradleAddress = Input::new()
+                .with_prompt("User Address")
+                .interact()?;
+            let amount = prompt_token_amount(wallet, "Amount to Deposit", None).await?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::Deposit(deposit::Args {
+                    user,
+                    amount,
+                    contract_id,
+                }),
+            )
+        }
+        17 => {
+            let user: CradleAddress = Input::new()
+                .with_prompt("User Address")
+                .interact()?;
+            let yield_token_amount =
+                prompt_token_amount(wallet, "Yield Token Amount to Withdraw", None).await?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::Withdraw(withdraw::Args {
+                    user,
+                    yield_token_amount,
+                    contract_id
+                }),
+            )
+        }
+        18 => {
+            let user: CradleAddress = Input::new()
+                .with_prompt("User Address")
+                .interact()?;
+            let collateral_asset: CradleAddress = Input::new()
+                .with_prompt("Collateral Asset Address")
+                .interact()?;
+            let collateral_amount =
+                prompt_token_amount(wallet, "Collateral Amount", Some(&collateral_asset)).await?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::Borrow(borrow::Args {
+                    user,
+                    collateral_amount,
+                    collateral_asset,
+                    contract_id
+                }),
+            )
+        }
+        19 => {
+            let user: CradleAddress = Input::new()
+                .with_prompt("User Address")
+                .interact()?;
+            let collateralized_asset: CradleAddress = Input::new()
+                .with_prompt("Collateralized Asset Address")
+                .interact()?;
+            let repay_amount = prompt_token_amount(wallet, "Repay Amount", None).await?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::Repay(repay::Args {
+                    user,
+                    collateralized_asset,
+                    repay_amount,
+                    contract_id
+                }),
+            )
+        }
+        20 => {
+            let liquidator: CradleAddress = Input::new()
+                .with_prompt("Liquidator Address")
+                .interact()?;
+            let borrower: CradleAddress = Input::new()
+                .with_prompt("Borrower Address")
+                .interact()?;
+            let debt_to_cover = prompt_token_amount(wallet, "Debt to Cover", None).await?;
+            let collateral_asset: CradleAddress = Input::new()
+                .with_prompt("Collateral Asset Address")
+                .interact()?;
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::Liquidate(liquidate::Args {
+                    liquidator,
+                    borrower,
+                    debt_to_cover,
+                    collateral_asset,
+                    contract_id
+                }),
+            )
+        }
+        21 => {
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::GetTreasuryAccount(
+                    get_treasury_account::Args { contract_id }
+                )
+            )
+        }
+        22 =>{
+
+            ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::GetReserveAccount(
+                    get_reserve_account::Args { contract_id }
+                )
+            )
+        }
+        _ => panic!("Invalid selection"),
+    };
+
+    // Execute the contract call
+    let res = wallet.execute(input).await?;
+
+    // Handle the response
+    match res {
+        ContractCallOutput::AssetLendingPool(output) => {
+            match output {
+                AssetLendingPoolFunctionsOutput::GetUtilization(result) => {
+                    println!("✓ Utilization Retrieved");
+                    if let Some(utilization) = result.output {
+                        println!("Utilization: {}", utilization);
+                    }
+                }
+                AssetLendingPoolFunctionsOutput::GetBorrowRate(result) => {
+                    println!("✓ Borrow Rate Retrieved");
+                    if let Some(borrow_rate) = result.output {
+                        println!("Borrow Rate: {}", borrow_rate);
+                    }
+                }
+                AssetLendingPoolFunctionsOutput::GetSupplyRate(result) => {
+                    println!("✓ Supply Rate Retrieved");
+                    if let Some(supply_rate) = result.output {
+                        println!("Supply Rate: {}", supply_rate);
+                    }
+                }
+                AssetLendingPoolFunctionsOutput::UpdateBorrowIndex(result) => {
+                    println!("✓ Borrow Index Updated");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetLendingPoolFunctionsOutput::UpdateSupplyIndex(result) => {
+                    println!("✓ Supply Index Updated");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetLendingPoolFunctionsOutput::UpdateIndices(result) => {
+                    println!("✓ Indices Updated");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetLendingPoolFunctionsOutput::CalculateCurrentDebt(result) => {
+                    println!("✓ Current Debt Calculated");
+                    if let Some(current_debt) = result.output {
+                        let decimals = prompt_decimals(wallet, "Pool Asset").await?;
+                        println!(
+                            "Current Debt: {}",
+                            TokenAmount::new(current_debt, decimals)
+                        );
+                    }
+                }
+                AssetLendingPoolFunctionsOutput::CalculateCurrentDeposit(result) => {
+                    println!("✓ Current Deposit Calculated");
+                    if let Some(current_deposit) = result.output {
+                        let decimals = prompt_decimals(wallet, "Pool Asset").await?;
+                        println!(
+                            "Current Deposit: {}",
+                            TokenAmount::new(current_deposit, decimals)
+                        );
+                    }
+                }
+                AssetLendingPoolFunctionsOutput::CalculateHealthFactor(result) => {
+                    println!("✓ Health Factor Calculated");
+                    if let Some(health_factor) = result.output {
+                        println!("Health Factor: {}", health_factor);
+                    }
+                }
+                AssetLendingPoolFunctionsOutput::UpdateOracle(result) => {
+                    println!("✓ Oracle Updated");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetLendingPoolFunctionsOutput::GetMultiplier(result) => {
+                    println!("✓ Asset Multiplier Retrieved");
+                    if let Some(multiplier) = result.output {
+                        println!("Multiplier: {}", multiplier);
+                    }
+                }
+                AssetLendingPoolFunctionsOutput::GetUserDepositPosition(result) => {
+                    println!("✓ User Deposit Position Retrieved");
+                    if let Some(info) = result.output {
+                        let yield_decimals = prompt_decimals(wallet, "Yield Token").await?;
+                        let decimals = prompt_decimals(wallet, "Pool Asset").await?;
+                        println!(
+                            "Yield Token Balance: {}",
+                            TokenAmount::new(info.yield_token_balance, yield_decimals)
+                        );
+                        println!(
+                            "Underlying Value: {}",
+                            TokenAmount::new(info.underlying_value, decimals)
+                        );
+                        println!("Current Supply APY: {}", info.current_supply_apy);
+                    }
+                }
+                AssetLendingPoolFunctionsOutput::GetUserBorrowPosition(result) => {
+                    println!("✓ User Borrow Position Retrieved");
+                    if let Some(info) = result.output {
+                        let decimals = prompt_decimals(wallet, "Pool Asset").await?;
+                        let collateral_decimals =
+                            prompt_decimals(wallet, "Collateral Asset").await?;
+                        println!(
+                            "Principal Borrowed: {}",
+                            TokenAmount::new(info.principal_borrowed, decimals)
+                        );
+                        println!(
+                            "Current Debt: {}",
+                            TokenAmount::new(info.current_debt, decimals)
+                        );
+                        println!(
+                            "Collateral Amount: {}",
+                            TokenAmount::new(info.collateral_amount, collateral_decimals)
+                        );
+                        println!("Health Factor: {}", info.health_factor);
+                        println!("Borrow Index: {}", info.borrow_index);
+                    }
+                }
+                AssetLendingPoolFunctionsOutput::GetMaxBorrowAmount(result) => {
+                    println!("✓ Max Borrow Amount Retrieved");
+                    if let Some(max_borrow_amount) = result.output {
+                        let decimals = prompt_decimals(wallet, "Pool Asset").await?;
+                        println!(
+                            "Max Borrow Amount: {}",
+                            TokenAmount::new(max_borrow_amount, decimals)
+                        );
+                    }
+                }
+                AssetLendingPoolFunctionsOutput::IsPositionLiquidatable(result) => {
+                    println!("✓ Position Liquidatability Checked");
+                    if let Some(info) = result.output {
+                        println!("Liquidatable: {}", info.value0);
+                        println!("Health Factor: {}", info.health_factor);
+                    }
+                }
+                AssetLendingPoolFunctionsOutput::GetPoolStats(result) => {
+                    println!("✓ Pool Stats Retrieved");
+                    if let Some(info) = result.output {
+                        let amounts = info.amounts(prompt_decimals(wallet, "Pool Asset").await?);
+                        println!("Total Supplied: {}", amounts.total_supplied);
+                        println!("Total Borrowed: {}", amounts.total_borrowed);
+                        println!("Liquidity: {}", amounts.liquidity);
+                        println!("Utilization: {}", info.utilization);
+                        println!("Borrow Rate: {}", info.borrow_rate);
+                        println!("Supply Rate: {}", info.supply_rate);
+                    }
+                }
+                AssetLendingPoolFunctionsOutput::Deposit(result) => {
+                    println!("✓ Deposit Successful");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetLendingPoolFunctionsOutput::Withdraw(result) => {
+                    println!("✓ Withdrawal Successful");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetLendingPoolFunctionsOutput::Borrow(result) => {
+                    println!("✓ Borrow Successful");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetLendingPoolFunctionsOutput::Repay(result) => {
+                    println!("✓ Repay Successful");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetLendingPoolFunctionsOutput::Liquidate(result) => {
+                    println!("✓ Liquidation Successful");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetLendingPoolFunctionsOutput::GetReserveAccount(result)=>{
+                    println!("Data :: {:?}", result.output.unwrap());
+                    println!("Transaction ID :: {:?}", result.transaction_id);
+                }
+                AssetLendingPoolFunctionsOutput::GetTreasuryAccount(result)=>{
+                    println!("Data :: {:?}", result.output.unwrap());
+                    println!("Transaction ID :: {:?}", result.transaction_id);
+                }
+            }
+        }
+        _ => println!("Unexpected contract response"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use std::env;
+use anyhow::Result;
+use dialoguer::{Input, Select};
+use crate::utils::address::CradleAddress;
+use crate::utils::functions::asset_manager::*;
+use crate::utils::functions::{ContractCallInput, ContractCallOutput};
+use crate::wallet::wallet::ActionWallet;
+
+pub async fn run(wallet: &mut ActionWallet) -> Result<()> {
+    // Select which function to call
+    let function_selection = Select::new()
+        .with_prompt("Select Asset Manager Function")
+        .items(&["Mint", "Burn", "Wipe", "Airdrop", "Self Associate", "TransferTokens", "Token Associate", "Grant KYC"])
+        .interact()?;
+
+    let input = match function_selection {
+        0 => {
+            // Mint
+            let asset_contract: CradleAddress = Input::new()
+                .with_prompt("Asset Contract ID")
+                .interact()?;
+            let amount: u64 = Input::new()
+                .with_prompt("Amount to Mint")
+                .interact()?;
+
+            ContractCallInput::AssetManager(AssetManagerFunctionsInput::Mint(mint::Args {
+                contract_id: asset_contract,
+                amount,
+            }))
+        }
+        1 => {
+            // Burn
+            let asset_contract: CradleAddress = Input::new()
+                .with_prompt("Asset Contract ID")
+                .interact()?;
+            let amount: u64 = Input::new()
+                .with_prompt("Amount to Burn")
+                .interact()?;
+
+            ContractCallInput::AssetManager(AssetManagerFunctionsInput::Burn(burn::Args {
+                contract_id: asset_contract,
+                amount,
+            }))
+        }
+        2 => {
+            // Wipe
+            let asset_contract: CradleAddress = Input::new()
+                .with_prompt("Asset Contract ID")
+                .interact()?;
+            let account: CradleAddress = Input::new()
+                .with_prompt("Account Address to Wipe")
+                .interact()?;
+            let amount: u64 = Input::new()
+                .with_prompt("Amount to Wipe")
+                .interact()?;
+
+            ContractCallInput::AssetManager(AssetManagerFunctionsInput::Wipe(wipe::Args {
+                contract_id: asset_contract,
+                account,
+                amount,
+            }))
+        }
+        3 => {
+            // Airdrop
+            let asset_contract: CradleAddress = Input::new()
+                .with_prompt("Asset Contract ID")
+                .interact()?;
+            let target: CradleAddress = Input::new()
+                .with_prompt("Target Address for Airdrop")
+                .interact()?;
+            let amount: u64 = Input::new()
+                .with_prompt("Amount to Airdrop")
+                .interact()?;
+
+            println!("Amount {}", amount);
+
+            ContractCallInput::AssetManager(AssetManagerFunctionsInput::AirdropTokens(
+                airdrop_tokens::Args {
+                    contract_id: asset_contract,
+                    target,
+                    amount,
+                },
+            ))
+        }
+        4 => {
+            // Self Associate
+            let asset_contract: CradleAddress = Input::new()
+                .with_prompt("Asset Contract ID")
+                .interact()?;
+
+            ContractCallInput::AssetManager(AssetManagerFunctionsInput::SelfAssociate(
+                self_associate::Args { contract_id: asset_contract },
+            ))
+        }
+        5 => {
+            // Transfer Tokens
+            let asset_contract: CradleAddress = Input::new()
+                .with_prompt("Asset Contract ID")
+                .interact()?;
+            let target: CradleAddress = Input::new()
+                .with_prompt("Target Address for Transfer")
+                .interact()?;
+            let amount: u64 = Input::new()
+                .with_prompt("Amount to Transfer")
+                .interact()?;
+
+            ContractCallInput::AssetManager(AssetManagerFunctionsInput::TransferTokens(
+                transfer_tokens::Args {
+                    contract_id: asset_contract,
+                    target,
+                    amount,
+                },
+            ))
+        }
+        6 => {
+            // Token Associate
+            let token_contract: CradleAddress = Input::new()
+                .with_prompt("Token Contract ID")
+                .interact()?;
+
+            ContractCallInput::TokenAssociate(token_contract)
+        }
+        7 => {
+            // Grant KYC
+            let token_contract: CradleAddress = Input::new()
+                .with_prompt("Token Contract ID")
+                .interact()?;
+            let account: CradleAddress = Input::new()
+                .with_prompt("Account Address to Grant KYC")
+                .interact()?;
+
+            ContractCallInput::AssetManager(AssetManagerFunctionsInput::GrantKyc(grant_kyc::Args {
+                contract_id: token_contract,
+                account,
+            }))
+        }
+        _ => panic!("Invalid selection"),
+    };
+
+    // Execute the contract call
+    let res = wallet.execute(input).await?;
+
+    // Handle the response based on the function called
+    match res {
+        ContractCallOutput::AssetManager(output) => {
+            match output {
+                AssetManagerFunctionsOutput::Mint(result) => {
+                    println!("✓ Asset Minted");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetManagerFunctionsOutput::Burn(result) => {
+                    println!("✓ Asset Burned");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetManagerFunctionsOutput::Wipe(result) => {
+                    println!("✓ Account Wiped");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetManagerFunctionsOutput::AirdropTokens(result) => {
+                    println!("✓ Airdrop Completed");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetManagerFunctionsOutput::SelfAssociate(result) => {
+                    println!("✓ Self Associated");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetManagerFunctionsOutput::TransferTokens(result) => {
+                    println!("✓ Tokens Transferred");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                AssetManagerFunctionsOutput::GrantKyc(result) => {
+                    println!("✓ KYC Granted");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+            }
+        }
+        ContractCallOutput::TokenAssociate(result) => {
+            println!("✓ Token Associated");
+            println!("Transaction ID: {}", result.transaction_id);
+        }
+        _ => println!("Unexpected contract response"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,364 @@
+use anyhow::{Context, Result};
+use dialoguer::{Input, Select, Confirm};
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::{TokenAmount, U256};
+use crate::utils::functions::cradle_account::*;
+use crate::utils::functions::{ContractCallInput, ContractCallOutput};
+use crate::utils::script_utils::{prompt_decimals, prompt_token_amount};
+use crate::wallet::wallet::ActionWallet;
+
+pub async fn run(wallet: &mut ActionWallet) -> Result<()> {
+    // Select which function to call
+    let function_selection = Select::new()
+        .with_prompt("Select Cradle Account Function")
+        .items(&[
+            "Associate Token",
+            "Withdraw",
+            "Update Bridging Status",
+            "Transfer Asset",
+            "Get Tradable Balance",
+            "Lock Asset",
+            "Unlock Asset",
+            "Add Loan Lock",
+            "Get Loan Amount",
+            "Get Collateral",
+            "Get Loan Block Index",
+            "Remove Loan Lock",
+        ])
+        .interact()?;
+
+    // Token of the amount a query returns, to show it in human units
+    let mut output_token: Option<CradleAddress> = None;
+
+    let input = match function_selection {
+        0 => {
+            // AssociateToken
+            let token: CradleAddress = Input::new()
+                .with_prompt("Token Address")
+                .interact()?;
+            let account_contract_id: CradleAddress = Input::new()
+                .with_prompt("Account Contract ID")
+                .interact()?;
+
+            ContractCallInput::CradleAccount(CradleAccountFunctionsInput::AssociateToken(
+                associate_token::Args {
+                    token,
+                    contract_id: account_contract_id,
+                },
+            ))
+        }
+        1 => {
+            // Withdraw
+            let asset: CradleAddress = Input::new()
+                .with_prompt("Asset Address")
+                .interact()?;
+            let amount = prompt_token_amount(wallet, "Amount to Withdraw", Some(&asset))
+                .await?
+                .to_u64()
+                .context("Amount does not fit in a uint64")?;
+            let to: CradleAddress = Input::new()
+                .with_prompt("Recipient Address")
+                .interact()?;
+            let account_contract_id: CradleAddress = Input::new()
+                .with_prompt("Account Contract ID")
+                .interact()?;
+
+            ContractCallInput::CradleAccount(CradleAccountFunctionsInput::Withdraw(withdraw::Args {
+                asset,
+                amount,
+                to,
+                contract_id: account_contract_id,
+            }))
+        }
+        2 => {
+            // UpdateBridgingStatus
+            let new_status: bool = Confirm::new()
+                .with_prompt("Enable Bridging?")
+                .interact()?;
+            let account_contract_id: CradleAddress = Input::new()
+                .with_prompt("Account Contract ID")
+                .interact()?;
+
+            ContractCallInput::CradleAccount(CradleAccountFunctionsInput::UpdateBridgingStatus(
+                update_bridging_status::Args {
+                    new_status,
+                    contract_id: account_contract_id,
+                },
+            ))
+        }
+        3 => {
+            // TransferAsset
+            let asset: CradleAddress = Input::new()
+                .with_prompt("Asset Address")
+                .interact()?;
+            let amount = prompt_token_amount(wallet, "Amount to Transfer", Some(&asset)).await?;
+            let to: CradleAddress = Input::new()
+                .with_prompt("Recipient Address")
+                .interact()?;
+            let account_contract_id: CradleAddress = Input::new()
+                .with_prompt("Account Contract ID")
+                .interact()?;
+
+            ContractCallInput::CradleAccount(CradleAccountFunctionsInput::TransferAsset(
+                transfer_asset::Args {
+                    asset,
+                    amount,
+                    to,
+                    contract_id: account_contract_id,
+                },
+            ))
+        }
+        4 => {
+            // GetTradableBalance
+            let asset: CradleAddress = Input::new()
+                .with_prompt("Asset Address")
+                .interact()?;
+            output_token = Some(asset);
+            let account_contract_id: CradleAddress = Input::new()
+                .with_prompt("Account Contract ID")
+                .interact()?;
+
+            ContractCallInput::CradleAccount(CradleAccountFunctionsInput::GetTradableBalance(
+                get_tradable_balance::Args {
+                    asset,
+                    contract_id: account_contract_id,
+                },
+            ))
+        }
+        5 => {
+            // LockAsset
+            let asset: CradleAddress = Input::new()
+                .with_prompt("Asset Address")
+                .interact()?;
+            let amount = prompt_token_amount(wallet, "Amount to Lock", Some(&asset)).await?;
+            let account_contract_id: CradleAddress = Input::new()
+                .with_prompt("Account Contract ID")
+                .interact()?;
+
+            ContractCallInput::CradleAccount(CradleAccountFunctionsInput::LockAsset(
+                lock_asset::Args {
+                    asset,
+                    amount,
+                    contract_id: account_contract_id,
+                },
+            ))
+        }
+        6 => {
+            // UnLockAsset
+            let asset: CradleAddress = Input::new()
+                .with_prompt("Asset Address")
+                .interact()?;
+            let amount = prompt_token_amount(wallet, "Amount to Unlock", Some(&asset)).await?;
+            let account_contract_id: CradleAddress = Input::new()
+                .with_prompt("Account Contract ID")
+                .interact()?;
+
+            ContractCallInput::CradleAccount(CradleAccountFunctionsInput::UnlockAsset(
+                unlock_asset::Args {
+                    asset,
+                    amount,
+                    contract_id: account_contract_id,
+                },
+            ))
+        }
+        7 => {
+            // AddLoanLock
+            let lender: CradleAddress = Input::new()
+                .with_prompt("Lender Address")
+                .interact()?;
+            let collateral: CradleAddress = Input::new()
+                .with_prompt("Collateral Address")
+                .interact()?;
+            let loan_amount = prompt_token_amount(wallet, "Loan Amount", None).await?;
+            let collateral_amount =
+                prompt_token_amount(wallet, "Collateral Amount", Some(&collateral)).await?;
+            let borrow_index: U256 = Input::new()
+                .with_prompt("Borrow Index")
+                .interact()?;
+            let account_contract_id: CradleAddress = Input::new()
+                .with_prompt("Account Contract ID")
+                .interact()?;
+
+            ContractCallInput::CradleAccount(CradleAccountFunctionsInput::AddLoanLock(
+                add_loan_lock::Args {
+                    lender,
+                    collateral,
+                    loan_amount,
+                    collateral_amount,
+                    borrow_index,
+                    contract_id: account_contract_id,
+                },
+            ))
+        }
+        8 => {
+            // GetLoanAmount
+            let lender: CradleAddress = Input::new()
+                .with_prompt("Lender Address")
+                .interact()?;
+            let collateral: CradleAddress = Input::new()
+                .with_prompt("Collateral Address")
+                .interact()?;
+            let account_contract_id: CradleAddress = Input::new()
+                .with_prompt("Account Contract ID")
+                .interact()?;
+
+            ContractCallInput::CradleAccount(CradleAccountFunctionsInput::GetLoanAmount(
+                get_loan_amount::Args {
+                    lender,
+                    collateral,
+                    contract_id: account_contract_id,
+                },
+            ))
+        }
+        9 => {
+            // GetCollateral
+            let lender: CradleAddress = Input::new()
+                .with_prompt("Lender Address")
+                .interact()?;
+            let collateral: CradleAddress = Input::new()
+                .with_prompt("Collateral Address")
+                .interact()?;
+            output_token = Some(collateral);
+            let account_contract_id: CradleAddress = Input::new()
+                .with_prompt("Account Contract ID")
+                .interact()?;
+
+            ContractCallInput::CradleAccount(CradleAccountFunctionsInput::GetCollateral(
+                get_collateral::Args {
+                    lender,
+                    collateral,
+                    contract_id: account_contract_id,
+                },
+            ))
+        }
+        10 => {
+            // GetLoanBlockIndex
+            let lender: CradleAddress = Input::new()
+                .with_prompt("Lender Address")
+                .interact()?;
+            let collateral: CradleAddress = Input::new()
+                .with_prompt("Collateral Address")
+                .interact()?;
+            let account_contract_id: CradleAddress = Input::new()
+                .with_prompt("Account Contract ID")
+                .interact()?;
+
+            ContractCallInput::CradleAccount(CradleAccountFunctionsInput::GetLoanBlockIndex(
+                get_loan_block_index::Args {
+                    lender,
+                    collateral,
+                    contract_id: account_contract_id,
+                },
+            ))
+        }
+        11 => {
+            // RemoveLoanLock
+            let lender: CradleAddress = Input::new()
+                .with_prompt("Lender Address")
+                .interact()?;
+            let collateral: CradleAddress = Input::new()
+                .with_prompt("Collateral Address")
+                .interact()?;
+            let loan_amount = prompt_token_amount(wallet, "Loan Amount", None).await?;
+            let collateral_amount =
+                prompt_token_amount(wallet, "Collateral Amount", Some(&collateral)).await?;
+            let borrow_index: U256 = Input::new()
+                .with_prompt("Borrow Index")
+                .interact()?;
+            let account_contract_id: CradleAddress = Input::new()
+                .with_prompt("Account Contract ID")
+                .interact()?;
+
+            ContractCallInput::CradleAccount(CradleAccountFunctionsInput::RemoveLoanLock(
+                remove_loan_lock::Args {
+                    lender,
+                    collateral,
+                    loan_amount,
+                    collateral_amount,
+                    borrow_index,
+                    contract_id: account_contract_id,
+                },
+            ))
+        }
+        _ => panic!("Invalid selection"),
+    };
+
+    // Execute the contract call
+    let res = wallet.execute(input).await?;
+
+    // Handle the response
+    match res {
+        ContractCallOutput::CradleAccount(output) => {
+            match output {
+                CradleAccountFunctionsOutput::AssociateToken(result) => {
+                    println!("✓ Token Associated");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                CradleAccountFunctionsOutput::Withdraw(result) => {
+                    println!("✓ Withdrawal Successful");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                CradleAccountFunctionsOutput::UpdateBridgingStatus(result) => {
+                    println!("✓ Bridging Status Updated");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                CradleAccountFunctionsOutput::TransferAsset(result) => {
+                    println!("✓ Asset Transferred");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                CradleAccountFunctionsOutput::GetTradableBalance(result) => {
+                    println!("✓ Tradable Balance Retrieved");
+                    if let Some(tradable_balance) = result.output {
+                        let decimals = wallet.token_decimals(&output_token.unwrap()).await?;
+                        println!(
+                            "Tradable Balance: {}",
+                            TokenAmount::new(tradable_balance, decimals)
+                        );
+                    }
+                }
+                CradleAccountFunctionsOutput::LockAsset(result) => {
+                    println!("✓ Asset Locked");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                CradleAccountFunctionsOutput::UnlockAsset(result) => {
+                    println!("✓ Asset Unlocked");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                CradleAccountFunctionsOutput::AddLoanLock(result) => {
+                    println!("✓ Loan Lock Added");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+                CradleAccountFunctionsOutput::GetLoanAmount(result) => {
+                    println!("✓ Loan Amount Retrieved");
+                    if let Some(loan_amount) = result.output {
+                        let decimals = prompt_decimals(wallet, "Loan Asset").await?;
+                        println!("Loan Amount: {}", TokenAmount::new(loan_amount, decimals));
+                    }
+                }
+                CradleAccountFunctionsOutput::GetCollateral(result) => {
+                    println!("✓ Collateral Amount Retrieved");
+                    if let Some(collateral_amount) = result.output {
+                        let decimals = wallet.token_decimals(&output_token.unwrap()).await?;
+                        println!(
+                            "Collateral Amount: {}",
+                            TokenAmount::new(collateral_amount, decimals)
+                        );
+                    }
+                }
+                CradleAccountFunctionsOutput::GetLoanBlockIndex(result) => {
+                    println!("✓ Loan Block Index Retrieved");
+                    if let Some(block_index) = result.output {
+                        println!("Block Index: {}", block_index);
+                    }
+                }
+                CradleAccountFunctionsOutput::RemoveLoanLock(result) => {
+                    println!("✓ Loan Lock Removed");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+            }
+        }
+        _ => println!("Unexpected contract response"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anyhow::Result;
+use dialoguer::{Input, Select};
+use crate::utils::address::CradleAddress;
+use crate::utils::functions::cradle_account_factory::{
+    CradleAccountFactoryFunctionsInput, CradleAccountFactoryFunctionsOutput, create_account,
+    create_account_for_user, get_account_by_controller,
+};
+use crate::utils::functions::{ContractCallInput, ContractCallOutput};
+use crate::wallet::wallet::ActionWallet;
+
+pub async fn run(wallet: &mut ActionWallet) -> Result<()> {
+    // Select which function to call
+    let function_selection = Select::new()
+        .with_prompt("Select Cradle Account Factory Function")
+        .items(&[
+            "Create Account",
+            "Create Account For User",
+            "Get Account By Controller",
+        ])
+        .interact()?;
+
+    let input = match function_selection {
+        0 => {
+            // CreateAccount
+            let controller: String = Input::new()
+                .with_prompt("Controller Name")
+                .interact()?;
+            let account_allow_list: u64 = Input::new()
+                .with_prompt("Allow List Value")
+                .interact()?;
+
+            ContractCallInput::CradleAccountFactory(
+                CradleAccountFactoryFunctionsInput::CreateAccount(create_account::Args {
+                    controller,
+                    account_allow_list,
+                }),
+            )
+        }
+        1 => {
+            // CreateAccountForUser
+            let controller: String = Input::new()
+                .with_prompt("Controller Name")
+                .interact()?;
+            let user: CradleAddress = Input::new()
+                .with_prompt("User Address")
+                .interact()?;
+            let account_allow_list: u64 = Input::new()
+                .with_prompt("Allow List Value")
+                .interact()?;
+
+            ContractCallInput::CradleAccountFactory(
+                CradleAccountFactoryFunctionsInput::CreateAccountForUser(
+                    create_account_for_user::Args {
+                        controller,
+                        user,
+                        account_allow_list,
+                    },
+                ),
+            )
+        }
+        2 => {
+            // GetAccountByController
+            let controller: String = Input::new()
+                .with_prompt("Controller Name")
+                .interact()?;
+
+            ContractCallInput::CradleAccountFactory(
+                CradleAccountFactoryFunctionsInput::GetAccountByController(
+                    get_account_by_controller::Args { controller },
+                ),
+            )
+        }
+        _ => panic!("Invalid selection"),
+    };
+
+    // Execute the contract call
+    let res = wallet.execute(input).await?;
+
+    // Handle the response
+    match res {
+        ContractCallOutput::CradleAccountFactory(output) => {
+            match output {
+                CradleAccountFactoryFunctionsOutput::CreateAccount(result) => {
+                    println!("✓ Account Created");
+                    println!("Transaction ID: {}", result.transaction_id);
+                    if let Some(account) = result.output {
+                        println!("Account Address: {}", account);
+                    }
+                }
+                CradleAccountFactoryFunctionsOutput::CreateAccountForUser(result) => {
+                    println!("✓ Account Created For User");
+                    println!("Transaction ID: {}", result.transaction_id);
+                    if let Some(account) = result.output {
+                        println!("Account Address: {}", account);
+                    }
+                }
+                CradleAccountFactoryFunctionsOutput::GetAccountByController(result) => {
+                    println!("✓ Account Retrieved");
+                    if let Some(account) = result.output {
+                        println!("Account Address: {}", account);
+                    }
+                }
+            }
+        }
+        _ => println!("Unexpected contract response"),
+    }
+
+    Ok(())
+}
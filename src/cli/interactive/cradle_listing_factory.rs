@@ -0,0 +1,59 @@
+use anyhow::Result;
+use crate::utils::address::CradleAddress;
+use crate::utils::script_utils::prompt_token_amount;
+use crate::{
+    utils::functions::{
+        ContractCallInput, ContractCallOutput,
+        cradle_listing_factory::{
+            CradleListingFactoryFunctionsInput, CradleListingFactoryFunctionsOutput, create_listing,
+        },
+    },
+    wallet::wallet::ActionWallet,
+};
+use dialoguer::Input;
+
+pub async fn run(wallet: &mut ActionWallet) -> Result<()> {
+    let input = {
+        let fee_collector: CradleAddress = Input::new().with_prompt("Fee collector").interact()?;
+        let reserve_account: CradleAddress =
+            Input::new().with_prompt("Reserve Account").interact()?;
+        let listing_asset: CradleAddress = Input::new().with_prompt("Listing Asset").interact()?;
+        let max_supply =
+            prompt_token_amount(wallet, "Max supply", Some(&listing_asset)).await?;
+        let purchase_asset: CradleAddress = Input::new().with_prompt("Purchase Asset").interact()?;
+        let purchase_price =
+            prompt_token_amount(wallet, "Purchase Price", Some(&purchase_asset)).await?;
+        let beneficiary_address: CradleAddress =
+            Input::new().with_prompt("Beneficiary Address").interact()?;
+        let shadow_asset: CradleAddress = Input::new().with_prompt("Shadow Asset").interact()?;
+
+        ContractCallInput::CradleListingFactory(CradleListingFactoryFunctionsInput::CreateListing(
+            create_listing::Args {
+                fee_collector,
+                reserve_account,
+                max_supply,
+                listing_asset,
+                purchase_asset,
+                purchase_price,
+                beneficiary: beneficiary_address,
+                shadow_asset,
+            },
+        ))
+    };
+
+    let res = wallet.execute(input).await?;
+
+    match res {
+        ContractCallOutput::CradleListingFactory(
+            CradleListingFactoryFunctionsOutput::CreateListing(output),
+        ) => {
+            println!("Transaction success :: {:?}", output.transaction_id);
+            println!("New Listing created at :: {:?}", output.output.unwrap());
+        }
+        _ => {
+            // println!("Transaction Failed")
+        }
+    }
+
+    Ok(())
+}
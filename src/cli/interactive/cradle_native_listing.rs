@@ -0,0 +1,154 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::TokenAmount;
+use crate::utils::script_utils::{prompt_decimals, prompt_token_amount};
+use crate::{
+    utils::functions::{
+        ContractCallInput, ContractCallOutput,
+        cradle_native_listing::{
+            CradleNativeListingFunctionsInput, CradleNativeListingFunctionsOutput, get_fee,
+            get_listing_stats, purchase, return_asset, update_listing_status,
+            withdraw_to_beneficiary,
+        },
+    },
+    wallet::wallet::ActionWallet,
+};
+use dialoguer::{Input, Select};
+
+/// Listing statuses in the order of the contract enum.
+const LISTING_STATUSES: [&str; 5] = ["Pending", "Open", "Closed", "Paused", "Cancelled"];
+
+pub async fn run(wallet: &mut ActionWallet) -> anyhow::Result<()> {
+    let function_selection = Select::new()
+        .with_prompt("Select an action:")
+        .item("Update Listing Status")
+        .item("Purchase")
+        .item("Return Asset")
+        .item("WithdrawToBeneficiary")
+        .item("GetListingStats")
+        .item("GetFee")
+        .interact()?;
+
+    let contract_id: CradleAddress = Input::new().with_prompt("Contract ID").interact()?;
+
+    let input = {
+        match function_selection {
+            0 => {
+                let new_status = Select::new()
+                    .with_prompt("Select new listing Status")
+                    .items(LISTING_STATUSES)
+                    .interact()?;
+
+                ContractCallInput::CradleNativeListing(
+                    CradleNativeListingFunctionsInput::UpdateListingStatus(
+                        update_listing_status::Args {
+                            contract_id,
+                            status: new_status as u8,
+                        },
+                    ),
+                )
+            }
+            1 => {
+                let account: CradleAddress = Input::new().with_prompt("Account").interact()?;
+
+                let amount = prompt_token_amount(wallet, "Amount", None).await?;
+
+                ContractCallInput::CradleNativeListing(CradleNativeListingFunctionsInput::Purchase(
+                    purchase::Args {
+                        contract_id,
+                        buyer: account,
+                        amount,
+                    },
+                ))
+            }
+            2 => {
+                let account: CradleAddress = Input::new().with_prompt("Account").interact()?;
+
+                let amount = prompt_token_amount(wallet, "Amount", None).await?;
+
+                ContractCallInput::CradleNativeListing(
+                    CradleNativeListingFunctionsInput::ReturnAsset(return_asset::Args {
+                        contract_id,
+                        account,
+                        amount,
+                    }),
+                )
+            }
+            3 => {
+                let amount = prompt_token_amount(wallet, "Amount", None).await?;
+
+                ContractCallInput::CradleNativeListing(
+                    CradleNativeListingFunctionsInput::WithdrawToBeneficiary(
+                        withdraw_to_beneficiary::Args {
+                            contract_id,
+                            amount,
+                        },
+                    ),
+                )
+            }
+            4 => ContractCallInput::CradleNativeListing(
+                CradleNativeListingFunctionsInput::GetListingStats(get_listing_stats::Args {
+                    contract_id,
+                }),
+            ),
+            5 => {
+                let amount = prompt_token_amount(wallet, "Amount", None).await?;
+
+                ContractCallInput::CradleNativeListing(CradleNativeListingFunctionsInput::GetFee(
+                    get_fee::Args {
+                        contract_id,
+                        amount,
+                    },
+                ))
+            }
+            _ => {
+                panic!("Invalid selection")
+            }
+        }
+    };
+
+    let res = wallet.execute(input).await?;
+
+    match res {
+        ContractCallOutput::CradleNativeListing(r) => match r {
+            CradleNativeListingFunctionsOutput::UpdateListingStatus(d) => {
+                println!("Transaction commited :: {:?}", d.transaction_id);
+            }
+            CradleNativeListingFunctionsOutput::Purchase(d) => {
+                println!("Transaction commited :: {:?}", d.transaction_id);
+                let decimals = prompt_decimals(wallet, "Listing Asset").await?;
+                println!("Amount received {}", TokenAmount::new(d.output.unwrap(), decimals));
+            }
+            CradleNativeListingFunctionsOutput::ReturnAsset(d) => {
+                println!("Transaction commited :: {:?}", d.transaction_id);
+                let decimals = prompt_decimals(wallet, "Purchase Asset").await?;
+                println!("Amount received {}", TokenAmount::new(d.output.unwrap(), decimals));
+            }
+            CradleNativeListingFunctionsOutput::WithdrawToBeneficiary(d) => {
+                println!("Transaction commited :: {:?}", d.transaction_id);
+            }
+            CradleNativeListingFunctionsOutput::GetListingStats(d) => {
+                println!("Transaction commited :: {:?}", d.transaction_id);
+                let stats = d.output.unwrap();
+                let listing_decimals = prompt_decimals(wallet, "Listing Asset").await?;
+                let purchase_decimals = prompt_decimals(wallet, "Purchase Asset").await?;
+                let amounts = stats.amounts(listing_decimals, purchase_decimals);
+                println!("Total distributed :: {}", amounts.total_distributed);
+                println!("Remaining :: {}", amounts.remaining);
+                println!("Raised :: {}", amounts.raised);
+                println!("Balance :: {}", amounts.balance);
+                match LISTING_STATUSES.get(stats.status as usize) {
+                    Some(status) => println!("Status :: {}", status),
+                    None => println!("Status :: {}", stats.status),
+                }
+            }
+            CradleNativeListingFunctionsOutput::GetFee(d) => {
+                println!("Transaction commited :: {:?} ", d.transaction_id);
+                let decimals = prompt_decimals(wallet, "Purchase Asset").await?;
+                println!("Fee :: {}", TokenAmount::new(d.output.unwrap(), decimals));
+            }
+        },
+        _ => panic!("invalid path :) "),
+    }
+
+    Ok(())
+}
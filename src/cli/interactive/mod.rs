@@ -0,0 +1,46 @@
+pub mod access_controller;
+pub mod asset_issuer;
+pub mod asset_lending;
+pub mod asset_lending_pool_factory;
+pub mod asset_manager;
+pub mod cradle_account;
+pub mod cradle_account_factory;
+pub mod cradle_listing_factory;
+pub mod cradle_native_listing;
+pub mod order_book_settler;
+
+use crate::wallet::wallet::ActionWallet;
+use anyhow::Result;
+use dialoguer::Select;
+
+/// Prompts for a contract, then for the function and arguments of the call.
+pub async fn run(wallet: &mut ActionWallet) -> Result<()> {
+    let contract_selection = Select::new()
+        .with_prompt("Select Contract")
+        .items(&[
+            "Access Controller",
+            "Asset Issuer",
+            "Asset Lending Pool",
+            "Asset Lending Pool Factory",
+            "Asset Manager",
+            "Cradle Account",
+            "Cradle Account Factory",
+            "Cradle Listing Factory",
+            "Cradle Native Listing",
+            "Order Book Settler",
+        ])
+        .interact()?;
+
+    match contract_selection {
+        0 => access_controller::run(wallet).await,
+        1 => asset_issuer::run(wallet).await,
+        2 => asset_lending::run(wallet).await,
+        3 => asset_lending_pool_factory::run(wallet).await,
+        4 => asset_manager::run(wallet).await,
+        5 => cradle_account::run(wallet).await,
+        6 => cradle_account_factory::run(wallet).await,
+        7 => cradle_listing_factory::run(wallet).await,
+        8 => cradle_native_listing::run(wallet).await,
+        _ => order_book_settler::run(wallet).await,
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+use dialoguer::Input;
+use crate::utils::address::CradleAddress;
+use crate::utils::functions::cradle_order_book_settler::{
+    CradleOrderBookSettlerFunctionsInput, CradleOrderBookSettlerFunctionsOutput, settle_order,
+};
+use crate::utils::functions::{ContractCallInput, ContractCallOutput};
+use crate::utils::script_utils::prompt_token_amount;
+use crate::wallet::wallet::ActionWallet;
+
+pub async fn run(wallet: &mut ActionWallet) -> Result<()> {
+    // SettleOrder
+    let bidder: CradleAddress = Input::new()
+        .with_prompt("Bidder Address")
+        .interact()?;
+    let asker: CradleAddress = Input::new()
+        .with_prompt("Asker Address")
+        .interact()?;
+    let bid_asset: CradleAddress = Input::new()
+        .with_prompt("Bid Asset Address")
+        .interact()?;
+    let ask_asset: CradleAddress = Input::new()
+        .with_prompt("Ask Asset Address")
+        .interact()?;
+    let bid_asset_amount =
+        prompt_token_amount(wallet, "Bid Asset Amount", Some(&bid_asset)).await?;
+    let ask_asset_amount =
+        prompt_token_amount(wallet, "Ask Asset Amount", Some(&ask_asset)).await?;
+
+    let input = ContractCallInput::OrderBookSettler(
+        CradleOrderBookSettlerFunctionsInput::SettleOrder(settle_order::Args {
+            bidder,
+            asker,
+            bid_asset,
+            ask_asset,
+            bid_asset_amount,
+            ask_asset_amount,
+        }),
+    );
+
+    // Execute the contract call
+    let res = wallet.execute(input).await?;
+
+    // Handle the response
+    match res {
+        ContractCallOutput::OrderBookSettler(output) => {
+            match output {
+                CradleOrderBookSettlerFunctionsOutput::SettleOrder(result) => {
+                    println!("✓ Order Settled");
+                    println!("Transaction ID: {}", result.transaction_id);
+                }
+            }
+        }
+        _ => println!("Unexpected contract response"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,299 @@
+pub mod args;
+pub mod commands;
+pub mod interactive;
+
+use crate::cli::commands::ContractCommand;
+use crate::server::ApiError;
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::utils::functions::ContractCallOutput;
+use crate::wallet::wallet::{ActionWallet, ActionWalletArgs};
+use clap::Parser;
+use serde_json::{Value, json};
+use std::process::ExitCode;
+
+/// Exit codes of the `cradle` binary. Usage errors exit with clap's `2`.
+pub mod exit_code {
+    pub const SUCCESS: u8 = 0;
+    pub const FAILURE: u8 = 1;
+    pub const USAGE: u8 = 2;
+    /// Missing or invalid configuration or arguments.
+    pub const CONFIG: u8 = 3;
+    /// The contract reverted.
+    pub const REVERT: u8 = 4;
+    /// The transaction failed at precheck or consensus.
+    pub const TRANSACTION: u8 = 5;
+    /// The network or mirror node couldn't be reached in time.
+    pub const NETWORK: u8 = 6;
+}
+
+/// Calls any wrapped contract function without prompting, e.g.
+///
+/// `cradle lending borrow --pool 0.0.1234 --user 0.0.5678 --collateral-amount 100
+/// --collateral-asset 0.0.91011`
+///
+/// The operator and network come from the same flags and variables as every other binary.
+#[derive(Parser, Clone, Debug)]
+#[clap(name = "cradle")]
+pub struct CradleCli {
+    /// Print the whole output, or the error, as json on stdout.
+    #[clap(long, global = true)]
+    pub json: bool,
+    /// Prompt for the contract, function and arguments instead.
+    #[clap(long)]
+    pub interactive: bool,
+    #[clap(subcommand)]
+    pub command: Option<ContractCommand>,
+    #[clap(flatten)]
+    pub wallet: ActionWalletArgs,
+}
+
+impl CradleCli {
+    /// Wallet of the operator flags parsed with the command.
+    pub fn wallet(&self) -> CradleResult<ActionWallet> {
+        ActionWallet::from_args(self.wallet.clone())
+    }
+
+    pub async fn run(self, wallet: &mut ActionWallet) -> ExitCode {
+        if self.interactive {
+            return match interactive::run(wallet).await {
+                Ok(()) => ExitCode::from(exit_code::SUCCESS),
+                Err(error) => self.fail(error.into()),
+            };
+        }
+
+        let Some(command) = self.command.clone() else {
+            eprintln!("No command given, see `cradle --help` or run `cradle --interactive`");
+            return ExitCode::from(exit_code::USAGE);
+        };
+
+        match self.execute(command, wallet).await {
+            Ok(output) => {
+                self.print(&output);
+                ExitCode::from(exit_code::SUCCESS)
+            }
+            Err(error) => self.fail(error),
+        }
+    }
+
+    async fn execute(
+        &self,
+        command: ContractCommand,
+        wallet: &mut ActionWallet,
+    ) -> CradleResult<ContractCallOutput> {
+        let input = command.into_input()?;
+        wallet.execute(input).await
+    }
+
+    fn print(&self, output: &ContractCallOutput) {
+        let value = serde_json::to_value(output).unwrap_or_default();
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&value).unwrap_or_default());
+            return;
+        }
+
+        for (name, value) in summary(&value) {
+            println!("{}: {}", name, value);
+        }
+    }
+
+    /// Reports the error and returns the exit code for it.
+    pub fn fail(&self, error: CradleError) -> ExitCode {
+        let code = exit_code_of(&error);
+        if self.json {
+            let error = json!({ "error": ApiError::from(error) });
+            println!("{}", serde_json::to_string_pretty(&error).unwrap_or_default());
+        } else {
+            eprintln!("Error: {}", error);
+            if let Some(transaction_id) = error.transaction_id() {
+                eprintln!("Transaction ID: {}", transaction_id);
+            }
+        }
+
+        ExitCode::from(code)
+    }
+}
+
+pub fn exit_code_of(error: &CradleError) -> u8 {
+    match error {
+        CradleError::Config(_) => exit_code::CONFIG,
+        CradleError::Revert(_) => exit_code::REVERT,
+        CradleError::Precheck { .. } | CradleError::Receipt { .. } => exit_code::TRANSACTION,
+        CradleError::Network(_) | CradleError::Mirror(_) | CradleError::Timeout(_) => {
+            exit_code::NETWORK
+        }
+        CradleError::Decoding(_) | CradleError::Other(_) => exit_code::FAILURE,
+    }
+}
+
+/// Lines of the human readable output: the transaction and the decoded output of the
+/// `FunctionCallOutput` nested in the contract and function variants.
+fn summary(value: &Value) -> Vec<(String, String)> {
+    let mut call = value;
+    while let Value::Object(fields) = call {
+        match fields.iter().next() {
+            Some((_, inner)) if fields.len() == 1 => call = inner,
+            _ => break,
+        }
+    }
+
+    let mut lines = vec![];
+    if let Some(transaction_id) = call.get("transaction_id") {
+        lines.push(("Transaction ID".to_string(), display(transaction_id)));
+    }
+    if let Some(status) = call.get("status").filter(|status| !status.is_null()) {
+        lines.push(("Status".to_string(), display(status)));
+    }
+
+    match call.get("output") {
+        Some(Value::Object(fields)) => {
+            lines.extend(fields.iter().map(|(name, value)| (name.clone(), display(value))));
+        }
+        Some(Value::Null) | None => {}
+        Some(output) => lines.push(("Output".to_string(), display(output))),
+    }
+
+    lines
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use super::*;
+    use crate::utils::amount::U256;
+    use crate::utils::errors::ERROR_STRING_SELECTOR;
+    use crate::utils::functions::ContractCallInput;
+    use crate::utils::functions::access_controller::AccessControllerFunctionsInput;
+    use crate::utils::functions::asset_lending_pool::AssetLendingPoolFunctionsInput;
+    use crate::wallet::network::CradleNetwork;
+    use crate::wallet::test_fixtures::{ASSET, USER, mock_wallet};
+    use anyhow::Result;
+    use hedera::{ContractFunctionParameters, PrivateKey, Status};
+
+    fn parse_input(args: &[&str]) -> Result<ContractCallInput> {
+        let cli = CradleCli::try_parse_from([&["cradle"][..], args].concat())?;
+        let command = cli.command.ok_or_else(|| anyhow::anyhow!("no command parsed"))?;
+
+        Ok(command.into_input()?)
+    }
+
+    #[test]
+    fn commands_parse_into_contract_calls() -> Result<()> {
+        let borrow = parse_input(&[
+            "lending",
+            "borrow",
+            "--pool",
+            "0.0.2001",
+            "--user",
+            USER,
+            "--collateral-amount",
+            "500",
+            "--collateral-asset",
+            ASSET,
+        ])?;
+        match borrow {
+            ContractCallInput::AssetLendingPool(AssetLendingPoolFunctionsInput::Borrow(args)) => {
+                assert_eq!(args.contract_id, "0.0.2001".parse()?);
+                assert_eq!(args.user, USER.parse()?);
+                assert_eq!(args.collateral_amount, U256::from(500u64));
+            }
+            other => panic!("unexpected input {:?}", other),
+        }
+
+        let batch = parse_input(&[
+            "access",
+            "grant-access-batch",
+            "--level",
+            "1",
+            "--accounts",
+            &format!("{},{}", USER, ASSET),
+        ])?;
+        match batch {
+            ContractCallInput::AccessController(AccessControllerFunctionsInput::GrantAccessBatch(
+                args,
+            )) => assert_eq!(args.accounts.len(), 2),
+            other => panic!("unexpected input {:?}", other),
+        }
+
+        assert!(parse_input(&["lending", "borrow", "--pool", "0.0.2001"]).is_err());
+        let call = ["call", "--contract-id", "0.0.1", "--artifact", "A", "--function", "f"];
+        assert!(parse_input(&[&call[..], &["--args", "{}"]].concat()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn the_wallet_comes_from_the_flags_parsed_with_the_command() -> Result<()> {
+        let key = PrivateKey::generate_ed25519().to_string_der();
+        let cli = CradleCli::try_parse_from([
+            "cradle",
+            "--operator-account-id",
+            "0.0.2",
+            "--operator-key",
+            key.as_str(),
+            "--network",
+            "testnet",
+            "--json",
+            "access",
+            "has-access",
+            "--level",
+            "1",
+            "--account",
+            USER,
+        ])?;
+        assert!(cli.command.is_some());
+        assert!(!format!("{:?}", cli).contains(&key));
+
+        let wallet = cli.wallet()?;
+        assert_eq!(wallet.account_id, "0.0.2");
+        assert_eq!(wallet.network, CradleNetwork::Testnet);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exit_codes_follow_the_error_kind() -> Result<()> {
+        let (backend, mut wallet) = mock_wallet();
+        let cli = CradleCli::try_parse_from([
+            "cradle",
+            "--json",
+            "settler",
+            "settle-order",
+            "--bidder",
+            USER,
+            "--asker",
+            USER,
+            "--bid-asset",
+            ASSET,
+            "--ask-asset",
+            ASSET,
+            "--bid-asset-amount",
+            "1",
+            "--ask-asset-amount",
+            "1",
+        ])?;
+        assert!(cli.json);
+        let input = cli.command.map(ContractCommand::into_input).expect("a command")?;
+
+        let mut reason = ContractFunctionParameters::new();
+        reason.add_string("order expired");
+        let mut data = ERROR_STRING_SELECTOR.to_vec();
+        data.extend(reason.to_bytes(None));
+        backend.push_revert(data);
+        let err = wallet.execute(input.clone()).await.expect_err("reverted");
+        assert_eq!(exit_code_of(&err), exit_code::REVERT);
+
+        backend.push_precheck(Status::InsufficientPayerBalance);
+        let err = wallet.execute(input).await.expect_err("rejected");
+        assert_eq!(exit_code_of(&err), exit_code::TRANSACTION);
+
+        assert_eq!(exit_code_of(&CradleError::config("no operator")), exit_code::CONFIG);
+
+        Ok(())
+    }
+}
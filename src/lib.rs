@@ -2,6 +2,10 @@ pub mod utils;
 
 pub mod wallet;
 
+pub mod server;
+
+pub mod cli;
+
 pub use hedera;
 
 pub mod macros;
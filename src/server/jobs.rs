@@ -0,0 +1,73 @@
+use crate::server::ApiError;
+use crate::utils::functions::ContractCallOutput;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+/// How long finished jobs can still be looked up.
+const RETENTION: Duration = Duration::hours(1);
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum JobStatus {
+    Running,
+    Succeeded { output: ContractCallOutput },
+    Failed { error: ApiError },
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct Job {
+    pub id: Uuid,
+    #[serde(flatten)]
+    pub status: JobStatus,
+    /// Unix timestamps.
+    pub created_at: i64,
+    pub finished_at: Option<i64>,
+}
+
+/// Calls run in the background for clients that shouldn't hold a request open until a write
+/// reaches consensus. Kept in memory only; a restarted server has forgotten its jobs.
+#[derive(Debug, Default)]
+pub struct JobStore {
+    jobs: Mutex<HashMap<Uuid, Job>>,
+}
+
+impl JobStore {
+    /// Registers a running job, or `None` while `max_running` jobs are running already.
+    pub fn start(&self, max_running: usize) -> Option<Job> {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let job = Job {
+            id: Uuid::new_v4(),
+            status: JobStatus::Running,
+            created_at: now,
+            finished_at: None,
+        };
+
+        let mut jobs = self.jobs.lock().unwrap();
+        let expired = now - RETENTION.whole_seconds();
+        jobs.retain(|_, job| job.finished_at.is_none_or(|finished_at| finished_at > expired));
+        let running = jobs.values().filter(|job| job.finished_at.is_none()).count();
+        if running >= max_running {
+            return None;
+        }
+        jobs.insert(job.id, job.clone());
+
+        Some(job)
+    }
+
+    pub fn finish(&self, id: Uuid, result: Result<ContractCallOutput, ApiError>) {
+        if let Some(job) = self.jobs.lock().unwrap().get_mut(&id) {
+            job.status = match result {
+                Ok(output) => JobStatus::Succeeded { output },
+                Err(error) => JobStatus::Failed { error },
+            };
+            job.finished_at = Some(OffsetDateTime::now_utc().unix_timestamp());
+        }
+    }
+
+    pub fn get(&self, id: &Uuid) -> Option<Job> {
+        self.jobs.lock().unwrap().get(id).cloned()
+    }
+}
@@ -0,0 +1,475 @@
+pub mod jobs;
+
+use crate::server::jobs::JobStore;
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::utils::functions::{ContractCallInput, ContractCallOutput};
+use crate::wallet::backend::ContractBackend;
+use crate::wallet::read_only::ReadOnlyBackend;
+use crate::wallet::wallet::{ActionWallet, ActionWalletArgs};
+use axum::extract::{Path, Request, State};
+use axum::http::StatusCode;
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use clap::Parser;
+use futures::FutureExt;
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::net::SocketAddr;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Header carrying the api key of `/v1` requests.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+pub const DEFAULT_MAX_RUNNING_JOBS: usize = 16;
+
+#[derive(Clone, Debug)]
+pub struct ServerConfig {
+    pub api_keys: Vec<String>,
+    /// Refuse calls that submit a transaction, serving queries only.
+    pub read_only: bool,
+    /// Jobs allowed to run at once. Further jobs are turned away with `429`.
+    pub max_running_jobs: usize,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            api_keys: Vec::new(),
+            read_only: false,
+            max_running_jobs: DEFAULT_MAX_RUNNING_JOBS,
+        }
+    }
+}
+
+impl ServerConfig {
+    fn accepts(&self, key: &str) -> bool {
+        self.api_keys
+            .iter()
+            .fold(false, |found, api_key| found | constant_time_eq(api_key, key))
+    }
+}
+
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len() && a.bytes().zip(b.bytes()).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+/// Serves contract calls as json over http, for services that can't link the crate, e.g.
+///
+/// `curl -H "x-api-key: $KEY" -d '{"AccessController":{"HasAccess":{...}}}' localhost:8080/v1/call`
+///
+/// The operator and network come from the same flags and variables as every other binary.
+#[derive(Parser)]
+pub struct ServerArgs {
+    #[clap(long, env = "CRADLE_SERVER_ADDRESS", default_value = "127.0.0.1:8080")]
+    pub address: SocketAddr,
+    /// Keys accepted in the `x-api-key` header, comma separated.
+    #[clap(long, env = "CRADLE_API_KEYS", value_delimiter = ',', required = true)]
+    pub api_keys: Vec<String>,
+    /// Serve queries only, refusing every call that submits a transaction.
+    #[clap(long, env = "CRADLE_READ_ONLY")]
+    pub read_only: bool,
+    /// Background jobs allowed to run at once.
+    #[clap(long, env = "CRADLE_MAX_RUNNING_JOBS", default_value_t = DEFAULT_MAX_RUNNING_JOBS)]
+    pub max_running_jobs: usize,
+    #[clap(flatten)]
+    pub wallet: ActionWalletArgs,
+}
+
+impl ServerArgs {
+    /// State to serve, with the wallet built from the operator flags parsed alongside.
+    pub fn state(self) -> CradleResult<ServerState> {
+        let config = ServerConfig {
+            api_keys: self.api_keys,
+            read_only: self.read_only,
+            max_running_jobs: self.max_running_jobs,
+        };
+
+        Ok(ServerState::new(ActionWallet::from_args(self.wallet)?, config))
+    }
+}
+
+/// A failed request as returned to clients, under `error`.
+#[derive(Serialize, Clone, Debug)]
+pub struct ApiError {
+    #[serde(skip)]
+    pub http_status: StatusCode,
+    /// What went wrong, e.g. `revert`, `precheck` or `read_only`.
+    pub kind: String,
+    pub message: String,
+    /// Hedera status of a failed transaction or query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<String>,
+}
+
+impl ApiError {
+    pub fn new(http_status: StatusCode, kind: &str, message: impl Into<String>) -> Self {
+        Self {
+            http_status,
+            kind: kind.to_string(),
+            message: message.into(),
+            status: None,
+            transaction_id: None,
+        }
+    }
+
+    fn unauthorized() -> Self {
+        Self::new(StatusCode::UNAUTHORIZED, "unauthorized", "Missing or unknown api key")
+    }
+
+    fn read_only(request: &str) -> Self {
+        Self::new(
+            StatusCode::FORBIDDEN,
+            "read_only",
+            format!("The server is read-only and {} changes state", request),
+        )
+    }
+
+    fn busy(max_running_jobs: usize) -> Self {
+        Self::new(
+            StatusCode::TOO_MANY_REQUESTS,
+            "busy",
+            format!("{} jobs are running already, retry later", max_running_jobs),
+        )
+    }
+}
+
+impl From<CradleError> for ApiError {
+    fn from(error: CradleError) -> Self {
+        let (http_status, kind) = match &error {
+            CradleError::Revert(_) => (StatusCode::UNPROCESSABLE_ENTITY, "revert"),
+            CradleError::Precheck { .. } => (StatusCode::UNPROCESSABLE_ENTITY, "precheck"),
+            CradleError::Receipt { .. } => (StatusCode::UNPROCESSABLE_ENTITY, "receipt"),
+            CradleError::Config(_) => (StatusCode::BAD_REQUEST, "config"),
+            CradleError::Decoding(_) => (StatusCode::INTERNAL_SERVER_ERROR, "decoding"),
+            CradleError::Mirror(_) => (StatusCode::BAD_GATEWAY, "mirror"),
+            CradleError::Network(_) => (StatusCode::BAD_GATEWAY, "network"),
+            CradleError::Timeout(_) => (StatusCode::GATEWAY_TIMEOUT, "timeout"),
+            CradleError::Other(_) => (StatusCode::INTERNAL_SERVER_ERROR, "internal"),
+        };
+
+        Self {
+            status: error.status().map(|status| format!("{:?}", status)),
+            transaction_id: error.transaction_id().map(|id| id.to_string()),
+            ..Self::new(http_status, kind, error.to_string())
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.http_status, Json(json!({ "error": self }))).into_response()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ServerState {
+    wallet: ActionWallet,
+    config: Arc<ServerConfig>,
+    jobs: Arc<JobStore>,
+}
+
+impl ServerState {
+    pub fn new(wallet: ActionWallet, config: ServerConfig) -> Self {
+        Self {
+            wallet,
+            config: Arc::new(config),
+            jobs: Arc::default(),
+        }
+    }
+
+    /// Runs `input`, with a panicking wrapper failing the call rather than the task running it.
+    async fn run(&self, input: ContractCallInput) -> Result<ContractCallOutput, ApiError> {
+        AssertUnwindSafe(self.run_call(input))
+            .catch_unwind()
+            .await
+            .unwrap_or_else(|panic| Err(CradleError::panicked("The call", panic).into()))
+    }
+
+    /// Read-only servers refuse calls that aren't queries before running them, so nothing is
+    /// paid for a call that is refused. `ReadOnlyBackend` still stops any transaction that
+    /// gets through.
+    async fn run_call(&self, input: ContractCallInput) -> Result<ContractCallOutput, ApiError> {
+        let mut wallet = self.wallet.clone();
+        if !self.config.read_only {
+            return Ok(wallet.execute(input).await?);
+        }
+        if !input.is_query()? {
+            return Err(ApiError::read_only("the call"));
+        }
+
+        let guard = Arc::new(ReadOnlyBackend::new(self.wallet.backend.clone()));
+        wallet.backend = guard.clone() as Arc<dyn ContractBackend>;
+
+        let result = wallet.execute(input).await;
+        match guard.refused() {
+            Some(request) => Err(ApiError::read_only(&request)),
+            None => Ok(result?),
+        }
+    }
+}
+
+/// Routes of the json api:
+///
+/// - `POST /v1/call` runs a json `ContractCallInput` and answers with its `ContractCallOutput`.
+/// - `POST /v1/jobs` starts the call in the background and answers `202` with the job, or
+///   `429` while `max_running_jobs` are running.
+/// - `GET /v1/jobs/{id}` reports a job as `running`, `succeeded` with its output or `failed`.
+/// - `GET /health/live` and `GET /health/ready` for probes, without an api key.
+///
+/// `/v1` requests need one of the configured keys in the `x-api-key` header. Failures come back
+/// as `{"error": ApiError}`.
+pub fn router(state: ServerState) -> Router {
+    let api = Router::new()
+        .route("/v1/call", post(call))
+        .route("/v1/jobs", post(start_job))
+        .route("/v1/jobs/{id}", get(job))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_api_key));
+
+    Router::new()
+        .route("/health/live", get(live))
+        .route("/health/ready", get(ready))
+        .merge(api)
+        .with_state(state)
+}
+
+async fn require_api_key(
+    State(state): State<ServerState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let key = request.headers().get(API_KEY_HEADER).and_then(|key| key.to_str().ok());
+
+    match key {
+        Some(key) if state.config.accepts(key) => next.run(request).await,
+        _ => ApiError::unauthorized().into_response(),
+    }
+}
+
+async fn call(
+    State(state): State<ServerState>,
+    Json(input): Json<ContractCallInput>,
+) -> Result<Json<ContractCallOutput>, ApiError> {
+    Ok(Json(state.run(input).await?))
+}
+
+async fn start_job(
+    State(state): State<ServerState>,
+    Json(input): Json<ContractCallInput>,
+) -> Response {
+    let Some(job) = state.jobs.start(state.config.max_running_jobs) else {
+        return ApiError::busy(state.config.max_running_jobs).into_response();
+    };
+
+    let id = job.id;
+    tokio::spawn(async move {
+        let result = state.run(input).await;
+        state.jobs.finish(id, result);
+    });
+
+    (StatusCode::ACCEPTED, Json(job)).into_response()
+}
+
+async fn job(State(state): State<ServerState>, Path(id): Path<Uuid>) -> Response {
+    match state.jobs.get(&id) {
+        Some(job) => Json(job).into_response(),
+        None => ApiError::new(StatusCode::NOT_FOUND, "not_found", format!("No job {}", id))
+            .into_response(),
+    }
+}
+
+async fn live() -> Json<Value> {
+    Json(json!({ "status": "ok" }))
+}
+
+/// Ready once contract ids are loaded and the mirror node answers.
+async fn ready(State(state): State<ServerState>) -> Result<Json<Value>, ApiError> {
+    state.wallet.get_contract_ids()?;
+    state.wallet.mirror().get::<Value>("/api/v1/network/supply").await?;
+
+    Ok(Json(json!({
+        "status": "ok",
+        "network": state.wallet.network.to_string(),
+        "read_only": state.config.read_only,
+    })))
+}
+
+#[cfg(test)]
+mod server_tests {
+    use super::*;
+    use crate::utils::functions::access_controller::{
+        AccessControllerFunctionsInput, has_access,
+    };
+    use crate::utils::poll::{PollOptions, poll_until};
+    use crate::wallet::mock_backend::RecordedCall;
+    use crate::wallet::test_fixtures::{USER, mock_wallet, settle_order};
+    use anyhow::Result;
+    use hedera::{ContractFunctionParameters, PrivateKey};
+    use tower::ServiceExt;
+
+    async fn serve(router: Router, key: Option<&str>, path: &str, body: Value) -> (u16, Value) {
+        let mut request =
+            axum::http::Request::post(path).header("content-type", "application/json");
+        if let Some(key) = key {
+            request = request.header(API_KEY_HEADER, key);
+        }
+        let request = request.body(axum::body::Body::from(body.to_string())).unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        let status = response.status().as_u16();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+    }
+
+    async fn finished_job(router: Router, job: &Value) -> Result<Value> {
+        let path = format!("/v1/jobs/{}", job["id"].as_str().unwrap_or_default());
+        let job = poll_until("the job", &PollOptions::default(), || {
+            let router = router.clone();
+            let path = path.clone();
+            async move {
+                let request = axum::http::Request::get(path.as_str())
+                    .header(API_KEY_HEADER, "secret")
+                    .body(axum::body::Body::empty())
+                    .unwrap();
+                let response = router.oneshot(request).await.unwrap();
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                let job: Value = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+
+                Ok((job["status"] != json!("running")).then_some(job))
+            }
+        })
+        .await?;
+
+        Ok(job)
+    }
+
+    #[tokio::test]
+    async fn starts_from_its_own_arguments_and_the_operator_flags() -> Result<()> {
+        let key = PrivateKey::generate_ed25519().to_string_der();
+        let args = ServerArgs::try_parse_from([
+            "server",
+            "--address",
+            "127.0.0.1:9090",
+            "--api-keys",
+            "secret,other",
+            "--read-only",
+            "--operator-account-id",
+            "0.0.2",
+            "--operator-key",
+            key.as_str(),
+            "--network",
+            "testnet",
+        ])?;
+        assert_eq!(args.address, "127.0.0.1:9090".parse()?);
+
+        let state = args.state()?;
+        assert_eq!(state.wallet.account_id, "0.0.2");
+        assert_eq!(state.config.api_keys, ["secret", "other"]);
+        assert!(state.config.read_only);
+
+        let request = axum::http::Request::get("/health/live")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        assert_eq!(router(state).oneshot(request).await?.status(), StatusCode::OK);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_only_servers_answer_queries_and_refuse_writes() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+        let config = ServerConfig {
+            api_keys: vec!["secret".to_string()],
+            read_only: true,
+            ..ServerConfig::default()
+        };
+        let router = router(ServerState::new(wallet, config));
+
+        let has_access = serde_json::to_value(ContractCallInput::AccessController(
+            AccessControllerFunctionsInput::HasAccess(has_access::Args {
+                level: 1,
+                account: USER.parse()?,
+            }),
+        ))?;
+        let (status, _) = serve(router.clone(), None, "/v1/call", has_access.clone()).await;
+        assert_eq!(status, 401);
+        let (status, _) =
+            serve(router.clone(), Some("secre"), "/v1/call", has_access.clone()).await;
+        assert_eq!(status, 401);
+
+        let mut returned = ContractFunctionParameters::new();
+        returned.add_bool(true);
+        backend.push_return(returned.to_bytes(None));
+        let (status, output) = serve(router.clone(), Some("secret"), "/v1/call", has_access).await;
+        assert_eq!(status, 200);
+        assert_eq!(output["AccessController"]["HasAccess"]["output"], json!(true));
+
+        let settle = serde_json::to_value(settle_order()?)?;
+        let (status, body) = serve(router, Some("secret"), "/v1/call", settle).await;
+        assert_eq!(status, 403);
+        assert_eq!(body["error"]["kind"], json!("read_only"));
+        assert_eq!(backend.calls().len(), 1);
+        assert!(backend.calls().iter().all(|call| matches!(call, RecordedCall::Query(_))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn jobs_run_calls_in_the_background() -> Result<()> {
+        let (_, wallet) = mock_wallet();
+        let config = ServerConfig {
+            api_keys: vec!["secret".to_string()],
+            read_only: false,
+            ..ServerConfig::default()
+        };
+        let router = router(ServerState::new(wallet, config));
+
+        let settle = serde_json::to_value(settle_order()?)?;
+        let (status, job) = serve(router.clone(), Some("secret"), "/v1/jobs", settle).await;
+        assert_eq!(status, 202);
+
+        let job = finished_job(router, &job).await?;
+        assert_eq!(job["status"], json!("succeeded"));
+        assert!(job["output"]["OrderBookSettler"]["SettleOrder"].is_object());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn panicking_jobs_end_as_failed() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+        backend.push_panic("backend bug");
+        let config = ServerConfig {
+            api_keys: vec!["secret".to_string()],
+            ..ServerConfig::default()
+        };
+        let router = router(ServerState::new(wallet, config));
+
+        let settle = serde_json::to_value(settle_order()?)?;
+        let (status, job) = serve(router.clone(), Some("secret"), "/v1/jobs", settle).await;
+        assert_eq!(status, 202);
+
+        let job = finished_job(router, &job).await?;
+        assert_eq!(job["status"], json!("failed"));
+        assert!(job["error"]["message"].as_str().unwrap_or_default().contains("panicked"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn running_jobs_are_capped() {
+        let jobs = JobStore::default();
+
+        let job = jobs.start(1).expect("a first job starts");
+        assert!(jobs.start(1).is_none());
+
+        jobs.finish(job.id, Err(ApiError::unauthorized()));
+        assert!(jobs.start(1).is_some());
+    }
+}
@@ -0,0 +1,615 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::errors::{CradleError, CradleResult};
+use num_bigint::{BigInt, BigUint, Sign};
+use serde_json::{Map, Value, json};
+use sha3::{Digest, Keccak256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const ARTIFACTS_DIR: &str = "./contracts/out";
+
+const WORD: usize = 32;
+
+pub fn artifact_path(name: &str) -> PathBuf {
+    Path::new(ARTIFACTS_DIR)
+        .join(format!("{}.sol", name))
+        .join(format!("{}.json", name))
+}
+
+/// Reads the `abi` array out of a forge artifact in `contracts/out`.
+pub fn load_abi(name: &str) -> CradleResult<Vec<Value>> {
+    load_abi_from_path(&artifact_path(name))
+}
+
+pub fn load_abi_from_path(path: &Path) -> CradleResult<Vec<Value>> {
+    let data = fs::read_to_string(path).map_err(|e| {
+        CradleError::config(format!("Failed to read artifact {}: {}", path.display(), e))
+    })?;
+
+    let artifact: Value = serde_json::from_str(&data).map_err(|e| {
+        CradleError::decoding(format!("Invalid artifact {}: {}", path.display(), e))
+    })?;
+
+    artifact
+        .get("abi")
+        .and_then(|abi| abi.as_array())
+        .cloned()
+        .ok_or_else(|| CradleError::decoding(format!("No abi in artifact {}", path.display())))
+}
+
+/// Every artifact json under `contracts/out`, used when the contract behind a call is unknown.
+pub fn artifact_paths() -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+
+    let Ok(dirs) = fs::read_dir(ARTIFACTS_DIR) else {
+        return paths;
+    };
+
+    for dir in dirs.flatten() {
+        let Ok(files) = fs::read_dir(dir.path()) else {
+            continue;
+        };
+
+        for file in files.flatten() {
+            let path = file.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                paths.push(path);
+            }
+        }
+    }
+
+    paths
+}
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+pub fn selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Canonical `name(type,...)` signature of an abi function, event or error entry.
+pub fn signature(item: &Value) -> CradleResult<String> {
+    let name = item
+        .get("name")
+        .and_then(|n| n.as_str())
+        .ok_or_else(|| CradleError::decoding("Abi entry has no name"))?;
+
+    let inputs = params(item, "inputs")
+        .iter()
+        .map(|p| ParamType::from_abi(p).map(|t| t.canonical()))
+        .collect::<CradleResult<Vec<String>>>()?;
+
+    Ok(format!("{}({})", name, inputs.join(",")))
+}
+
+pub fn params<'a>(item: &'a Value, key: &str) -> &'a [Value] {
+    item.get(key)
+        .and_then(|p| p.as_array())
+        .map(|p| p.as_slice())
+        .unwrap_or(&[])
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParamType {
+    Address,
+    Bool,
+    Uint(usize),
+    Int(usize),
+    FixedBytes(usize),
+    Bytes,
+    String,
+    Array(Box<ParamType>),
+    FixedArray(Box<ParamType>, usize),
+    Tuple(Vec<(String, ParamType)>),
+}
+
+impl ParamType {
+    /// Builds the type from an abi parameter, picking up tuple `components`.
+    pub fn from_abi(param: &Value) -> CradleResult<Self> {
+        let kind = param
+            .get("type")
+            .and_then(|t| t.as_str())
+            .ok_or_else(|| CradleError::decoding("Abi parameter has no type"))?;
+
+        Self::parse(kind, param)
+    }
+
+    fn parse(kind: &str, param: &Value) -> CradleResult<Self> {
+        if let Some(inner) = kind.strip_suffix("[]") {
+            return Ok(ParamType::Array(Box::new(Self::parse(inner, param)?)));
+        }
+
+        if kind.ends_with(']') {
+            let open = kind
+                .rfind('[')
+                .ok_or_else(|| CradleError::decoding(format!("Invalid abi type {}", kind)))?;
+            let size: usize = kind[open + 1..kind.len() - 1]
+                .parse()
+                .map_err(|_| CradleError::decoding(format!("Invalid abi type {}", kind)))?;
+
+            return Ok(ParamType::FixedArray(
+                Box::new(Self::parse(&kind[..open], param)?),
+                size,
+            ));
+        }
+
+        let parsed = match kind {
+            "address" => ParamType::Address,
+            "bool" => ParamType::Bool,
+            "string" => ParamType::String,
+            "bytes" => ParamType::Bytes,
+            "uint" => ParamType::Uint(256),
+            "int" => ParamType::Int(256),
+            "tuple" => {
+                let components = params(param, "components")
+                    .iter()
+                    .map(|c| {
+                        let name = c.get("name").and_then(|n| n.as_str()).unwrap_or_default();
+                        Ok((name.to_string(), ParamType::from_abi(c)?))
+                    })
+                    .collect::<CradleResult<Vec<_>>>()?;
+
+                ParamType::Tuple(components)
+            }
+            _ => {
+                let bits = |prefix: &str| kind.strip_prefix(prefix).and_then(|b| b.parse().ok());
+
+                if let Some(size) = bits("uint") {
+                    ParamType::Uint(size)
+                } else if let Some(size) = bits("int") {
+                    ParamType::Int(size)
+                } else if let Some(size) = bits("bytes") {
+                    ParamType::FixedBytes(size)
+                } else {
+                    return Err(CradleError::decoding(format!("Unsupported abi type {}", kind)));
+                }
+            }
+        };
+
+        Ok(parsed)
+    }
+
+    pub fn canonical(&self) -> String {
+        match self {
+            ParamType::Address => "address".to_string(),
+            ParamType::Bool => "bool".to_string(),
+            ParamType::Uint(size) => format!("uint{}", size),
+            ParamType::Int(size) => format!("int{}", size),
+            ParamType::FixedBytes(size) => format!("bytes{}", size),
+            ParamType::Bytes => "bytes".to_string(),
+            ParamType::String => "string".to_string(),
+            ParamType::Array(inner) => format!("{}[]", inner.canonical()),
+            ParamType::FixedArray(inner, size) => format!("{}[{}]", inner.canonical(), size),
+            ParamType::Tuple(components) => {
+                let inner: Vec<String> = components.iter().map(|(_, t)| t.canonical()).collect();
+                format!("({})", inner.join(","))
+            }
+        }
+    }
+
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            ParamType::Bytes | ParamType::String | ParamType::Array(_) => true,
+            ParamType::FixedArray(inner, _) => inner.is_dynamic(),
+            ParamType::Tuple(components) => components.iter().any(|(_, t)| t.is_dynamic()),
+            _ => false,
+        }
+    }
+
+    /// Size of the type in the head of an encoding.
+    fn head_size(&self) -> usize {
+        if self.is_dynamic() {
+            return WORD;
+        }
+
+        match self {
+            ParamType::FixedArray(inner, size) => inner.head_size() * size,
+            ParamType::Tuple(components) => components.iter().map(|(_, t)| t.head_size()).sum(),
+            _ => WORD,
+        }
+    }
+}
+
+/// Decodes abi encoded `data` against a list of abi parameters, returning one json value per
+/// parameter. Integers are returned as decimal strings so uint256 values survive the trip.
+pub fn decode_params(params: &[Value], data: &[u8]) -> CradleResult<Vec<Value>> {
+    let types = params
+        .iter()
+        .map(ParamType::from_abi)
+        .collect::<CradleResult<Vec<_>>>()?;
+
+    decode(&types, data)
+}
+
+pub fn decode(types: &[ParamType], data: &[u8]) -> CradleResult<Vec<Value>> {
+    decode_sequence(types, data, 0)
+}
+
+/// Decodes the return value in head slot `index`, indexed the way the sdk result getters are.
+pub fn decode_slot(kind: &ParamType, data: &[u8], index: usize) -> CradleResult<Value> {
+    let head = index * WORD;
+
+    if kind.is_dynamic() {
+        decode_value(kind, data, read_usize(data, head)?)
+    } else {
+        decode_value(kind, data, head)
+    }
+}
+
+/// Abi encodes json `values` against a list of abi parameters. Integers may be json numbers or
+/// decimal / `0x` hex strings, tuples either arrays or objects keyed by component name.
+pub fn encode_params(params: &[Value], values: &[Value]) -> CradleResult<Vec<u8>> {
+    let types = params
+        .iter()
+        .map(ParamType::from_abi)
+        .collect::<CradleResult<Vec<_>>>()?;
+
+    encode(&types, values)
+}
+
+pub fn encode(types: &[ParamType], values: &[Value]) -> CradleResult<Vec<u8>> {
+    if types.len() != values.len() {
+        return Err(CradleError::decoding(format!(
+            "Expected {} abi values, got {}",
+            types.len(),
+            values.len()
+        )));
+    }
+
+    encode_sequence(types, values)
+}
+
+/// Selector followed by the encoded arguments of an abi function entry.
+pub fn encode_function_call(item: &Value, values: &[Value]) -> CradleResult<Vec<u8>> {
+    let mut data = selector(&signature(item)?).to_vec();
+    data.extend(encode_params(params(item, "inputs"), values)?);
+
+    Ok(data)
+}
+
+/// Names each decoded value after its abi parameter, falling back to the position.
+pub fn named_values(params: &[Value], values: Vec<Value>) -> Value {
+    let mut map = Map::new();
+
+    for (index, value) in values.into_iter().enumerate() {
+        let name = params
+            .get(index)
+            .and_then(|p| p.get("name"))
+            .and_then(|n| n.as_str())
+            .filter(|n| !n.is_empty())
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| index.to_string());
+
+        map.insert(name, value);
+    }
+
+    Value::Object(map)
+}
+
+fn decode_sequence(types: &[ParamType], data: &[u8], base: usize) -> CradleResult<Vec<Value>> {
+    let mut values = Vec::with_capacity(types.len());
+    let mut head = base;
+
+    for kind in types {
+        let value = if kind.is_dynamic() {
+            let offset = read_usize(data, head)?;
+            decode_value(kind, data, base + offset)?
+        } else {
+            decode_value(kind, data, head)?
+        };
+
+        values.push(value);
+        head += kind.head_size();
+    }
+
+    Ok(values)
+}
+
+fn decode_value(kind: &ParamType, data: &[u8], at: usize) -> CradleResult<Value> {
+    let value = match kind {
+        ParamType::Address => {
+            let word = read_word(data, at)?;
+            json!(format!("0x{}", hex::encode(&word[12..])))
+        }
+        ParamType::Bool => json!(read_word(data, at)?[31] != 0),
+        ParamType::Uint(_) => json!(BigUint::from_bytes_be(read_word(data, at)?).to_string()),
+        ParamType::Int(_) => {
+            let word = read_word(data, at)?;
+            let value = if word[0] & 0x80 != 0 {
+                let inverted: Vec<u8> = word.iter().map(|b| !b).collect();
+                -(BigInt::from_bytes_be(Sign::Plus, &inverted) + 1u32)
+            } else {
+                BigInt::from_bytes_be(Sign::Plus, word)
+            };
+            json!(value.to_string())
+        }
+        ParamType::FixedBytes(size) => {
+            let word = read_word(data, at)?;
+            json!(format!("0x{}", hex::encode(&word[..(*size).min(WORD)])))
+        }
+        ParamType::Bytes => json!(format!("0x{}", hex::encode(read_dynamic(data, at)?))),
+        ParamType::String => json!(String::from_utf8_lossy(read_dynamic(data, at)?)),
+        ParamType::Array(inner) => {
+            let length = read_usize(data, at)?;
+            let types = vec![inner.as_ref().clone(); length];
+            Value::Array(decode_sequence(&types, data, at + WORD)?)
+        }
+        ParamType::FixedArray(inner, size) => {
+            let types = vec![inner.as_ref().clone(); *size];
+            Value::Array(decode_sequence(&types, data, at)?)
+        }
+        ParamType::Tuple(components) => {
+            let types: Vec<ParamType> = components.iter().map(|(_, t)| t.clone()).collect();
+            let values = decode_sequence(&types, data, at)?;
+
+            if components.iter().all(|(name, _)| !name.is_empty()) {
+                let map = components
+                    .iter()
+                    .map(|(name, _)| name.clone())
+                    .zip(values)
+                    .collect::<Map<String, Value>>();
+                Value::Object(map)
+            } else {
+                Value::Array(values)
+            }
+        }
+    };
+
+    Ok(value)
+}
+
+fn encode_sequence(types: &[ParamType], values: &[Value]) -> CradleResult<Vec<u8>> {
+    let head_size: usize = types.iter().map(|t| t.head_size()).sum();
+    let mut head = Vec::with_capacity(head_size);
+    let mut tail = Vec::new();
+
+    for (kind, value) in types.iter().zip(values) {
+        let encoded = encode_value(kind, value)?;
+
+        if kind.is_dynamic() {
+            head.extend(uint_word(&BigUint::from(head_size + tail.len()))?);
+            tail.extend(encoded);
+        } else {
+            head.extend(encoded);
+        }
+    }
+
+    head.extend(tail);
+    Ok(head)
+}
+
+fn encode_value(kind: &ParamType, value: &Value) -> CradleResult<Vec<u8>> {
+    let invalid = || CradleError::decoding(format!("Invalid {} value {}", kind.canonical(), value));
+
+    let encoded = match kind {
+        ParamType::Address => {
+            let address = value.as_str().ok_or_else(invalid)?;
+            let bytes = address_bytes(address).ok_or_else(invalid)?;
+
+            let mut word = vec![0u8; 12];
+            word.extend(bytes);
+            word
+        }
+        ParamType::Bool => {
+            let flag = match value {
+                Value::Bool(flag) => *flag,
+                Value::String(flag) => flag.parse().map_err(|_| invalid())?,
+                _ => return Err(invalid()),
+            };
+
+            let mut word = vec![0u8; WORD];
+            word[31] = flag as u8;
+            word
+        }
+        ParamType::Uint(size) => {
+            let number = parse_int(value).ok_or_else(invalid)?;
+            let number = number.to_biguint().ok_or_else(invalid)?;
+
+            if number.bits() > *size as u64 {
+                return Err(invalid());
+            }
+
+            uint_word(&number)?
+        }
+        ParamType::Int(size) => {
+            let number = parse_int(value).ok_or_else(invalid)?;
+
+            if number.bits() >= *size as u64 && number != -(BigInt::from(1) << (*size - 1)) {
+                return Err(invalid());
+            }
+
+            let mut word = vec![if number.sign() == Sign::Minus { 0xff } else { 0 }; WORD];
+            let bytes = number.to_signed_bytes_be();
+            word[WORD - bytes.len()..].copy_from_slice(&bytes);
+            word
+        }
+        ParamType::FixedBytes(size) => {
+            let bytes = hex_value(value).ok_or_else(invalid)?;
+
+            if bytes.len() > *size || *size > WORD {
+                return Err(invalid());
+            }
+
+            let mut word = bytes;
+            word.resize(WORD, 0);
+            word
+        }
+        ParamType::Bytes => {
+            let bytes = hex_value(value).ok_or_else(invalid)?;
+            dynamic_bytes(bytes)?
+        }
+        ParamType::String => {
+            let string = value.as_str().ok_or_else(invalid)?;
+            dynamic_bytes(string.as_bytes().to_vec())?
+        }
+        ParamType::Array(inner) => {
+            let items = value.as_array().ok_or_else(invalid)?;
+            let types = vec![inner.as_ref().clone(); items.len()];
+
+            let mut encoded = uint_word(&BigUint::from(items.len()))?;
+            encoded.extend(encode_sequence(&types, items)?);
+            encoded
+        }
+        ParamType::FixedArray(inner, size) => {
+            let items = value.as_array().filter(|i| i.len() == *size).ok_or_else(invalid)?;
+            let types = vec![inner.as_ref().clone(); *size];
+
+            encode_sequence(&types, items)?
+        }
+        ParamType::Tuple(components) => {
+            let types: Vec<ParamType> = components.iter().map(|(_, t)| t.clone()).collect();
+
+            let items = match value {
+                Value::Array(items) if items.len() == components.len() => items.clone(),
+                Value::Object(map) => components
+                    .iter()
+                    .map(|(name, _)| map.get(name).cloned().ok_or_else(invalid))
+                    .collect::<CradleResult<Vec<_>>>()?,
+                _ => return Err(invalid()),
+            };
+
+            encode_sequence(&types, &items)?
+        }
+    };
+
+    Ok(encoded)
+}
+
+/// Any form `CradleAddress` accepts, entity ids in long-zero form.
+fn address_bytes(address: &str) -> Option<[u8; 20]> {
+    address.parse::<CradleAddress>().ok().map(|address| address.evm_bytes())
+}
+
+fn parse_int(value: &Value) -> Option<BigInt> {
+    match value {
+        Value::Number(number) => number
+            .as_i64()
+            .map(BigInt::from)
+            .or_else(|| number.as_u64().map(BigInt::from)),
+        Value::String(number) => match number.strip_prefix("0x") {
+            Some(hex_number) => BigInt::parse_bytes(hex_number.as_bytes(), 16),
+            None => number.parse().ok(),
+        },
+        _ => None,
+    }
+}
+
+fn hex_value(value: &Value) -> Option<Vec<u8>> {
+    let data = value.as_str()?;
+    hex::decode(data.strip_prefix("0x").unwrap_or(data)).ok()
+}
+
+fn uint_word(number: &BigUint) -> CradleResult<Vec<u8>> {
+    let bytes = number.to_bytes_be();
+
+    if bytes.len() > WORD {
+        return Err(CradleError::decoding(format!("{} does not fit in a word", number)));
+    }
+
+    let mut word = vec![0u8; WORD - bytes.len()];
+    word.extend(bytes);
+    Ok(word)
+}
+
+fn pad_right(mut bytes: Vec<u8>) -> Vec<u8> {
+    let padded = bytes.len().div_ceil(WORD) * WORD;
+    bytes.resize(padded, 0);
+    bytes
+}
+
+fn dynamic_bytes(bytes: Vec<u8>) -> CradleResult<Vec<u8>> {
+    let mut encoded = uint_word(&BigUint::from(bytes.len()))?;
+    encoded.extend(pad_right(bytes));
+    Ok(encoded)
+}
+
+fn read_word(data: &[u8], at: usize) -> CradleResult<&[u8]> {
+    data.get(at..at + WORD)
+        .ok_or_else(|| CradleError::decoding(format!("Abi data too short, need word at {}", at)))
+}
+
+fn read_usize(data: &[u8], at: usize) -> CradleResult<usize> {
+    let word = read_word(data, at)?;
+
+    if word[..24].iter().any(|b| *b != 0) {
+        return Err(CradleError::decoding(format!("Abi offset at {} out of range", at)));
+    }
+
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&word[24..]);
+    Ok(u64::from_be_bytes(bytes) as usize)
+}
+
+fn read_dynamic(data: &[u8], at: usize) -> CradleResult<&[u8]> {
+    let length = read_usize(data, at)?;
+
+    data.get(at + WORD..at + WORD + length)
+        .ok_or_else(|| CradleError::decoding(format!("Abi data too short, need {} bytes", length)))
+}
+
+#[cfg(test)]
+mod abi_tests {
+    use super::*;
+    use hedera::ContractFunctionParameters;
+
+    const USER: &str = "0x0000000000000000000000000000000000000457";
+    const MAX_U256: &str =
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+
+    #[test]
+    fn encoding_matches_sdk_parameters() -> CradleResult<()> {
+        let item = json!({
+            "type": "function",
+            "name": "borrow",
+            "inputs": [
+                { "name": "user", "type": "address" },
+                { "name": "amount", "type": "uint256" },
+                { "name": "memo", "type": "string" }
+            ]
+        });
+
+        let data = encode_function_call(&item, &[json!(USER), json!(500), json!("loan")])?;
+
+        let mut expected = ContractFunctionParameters::new();
+        expected.add_address(USER);
+        expected.add_uint256(BigUint::from(500u64));
+        expected.add_string("loan");
+        assert_eq!(data, expected.to_bytes(Some("borrow")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn encoding_round_trips_through_decode() -> CradleResult<()> {
+        let types = vec![
+            ParamType::Int(64),
+            ParamType::Array(Box::new(ParamType::Uint(256))),
+            ParamType::Tuple(vec![
+                ("asset".to_string(), ParamType::Address),
+                ("active".to_string(), ParamType::Bool),
+            ]),
+        ];
+        let values = vec![
+            json!("-42"),
+            json!(["1", MAX_U256]),
+            json!({ "asset": USER, "active": true }),
+        ];
+
+        assert_eq!(decode(&types, &encode(&types, &values)?)?, values);
+
+        Ok(())
+    }
+
+    #[test]
+    fn entity_ids_encode_as_long_zero_addresses() -> CradleResult<()> {
+        assert_eq!(
+            encode(&[ParamType::Address], &[json!("0.0.1111")])?,
+            encode(&[ParamType::Address], &[json!(USER)])?
+        );
+
+        Ok(())
+    }
+}
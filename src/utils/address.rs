@@ -0,0 +1,263 @@
+use crate::utils::errors::{CradleError, CradleResult};
+use hedera::{AccountId, ContractId, TokenId};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// An account, contract or token as the contracts see it. Parsed from a `shard.realm.num` id, a
+/// long-zero evm address (`0x00..04d2`), or an evm alias such as the address of an ECDSA account,
+/// given either as `0x...` or `shard.realm.<hex>`.
+///
+/// Parsing validates the address, so a mistyped one fails before any fee is paid. Serialized as
+/// `shard.realm.num` for entity ids and `0x...` for aliases.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CradleAddress {
+    Entity { shard: u64, realm: u64, num: u64 },
+    Alias([u8; 20]),
+}
+
+impl CradleAddress {
+    pub fn entity(shard: u64, realm: u64, num: u64) -> Self {
+        Self::Entity { shard, realm, num }
+    }
+
+    pub fn is_alias(&self) -> bool {
+        matches!(self, Self::Alias(_))
+    }
+
+    /// An address returned by a contract, as `ContractFunctionResult::get_address` gives it.
+    pub fn from_solidity_address(address: &str) -> CradleResult<Self> {
+        parse_evm_address(address.trim_start_matches("0x")).ok_or_else(|| {
+            CradleError::decoding(format!("Invalid address {} in contract result", address))
+        })
+    }
+
+    /// The 20 bytes the evm sees: the alias itself, or the long-zero form of an entity id.
+    pub fn evm_bytes(&self) -> [u8; 20] {
+        match *self {
+            Self::Alias(bytes) => bytes,
+            Self::Entity { shard, realm, num } => {
+                let mut bytes = [0u8; 20];
+                bytes[..4].copy_from_slice(&(shard as u32).to_be_bytes());
+                bytes[4..12].copy_from_slice(&realm.to_be_bytes());
+                bytes[12..].copy_from_slice(&num.to_be_bytes());
+                bytes
+            }
+        }
+    }
+
+    /// `0x` prefixed, for `ContractFunctionParameters::add_address`.
+    pub fn to_evm_address(&self) -> String {
+        format!("0x{}", self.to_solidity_address())
+    }
+
+    /// Without the `0x` prefix, as `ContractId::to_solidity_address` returns it.
+    pub fn to_solidity_address(&self) -> String {
+        hex::encode(self.evm_bytes())
+    }
+
+    pub fn to_contract_id(&self) -> CradleResult<ContractId> {
+        match *self {
+            Self::Entity { shard, realm, num } => Ok(ContractId::new(shard, realm, num)),
+            Self::Alias(_) => ContractId::from_evm_address(0, 0, &self.to_solidity_address())
+                .map_err(|e| CradleError::config(format!("Invalid contract {}: {}", self, e))),
+        }
+    }
+
+    pub fn to_account_id(&self) -> CradleResult<AccountId> {
+        let id = match *self {
+            Self::Entity { shard, realm, num } => return Ok(AccountId::new(shard, realm, num)),
+            Self::Alias(_) => format!("0.0.{}", self.to_solidity_address()),
+        };
+
+        AccountId::from_str(&id)
+            .map_err(|e| CradleError::config(format!("Invalid account {}: {}", self, e)))
+    }
+
+    /// Tokens have no alias, so only entity ids convert.
+    pub fn to_token_id(&self) -> CradleResult<TokenId> {
+        match *self {
+            Self::Entity { shard, realm, num } => Ok(TokenId::new(shard, realm, num)),
+            Self::Alias(_) => Err(CradleError::config(format!("{} is not a token id", self))),
+        }
+    }
+}
+
+impl FromStr for CradleAddress {
+    type Err = CradleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid =
+            |reason: &str| CradleError::config(format!("Invalid address {}: {}", s, reason));
+
+        if let Some(hex_value) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            return parse_evm_address(hex_value).ok_or_else(|| invalid("expected 20 hex bytes"));
+        }
+
+        let parts: Vec<&str> = s.split('.').collect();
+        let [shard, realm, num] = parts.as_slice() else {
+            return Err(invalid("expected shard.realm.num or an evm address"));
+        };
+
+        let shard: u64 = shard.parse().map_err(|_| invalid("invalid shard"))?;
+        let realm: u64 = realm.parse().map_err(|_| invalid("invalid realm"))?;
+
+        if shard > MAX_LONG_ZERO_SHARD || realm > MAX_LONG_ZERO_REALM {
+            return Err(invalid("shard or realm does not fit in a long-zero evm address"));
+        }
+
+        if num.len() == 40 {
+            // The 20 bytes already hold the shard and realm of a long-zero address
+            if shard != 0 || realm != 0 {
+                return Err(invalid("evm addresses are only supported in 0.0"));
+            }
+            return parse_evm_address(num).ok_or_else(|| invalid("invalid evm address"));
+        }
+
+        let num: u64 = num.parse().map_err(|_| invalid("invalid entity number"))?;
+        Ok(Self::Entity { shard, realm, num })
+    }
+}
+
+/// Largest shard and realm of an entity id, so the long-zero form tells them apart from an
+/// alias: aliases are hashes and are not expected to have six zero bytes in those places.
+const MAX_LONG_ZERO_SHARD: u64 = u16::MAX as u64;
+const MAX_LONG_ZERO_REALM: u64 = u32::MAX as u64;
+
+/// 40 hex characters. Long-zero entity ids hold the shard in the first 4 bytes, the realm in
+/// the next 8 and the number in the last 8.
+fn parse_evm_address(hex_value: &str) -> Option<CradleAddress> {
+    let bytes: [u8; 20] = hex::decode(hex_value).ok()?.try_into().ok()?;
+
+    let shard = u32::from_be_bytes(bytes[..4].try_into().ok()?) as u64;
+    let realm = u64::from_be_bytes(bytes[4..12].try_into().ok()?);
+    if shard <= MAX_LONG_ZERO_SHARD && realm <= MAX_LONG_ZERO_REALM {
+        let num = u64::from_be_bytes(bytes[12..].try_into().ok()?);
+        return Some(CradleAddress::entity(shard, realm, num));
+    }
+
+    Some(CradleAddress::Alias(bytes))
+}
+
+impl Display for CradleAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Entity { shard, realm, num } => write!(f, "{}.{}.{}", shard, realm, num),
+            Self::Alias(_) => write!(f, "{}", self.to_evm_address()),
+        }
+    }
+}
+
+impl From<ContractId> for CradleAddress {
+    fn from(id: ContractId) -> Self {
+        match id.evm_address {
+            Some(bytes) => Self::Alias(bytes),
+            None => Self::entity(id.shard, id.realm, id.num),
+        }
+    }
+}
+
+impl From<TokenId> for CradleAddress {
+    fn from(id: TokenId) -> Self {
+        Self::entity(id.shard, id.realm, id.num)
+    }
+}
+
+impl TryFrom<AccountId> for CradleAddress {
+    type Error = CradleError;
+
+    /// Goes through the string form, which covers evm aliased accounts as `0.0.<hex>`.
+    fn try_from(id: AccountId) -> Result<Self, Self::Error> {
+        id.to_string().parse()
+    }
+}
+
+impl Serialize for CradleAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CradleAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod address_tests {
+    use super::*;
+
+    const ALIAS: &str = "0x5b38da6a701c568545dcfcb03fcb875f56beddc4";
+
+    #[test]
+    fn entity_ids_and_long_zero_addresses_are_the_same_address() -> CradleResult<()> {
+        let from_id: CradleAddress = "0.0.1234".parse()?;
+        let from_evm: CradleAddress = "0x00000000000000000000000000000000000004d2".parse()?;
+
+        assert_eq!(from_id, from_evm);
+        assert_eq!(from_id.to_evm_address(), "0x00000000000000000000000000000000000004d2");
+        assert_eq!(from_id.to_contract_id()?, ContractId::new(0, 0, 1234));
+        assert_eq!(from_id.to_account_id()?, AccountId::new(0, 0, 1234));
+        assert_eq!(from_evm.to_string(), "0.0.1234");
+
+        Ok(())
+    }
+
+    #[test]
+    fn ecdsa_aliases_are_kept_as_evm_addresses() -> CradleResult<()> {
+        let alias: CradleAddress = ALIAS.parse()?;
+        let prefixed: CradleAddress = format!("0.0.{}", &ALIAS[2..]).parse()?;
+
+        assert!(alias.is_alias());
+        assert_eq!(alias, prefixed);
+        assert_eq!(alias.to_evm_address(), ALIAS);
+        assert!(alias.to_token_id().is_err());
+        assert!(format!("1.2.{}", &ALIAS[2..]).parse::<CradleAddress>().is_err());
+        assert!("0.1.00000000000000000000000000000000000004d2".parse::<CradleAddress>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn entity_ids_outside_shard_and_realm_zero_round_trip() -> CradleResult<()> {
+        for id in ["1.2.3", "0.7.1234", "65535.4294967295.18446744073709551615"] {
+            let address: CradleAddress = id.parse()?;
+            let decoded = CradleAddress::from_solidity_address(&address.to_evm_address())?;
+
+            assert_eq!(decoded, address);
+            assert_eq!(decoded.to_string(), id);
+        }
+
+        assert_eq!(
+            CradleAddress::entity(1, 2, 3).to_evm_address(),
+            "0x0000000100000000000000020000000000000003"
+        );
+        assert!("65536.0.1".parse::<CradleAddress>().is_err());
+        assert!("0.4294967296.1".parse::<CradleAddress>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn mistyped_addresses_are_rejected() {
+        for address in ["", "0.0", "0.0.x", "0.0.-1", "0x1234", &ALIAS[..41], "1.2.3.4"] {
+            assert!(address.parse::<CradleAddress>().is_err(), "{} parsed", address);
+        }
+
+        assert!(serde_json::from_value::<CradleAddress>(serde_json::json!("0.0.12a")).is_err());
+    }
+
+    #[test]
+    fn serializes_in_canonical_form() -> serde_json::Result<()> {
+        let address: CradleAddress =
+            serde_json::from_str("\"0x00000000000000000000000000000000000004D2\"")?;
+
+        assert_eq!(serde_json::to_string(&address)?, "\"0.0.1234\"");
+
+        Ok(())
+    }
+}
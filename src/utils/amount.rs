@@ -0,0 +1,306 @@
+use crate::utils::errors::{CradleError, CradleResult};
+use bigdecimal::BigDecimal;
+use num_bigint::{BigInt, BigUint, Sign};
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A solidity `uint256`, used for token amounts, shares, rates and ray-scaled indices.
+/// Serialized as a decimal string so values beyond 2^53 survive json; deserializing also
+/// accepts plain json numbers and `0x` hex strings.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U256(BigUint);
+
+impl U256 {
+    pub const BITS: u64 = 256;
+
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// `type(uint256).max`, e.g. the health factor of a position without debt.
+    pub fn max() -> Self {
+        Self((BigUint::from(1u8) << Self::BITS) - 1u8)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == BigUint::default()
+    }
+
+    pub fn as_biguint(&self) -> &BigUint {
+        &self.0
+    }
+
+    /// For `ContractFunctionParameters::add_uint256`.
+    pub fn to_biguint(&self) -> BigUint {
+        self.0.clone()
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        u64::try_from(&self.0).ok()
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> Self {
+        Self(BigUint::from(value))
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(value: u128) -> Self {
+        Self(BigUint::from(value))
+    }
+}
+
+impl TryFrom<BigUint> for U256 {
+    type Error = CradleError;
+
+    fn try_from(value: BigUint) -> Result<Self, Self::Error> {
+        if value.bits() > Self::BITS {
+            return Err(CradleError::decoding(format!("{} does not fit in a uint256", value)));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl From<U256> for BigUint {
+    fn from(value: U256) -> Self {
+        value.0
+    }
+}
+
+impl FromStr for U256 {
+    type Err = CradleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let value = match s.strip_prefix("0x") {
+            Some(hex_value) => BigUint::parse_bytes(hex_value.as_bytes(), 16),
+            None => BigUint::parse_bytes(s.replace('_', "").as_bytes(), 10),
+        };
+
+        value
+            .ok_or_else(|| CradleError::decoding(format!("Invalid uint256 {}", s)))?
+            .try_into()
+    }
+}
+
+impl Display for U256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for U256 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for U256 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(U256Visitor)
+    }
+}
+
+struct U256Visitor;
+
+impl Visitor<'_> for U256Visitor {
+    type Value = U256;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "a uint256 as a decimal string or a non-negative integer")
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<U256, E> {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<U256, E> {
+        Ok(U256::from(value))
+    }
+
+    fn visit_u128<E: Error>(self, value: u128) -> Result<U256, E> {
+        Ok(U256::from(value))
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<U256, E> {
+        u64::try_from(value)
+            .map(U256::from)
+            .map_err(|_| E::custom(format!("negative uint256 {}", value)))
+    }
+}
+
+/// An amount in a token's base units together with the token's decimals, so it can be read
+/// and shown in human units. Serialized as
+/// `{"raw": "1250000000", "decimals": 8, "formatted": "12.5"}`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(into = "TokenAmountJson", from = "TokenAmountJson")]
+pub struct TokenAmount {
+    pub raw: U256,
+    pub decimals: u8,
+}
+
+impl TokenAmount {
+    pub fn new(raw: U256, decimals: u8) -> Self {
+        Self { raw, decimals }
+    }
+
+    /// Parses a human amount such as `12.5` into base units. Amounts with more fractional
+    /// digits than the token supports are rejected rather than rounded, and so is exponent
+    /// notation such as `1e18`.
+    pub fn from_human(value: &str, decimals: u8) -> CradleResult<Self> {
+        let invalid =
+            |reason: &str| CradleError::decoding(format!("Invalid amount {}: {}", value, reason));
+
+        if value.contains(['e', 'E']) {
+            return Err(invalid("exponent notation"));
+        }
+
+        let parsed = BigDecimal::from_str(value.trim().replace('_', "").as_str())
+            .map_err(|e| invalid(&e.to_string()))?;
+
+        if parsed.sign() == Sign::Minus {
+            return Err(invalid("negative"));
+        }
+
+        let scaled = parsed * BigDecimal::from(BigInt::from(10u8).pow(decimals as u32));
+        if !scaled.is_integer() {
+            return Err(invalid(&format!("more than {} decimals", decimals)));
+        }
+
+        let (digits, _) = scaled.with_scale(0).into_bigint_and_exponent();
+        let raw = digits.to_biguint().ok_or_else(|| invalid("negative"))?.try_into()?;
+
+        Ok(Self { raw, decimals })
+    }
+
+    /// The amount in token units, without trailing zeros, e.g. `12.5`.
+    pub fn to_human(&self) -> String {
+        let unit = BigUint::from(10u8).pow(self.decimals as u32);
+        let whole = self.raw.as_biguint() / &unit;
+        let fraction = self.raw.as_biguint() % &unit;
+
+        if fraction == BigUint::default() {
+            return whole.to_string();
+        }
+
+        let fraction = format!("{:0>width$}", fraction, width = self.decimals as usize);
+        format!("{}.{}", whole, fraction.trim_end_matches('0'))
+    }
+}
+
+impl Display for TokenAmount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_human())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct TokenAmountJson {
+    raw: U256,
+    decimals: u8,
+    /// Informational only, `raw` is authoritative when reading.
+    #[serde(default)]
+    formatted: String,
+}
+
+impl From<TokenAmount> for TokenAmountJson {
+    fn from(amount: TokenAmount) -> Self {
+        Self {
+            formatted: amount.to_human(),
+            raw: amount.raw,
+            decimals: amount.decimals,
+        }
+    }
+}
+
+impl From<TokenAmountJson> for TokenAmount {
+    fn from(json: TokenAmountJson) -> Self {
+        Self::new(json.raw, json.decimals)
+    }
+}
+
+#[cfg(test)]
+mod amount_tests {
+    use super::*;
+    use anyhow::Result;
+    use serde_json::json;
+
+    #[test]
+    fn u256_accepts_numbers_and_strings() -> Result<()> {
+        let from_number: U256 = serde_json::from_value(json!(42))?;
+        let from_string: U256 = serde_json::from_value(json!("42"))?;
+        let from_hex: U256 = "0x2a".parse()?;
+
+        assert_eq!(from_number, from_string);
+        assert_eq!(from_string, from_hex);
+        assert!(serde_json::from_value::<U256>(json!(-1)).is_err());
+        assert!(format!("{}1", U256::max()).parse::<U256>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn token_amounts_convert_between_human_and_base_units() -> Result<()> {
+        let amount = TokenAmount::from_human("12.5", 8)?;
+        assert_eq!(amount.raw, U256::from(1_250_000_000u64));
+        assert_eq!(amount.to_human(), "12.5");
+        assert_eq!(TokenAmount::new(U256::from(7u64), 8).to_string(), "0.00000007");
+        assert_eq!(TokenAmount::from_human("3", 0)?.to_human(), "3");
+
+        assert!(TokenAmount::from_human("0.000000001", 8).is_err());
+        assert!(TokenAmount::from_human("-1", 8).is_err());
+
+        let json = serde_json::to_value(&amount)?;
+        assert_eq!(json, json!({"raw": "1250000000", "decimals": 8, "formatted": "12.5"}));
+
+        Ok(())
+    }
+
+    #[test]
+    fn exponent_notation_is_rejected() {
+        for value in ["1e18", "1E18", "1.5e-3", "2e0"] {
+            assert!(TokenAmount::from_human(value, 18).is_err(), "{} parsed", value);
+        }
+    }
+
+    #[test]
+    fn amounts_beyond_uint256_are_rejected() -> Result<()> {
+        let max = U256::max().to_string();
+        assert_eq!(TokenAmount::from_human(&max, 0)?.raw, U256::max());
+        assert!(TokenAmount::from_human(&max, 1).is_err());
+        assert!(TokenAmount::from_human("1", u8::MAX).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn extra_fractional_digits_are_rejected_not_rounded() -> Result<()> {
+        assert!(TokenAmount::from_human("1.005", 2).is_err());
+        assert!(TokenAmount::from_human("0.999", 2).is_err());
+        assert!(TokenAmount::from_human("0.5", 0).is_err());
+
+        assert_eq!(TokenAmount::from_human("1.500", 2)?.raw, U256::from(150u64));
+        assert_eq!(TokenAmount::from_human("1_000.25", 2)?.raw, U256::from(100_025u64));
+
+        Ok(())
+    }
+
+    #[test]
+    fn formatting_round_trips() -> Result<()> {
+        for (raw, decimals) in [(0u64, 6), (1, 18), (1_000_000, 6), (123_456_789, 4), (10, 0)] {
+            let amount = TokenAmount::new(U256::from(raw), decimals);
+            assert_eq!(TokenAmount::from_human(&amount.to_human(), decimals)?, amount);
+        }
+
+        let max = TokenAmount::new(U256::max(), 18);
+        assert_eq!(TokenAmount::from_human(&max.to_human(), 18)?, max);
+
+        Ok(())
+    }
+}
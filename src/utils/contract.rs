@@ -1,29 +1,33 @@
 use crate::id_to_address;
+use crate::utils::errors::CradleError;
 use crate::utils::functions::access_controller::{
-    AccessControllerArgs, AccessControllerFunctionsInput, AccessControllerFunctionsOutput,
+    AccessControllerFunctionsInput, AccessControllerFunctionsOutput, grant_access,
 };
 use crate::utils::functions::{ContractCallInput, ContractCallOutput};
+use crate::utils::mirror::MirrorClient;
+use crate::utils::poll::{PollOptions, poll_until};
 use crate::utils::script_utils::BaseAssetConstructor;
 use crate::utils::script_utils::DeployOrderBookSettler;
 use crate::utils::script_utils::{
     AssetIssuerConstructor, AssetLendingPoolConstructor, DeployLendingPoolFactory, GetClientArgs,
     NativeAssetIssuerConstructor,
 };
+use crate::wallet::network::CradleNetwork;
 use crate::wallet::wallet::ActionWallet;
 use anyhow::{Result, format_err};
 use clap::Parser;
 use hedera::{
     Client, ContractCreateTransaction, ContractFunctionParameters, ContractId,
-    FileAppendTransaction, FileCreateTransaction, FileId, Hbar,
+    FileAppendTransaction, FileCreateTransaction, FileId, Hbar, TransactionId,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::Path;
-use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 use std::{env, fs};
 use time::{Duration, OffsetDateTime};
-use tokio::time::{Duration as TokioDuration, sleep};
+use tokio::time::Duration as TokioDuration;
 
 #[derive(Deserialize, Serialize, Clone)]
 pub struct Contract {
@@ -80,24 +84,30 @@ impl Contract {
         let (byte_code, abi) = match fs::read_to_string(path) {
             Ok(data) => {
                 let map: HashMap<String, Value> = serde_json::from_str(&data)?;
-                let abi = map.get("abi").unwrap();
-                let bytecode = map.get("bytecode").unwrap();
-
-                let bytecode_object = bytecode.as_object().unwrap();
-
-                let compiled_code = bytecode_object.get("object").unwrap();
-                let compiled_str = compiled_code.as_str().unwrap();
+                let abi = map
+                    .get("abi")
+                    .ok_or_else(|| CradleError::decoding(format!("No abi in {}", file_name)))?;
+
+                let compiled_str = map
+                    .get("bytecode")
+                    .and_then(|bytecode| bytecode.get("object"))
+                    .and_then(|object| object.as_str())
+                    .ok_or_else(|| {
+                        CradleError::decoding(format!("No bytecode object in {}", file_name))
+                    })?;
 
                 (compiled_str.to_string(), abi.clone())
             }
-            Err(_e) => panic!("Failed to load {}", name.clone()),
+            Err(e) => {
+                return Err(CradleError::config(format!("Failed to load {}: {}", name, e)).into());
+            }
         };
 
         Ok(Self::new(name.clone(), byte_code, abi))
     }
 
     pub async fn get_client(&mut self, args: &GetClientArgs) -> Result<Client> {
-        let client = Client::for_name(&args.network)?;
+        let client = args.network.parse::<CradleNetwork>()?.client()?;
         let duration = Duration::minutes(10);
         client.set_request_timeout(Some(duration.try_into()?));
         client.set_max_backoff(TokioDuration::from_secs(30));
@@ -223,7 +233,12 @@ impl Contract {
         Ok(values)
     }
 
-    pub async fn create_file(&mut self, args: &GetClientArgs, client: &Client) -> Result<FileId> {
+    /// Uploads the bytecode, returning the file and the last transaction that wrote to it.
+    pub async fn create_file(
+        &mut self,
+        args: &GetClientArgs,
+        client: &Client,
+    ) -> Result<(FileId, TransactionId)> {
         let expire_in_an_hour = OffsetDateTime::now_utc() + Duration::hours(1);
         let contents_full = self.bytecode.as_bytes();
         println!("File size :: {:?}", contents_full.len());
@@ -240,13 +255,16 @@ impl Contract {
             println!("transaction submitted...");
 
             let file_receipt = file_transaction_response.get_receipt(&client).await?;
-            let new_file_id = file_receipt.file_id.unwrap();
+            let new_file_id = file_receipt
+                .file_id
+                .ok_or_else(|| CradleError::decoding("File id missing from receipt"))?;
 
             println!("Contract bytecode file {new_file_id}");
-            Ok(new_file_id)
+            Ok((new_file_id, file_transaction_response.transaction_id))
         } else {
             let mut offset = 0;
             let mut file_id: Option<FileId> = None;
+            let mut last_transaction = None;
 
             while offset < contents_full.len() {
                 let end = std::cmp::min(offset + CHUNK_SIZE, contents_full.len());
@@ -262,6 +280,7 @@ impl Contract {
                             .await?;
 
                         let receipt = append_tx.get_receipt(&client).await?;
+                        last_transaction = Some(append_tx.transaction_id);
                         // println!("Appended to file ID: {}", fid);
                         // println!("receipt: {:?}", receipt.transaction_id.unwrap());
                     }
@@ -274,8 +293,11 @@ impl Contract {
                             .await?;
 
                         let receipt = create_tx.get_receipt(&client).await?;
-                        let new_file_id = receipt.file_id.unwrap();
+                        let new_file_id = receipt
+                            .file_id
+                            .ok_or_else(|| CradleError::decoding("File id missing from receipt"))?;
                         file_id = Some(new_file_id);
+                        last_transaction = Some(create_tx.transaction_id);
                         println!("Created file with ID: {}", new_file_id);
                     }
                 }
@@ -283,51 +305,103 @@ impl Contract {
                 offset += chunk.len();
             }
 
-            file_id.ok_or(format_err!("File id not found in file"))
+            file_id
+                .zip(last_transaction)
+                .ok_or(format_err!("File id not found in file"))
+        }
+    }
+
+    /// Waits until the mirror node has the last write to the bytecode file, which the contract
+    /// create reads. Asks the mirror node rather than paying for a file info query every poll.
+    pub async fn wait_for_file(
+        &self,
+        args: &GetClientArgs,
+        file_id: FileId,
+        last_transaction: TransactionId,
+    ) -> Result<()> {
+        let mirror = MirrorClient::for_network(&args.network.parse::<CradleNetwork>()?);
+        let what = format!("file {} to hold the bytecode", file_id);
+        let options = PollOptions {
+            interval: TokioDuration::from_secs(1),
+            ..PollOptions::default()
+        };
+
+        let result = poll_until(&what, &options, || {
+            let mirror = &mirror;
+            async move {
+                let transaction = mirror.find_transaction(&last_transaction).await?;
+                Ok(transaction.map(|transaction| transaction.result))
+            }
+        })
+        .await?;
+
+        if result != "SUCCESS" {
+            return Err(format_err!("Writing the bytecode to {} failed: {}", file_id, result));
         }
+
+        Ok(())
     }
 
     pub async fn deploy_contract(&mut self) -> Result<ContractId> {
         let args = GetClientArgs::try_parse()?;
+        // Asked up front, so a missing co-signer doesn't waste the bytecode upload
+        let admin_signers = args.admin_signers()?;
 
         let client = self.get_client(&args).await?;
 
         println!("Client");
 
-        let new_file_id = self.create_file(&args, &client).await?;
+        let (new_file_id, last_transaction) = self.create_file(&args, &client).await?;
         //
         println!("Contract bytecode file {new_file_id}");
 
         let constructor_parameters = self.get_constructor_parameters()?;
 
-        sleep(TokioDuration::from_secs(30)).await;
+        self.wait_for_file(&args, new_file_id, last_transaction).await?;
+
+        let mut transaction = ContractCreateTransaction::new();
+        // Signers can't fail from inside the sdk, so the first failure is kept and returned
+        let failure = Arc::new(Mutex::new(None));
+        for signer in admin_signers {
+            let public_key = signer.public_key();
+            let signing_failure = failure.clone();
+            transaction.sign_with(public_key, move |message| {
+                signer.sign(message).unwrap_or_else(|e| {
+                    signing_failure.lock().unwrap().get_or_insert(e);
+                    Vec::new()
+                })
+            });
+        }
 
-        let contract_id = ContractCreateTransaction::new()
-            .admin_key(args.operator_key.public_key())
+        let response = transaction
+            .admin_key(args.admin_key()?)
             .bytecode_file_id(new_file_id)
             .max_transaction_fee(Hbar::new(400))
             .constructor_parameters(constructor_parameters.to_bytes(None))
             .gas(15_000_000)
             .execute_with_timeout(&client, TokioDuration::from_secs(180))
-            .await?
+            .await;
+        if let Some(e) = failure.lock().unwrap().take() {
+            return Err(e.into());
+        }
+
+        let contract_id = response?
             .get_receipt(&client)
             .await?
             .contract_id
-            .unwrap();
+            .ok_or_else(|| CradleError::decoding("Contract id missing from receipt"))?;
 
         println!("Contract id {contract_id}");
 
         if let Some(access_level) = self.access_level {
             println!("Needs to be added to access controller");
 
-            let mut wallet = ActionWallet::from_env();
-
-            let evm_address = contract_id.to_solidity_address()?;
+            let mut wallet = ActionWallet::from_env()?;
 
             let res = wallet
                 .execute(ContractCallInput::AccessController(
-                    AccessControllerFunctionsInput::GrantAccess(AccessControllerArgs {
-                        account: evm_address,
+                    AccessControllerFunctionsInput::GrantAccess(grant_access::Args {
+                        account: contract_id.into(),
                         level: access_level,
                     }),
                 ))
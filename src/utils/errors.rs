@@ -0,0 +1,293 @@
+use crate::utils::abi;
+use hedera::{Status, TransactionId};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::OnceLock;
+use thiserror::Error;
+
+pub type CradleResult<T> = Result<T, CradleError>;
+
+/// Selector of solidity's builtin `Error(string)`.
+pub const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// Selector of solidity's builtin `Panic(uint256)`.
+pub const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+#[derive(Debug, Error)]
+pub enum CradleError {
+    /// The node rejected the transaction or query before it reached consensus.
+    #[error("precheck failed with status {status:?}")]
+    Precheck {
+        status: Status,
+        transaction_id: Option<TransactionId>,
+    },
+    /// The transaction reached consensus but the receipt carries a failure status.
+    #[error("transaction failed with receipt status {status:?}")]
+    Receipt {
+        status: Status,
+        transaction_id: Option<TransactionId>,
+    },
+    #[error("contract reverted: {0}")]
+    Revert(ContractRevert),
+    #[error("failed to decode response: {0}")]
+    Decoding(String),
+    #[error("invalid configuration: {0}")]
+    Config(String),
+    #[error("mirror node request failed: {0}")]
+    Mirror(String),
+    #[error("timed out waiting for {0}")]
+    Timeout(String),
+    #[error(transparent)]
+    Network(hedera::Error),
+    #[error(transparent)]
+    Other(anyhow::Error),
+}
+
+impl CradleError {
+    pub fn decoding(message: impl Into<String>) -> Self {
+        CradleError::Decoding(message.into())
+    }
+
+    pub fn config(message: impl Into<String>) -> Self {
+        CradleError::Config(message.into())
+    }
+
+    pub fn mirror(message: impl Into<String>) -> Self {
+        CradleError::Mirror(message.into())
+    }
+
+    /// A panic caught with `catch_unwind` while running `what`, kept as an error.
+    pub fn panicked(what: &str, payload: Box<dyn Any + Send>) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+
+        CradleError::Other(anyhow::anyhow!("{} panicked: {}", what, message))
+    }
+
+    pub fn revert(&self) -> Option<&ContractRevert> {
+        match self {
+            CradleError::Revert(revert) => Some(revert),
+            _ => None,
+        }
+    }
+
+    pub fn status(&self) -> Option<Status> {
+        match self {
+            CradleError::Precheck { status, .. } | CradleError::Receipt { status, .. } => {
+                Some(*status)
+            }
+            CradleError::Revert(revert) => Some(revert.status),
+            _ => None,
+        }
+    }
+
+    pub fn transaction_id(&self) -> Option<TransactionId> {
+        match self {
+            CradleError::Precheck { transaction_id, .. }
+            | CradleError::Receipt { transaction_id, .. } => *transaction_id,
+            CradleError::Revert(revert) => revert.transaction_id,
+            _ => None,
+        }
+    }
+}
+
+impl From<hedera::Error> for CradleError {
+    fn from(error: hedera::Error) -> Self {
+        match error {
+            hedera::Error::TransactionPreCheckStatus {
+                status,
+                transaction_id,
+                ..
+            }
+            | hedera::Error::QueryPreCheckStatus {
+                status,
+                transaction_id,
+            }
+            | hedera::Error::QueryPaymentPreCheckStatus {
+                status,
+                transaction_id,
+            } => precheck(status, Some(*transaction_id)),
+            hedera::Error::TransactionNoIdPreCheckStatus { status }
+            | hedera::Error::QueryNoPaymentPreCheckStatus { status } => precheck(status, None),
+            hedera::Error::ReceiptStatus {
+                status,
+                transaction_id,
+            } => CradleError::Receipt {
+                status,
+                transaction_id: transaction_id.map(|id| *id),
+            },
+            other => CradleError::Network(other),
+        }
+    }
+}
+
+/// Keeps an error that already went through `anyhow` matchable as a `CradleError`.
+impl From<anyhow::Error> for CradleError {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<CradleError>() {
+            Ok(error) => error,
+            Err(error) => match error.downcast::<hedera::Error>() {
+                Ok(error) => error.into(),
+                Err(error) => CradleError::Other(error),
+            },
+        }
+    }
+}
+
+fn precheck(status: Status, transaction_id: Option<TransactionId>) -> CradleError {
+    // Queries that revert never produce a record, so the revert data is lost at this point.
+    if status == Status::ContractRevertExecuted {
+        return CradleError::Revert(ContractRevert {
+            transaction_id,
+            status,
+            reason: RevertReason::Unknown(String::new()),
+        });
+    }
+
+    CradleError::Precheck {
+        status,
+        transaction_id,
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ContractRevert {
+    pub transaction_id: Option<TransactionId>,
+    pub status: Status,
+    pub reason: RevertReason,
+}
+
+impl ContractRevert {
+    /// The `Error(string)` message, e.g. `require(ok, "insufficient collateral")`.
+    pub fn message(&self) -> Option<&str> {
+        match &self.reason {
+            RevertReason::Message(message) => Some(message.as_str()),
+            _ => None,
+        }
+    }
+
+    /// True when the contract reverted with the named custom error.
+    pub fn is_error(&self, name: &str) -> bool {
+        matches!(&self.reason, RevertReason::Custom { name: n, .. } if n == name)
+    }
+}
+
+impl Display for ContractRevert {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.transaction_id {
+            Some(transaction_id) => write!(f, "{} ({})", self.reason, transaction_id),
+            None => write!(f, "{}", self.reason),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum RevertReason {
+    /// `Error(string)`
+    Message(String),
+    /// `Panic(uint256)` with the solidity panic code.
+    Panic(u64),
+    /// A custom error matched against the selectors in `contracts/out`.
+    Custom {
+        name: String,
+        selector: String,
+        args: Value,
+    },
+    /// Revert data that could not be matched, hex encoded.
+    Unknown(String),
+}
+
+impl Display for RevertReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RevertReason::Message(message) => write!(f, "{}", message),
+            RevertReason::Panic(code) => write!(f, "panic 0x{:02x}", code),
+            RevertReason::Custom { name, args, .. } => write!(f, "{}{}", name, args),
+            RevertReason::Unknown(data) if data.is_empty() => write!(f, "no revert data"),
+            RevertReason::Unknown(data) => write!(f, "unknown revert data {}", data),
+        }
+    }
+}
+
+impl RevertReason {
+    /// Decodes raw revert data: the builtin `Error(string)` and `Panic(uint256)` first,
+    /// then custom errors declared in any artifact under `contracts/out`.
+    pub fn decode(data: &[u8]) -> Self {
+        if data.len() < 4 {
+            return RevertReason::Unknown(hex_data(data));
+        }
+
+        let (head, body) = data.split_at(4);
+
+        if head == ERROR_STRING_SELECTOR {
+            if let Ok(values) = abi::decode(&[abi::ParamType::String], body) {
+                if let Some(Value::String(message)) = values.into_iter().next() {
+                    return RevertReason::Message(message);
+                }
+            }
+        }
+
+        if head == PANIC_SELECTOR {
+            if let Ok(values) = abi::decode(&[abi::ParamType::Uint(256)], body) {
+                if let Some(code) = values[0].as_str().and_then(|c| c.parse().ok()) {
+                    return RevertReason::Panic(code);
+                }
+            }
+        }
+
+        if let Some(error) = custom_errors().get(head) {
+            let inputs = abi::params(error, "inputs");
+            if let Ok(values) = abi::decode_params(inputs, body) {
+                return RevertReason::Custom {
+                    name: error["name"].as_str().unwrap_or_default().to_string(),
+                    selector: hex_data(head),
+                    args: abi::named_values(inputs, values),
+                };
+            }
+        }
+
+        RevertReason::Unknown(hex_data(data))
+    }
+
+    /// Decodes the `error_message` of a contract function result. Hedera reports reverts as
+    /// hex encoded revert data, other failures as a plain status string.
+    pub fn from_error_message(message: &str) -> Self {
+        match message.strip_prefix("0x").map(hex::decode) {
+            Some(Ok(data)) => Self::decode(&data),
+            _ if message.is_empty() => RevertReason::Unknown(String::new()),
+            _ => RevertReason::Message(message.to_string()),
+        }
+    }
+}
+
+fn hex_data(data: &[u8]) -> String {
+    format!("0x{}", hex::encode(data))
+}
+
+/// Error definitions of every artifact in `contracts/out`, keyed by selector.
+fn custom_errors() -> &'static HashMap<[u8; 4], Value> {
+    static ERRORS: OnceLock<HashMap<[u8; 4], Value>> = OnceLock::new();
+
+    ERRORS.get_or_init(|| {
+        let mut errors = HashMap::new();
+
+        for path in abi::artifact_paths() {
+            let Ok(items) = abi::load_abi_from_path(&path) else {
+                continue;
+            };
+
+            for item in items.iter().filter(|i| i["type"] == "error") {
+                if let Ok(signature) = abi::signature(item) {
+                    errors.insert(abi::selector(&signature), item.clone());
+                }
+            }
+        }
+
+        errors
+    })
+}
@@ -0,0 +1,240 @@
+use crate::utils::abi::{self, ParamType};
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::utils::functions::generated;
+use hedera::ContractLogInfo;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DecodedEvent {
+    pub contract_id: String,
+    pub name: String,
+    pub signature: String,
+    /// Arguments keyed by their abi name. Indexed dynamic values only carry their topic hash.
+    pub args: Value,
+}
+
+impl DecodedEvent {
+    pub fn arg(&self, name: &str) -> Option<&Value> {
+        self.args.get(name)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct EventDefinition {
+    name: String,
+    signature: String,
+    inputs: Vec<(String, ParamType, bool)>,
+}
+
+/// Decodes contract logs into named events using the abi of forge artifacts.
+#[derive(Clone, Debug, Default)]
+pub struct EventDecoder {
+    events: HashMap<[u8; 32], EventDefinition>,
+}
+
+impl EventDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decoder for the given artifacts, e.g. `["AssetLendingPool", "CradleNativeListing"]`.
+    pub fn from_artifacts(names: &[&str]) -> CradleResult<Self> {
+        let mut decoder = Self::new();
+
+        for name in names {
+            decoder.add_abi(&abi::load_abi(name)?)?;
+        }
+
+        Ok(decoder)
+    }
+
+    /// Decoder over the artifacts of the deployed contracts, loaded once. Libraries, tests and
+    /// mocks in `contracts/out` are left out, so an event of theirs with the same topic can't
+    /// take the place of the deployed contract's.
+    pub fn artifacts() -> &'static EventDecoder {
+        static DECODER: OnceLock<EventDecoder> = OnceLock::new();
+
+        DECODER.get_or_init(|| {
+            let mut decoder = EventDecoder::new();
+
+            for name in generated::CONTRACTS {
+                if let Ok(items) = abi::load_abi(name) {
+                    let _ = decoder.add_abi(&items);
+                }
+            }
+
+            decoder
+        })
+    }
+
+    pub fn add_abi(&mut self, items: &[Value]) -> CradleResult<()> {
+        let events = items
+            .iter()
+            .filter(|item| item["type"] == "event" && item["anonymous"] != true);
+
+        for item in events {
+            let signature = abi::signature(item)?;
+
+            let inputs = abi::params(item, "inputs")
+                .iter()
+                .map(|input| {
+                    let name = input["name"].as_str().unwrap_or_default().to_string();
+                    let indexed = input["indexed"].as_bool().unwrap_or(false);
+                    Ok((name, ParamType::from_abi(input)?, indexed))
+                })
+                .collect::<CradleResult<Vec<_>>>()?;
+
+            self.events.insert(
+                abi::keccak256(signature.as_bytes()),
+                EventDefinition {
+                    name: item["name"].as_str().unwrap_or_default().to_string(),
+                    signature,
+                    inputs,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Decodes every log with a known signature, skipping the rest.
+    pub fn decode_logs(&self, logs: &[ContractLogInfo]) -> Vec<DecodedEvent> {
+        logs.iter()
+            .filter_map(|log| self.decode_log(log).ok().flatten())
+            .collect()
+    }
+
+    pub fn decode_log(&self, log: &ContractLogInfo) -> CradleResult<Option<DecodedEvent>> {
+        self.decode(log.contract_id.to_string(), &log.topics, &log.data)
+    }
+
+    pub fn decode(
+        &self,
+        contract_id: String,
+        topics: &[Vec<u8>],
+        data: &[u8],
+    ) -> CradleResult<Option<DecodedEvent>> {
+        let Some(topic) = topics.first() else {
+            return Ok(None);
+        };
+
+        let Some(event) = <[u8; 32]>::try_from(topic.as_slice())
+            .ok()
+            .and_then(|topic| self.events.get(&topic))
+        else {
+            return Ok(None);
+        };
+
+        let data_types: Vec<ParamType> = event
+            .inputs
+            .iter()
+            .filter(|(_, _, indexed)| !indexed)
+            .map(|(_, kind, _)| kind.clone())
+            .collect();
+        let mut data_values = abi::decode(&data_types, data)?.into_iter();
+        let mut indexed_topics = topics.iter().skip(1);
+
+        let mut args = Map::new();
+        for (index, (name, kind, indexed)) in event.inputs.iter().enumerate() {
+            let value = if *indexed {
+                let topic = indexed_topics.next().ok_or_else(|| {
+                    CradleError::decoding(format!("Missing topic for {}", event.signature))
+                })?;
+
+                if kind.is_dynamic() {
+                    Value::String(format!("0x{}", hex::encode(topic)))
+                } else {
+                    abi::decode(std::slice::from_ref(kind), topic)?.remove(0)
+                }
+            } else {
+                data_values.next().unwrap_or(Value::Null)
+            };
+
+            let key = if name.is_empty() {
+                index.to_string()
+            } else {
+                name.clone()
+            };
+            args.insert(key, value);
+        }
+
+        Ok(Some(DecodedEvent {
+            contract_id,
+            name: event.name.clone(),
+            signature: event.signature.clone(),
+            args: Value::Object(args),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod events_tests {
+    use super::*;
+    use serde_json::json;
+
+    fn deposited_abi() -> Vec<Value> {
+        vec![json!({
+            "type": "event",
+            "name": "Deposited",
+            "anonymous": false,
+            "inputs": [
+                { "name": "user", "type": "address", "indexed": true },
+                { "name": "amount", "type": "uint256", "indexed": false },
+                { "name": "note", "type": "string", "indexed": false }
+            ]
+        })]
+    }
+
+    fn word(value: u64) -> Vec<u8> {
+        let mut word = vec![0u8; 32];
+        word[24..].copy_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    #[test]
+    fn decodes_indexed_and_data_arguments() -> CradleResult<()> {
+        let mut decoder = EventDecoder::new();
+        decoder.add_abi(&deposited_abi())?;
+
+        let topic = abi::keccak256(b"Deposited(address,uint256,string)").to_vec();
+        let mut user = vec![0u8; 12];
+        user.extend(hex::decode("0000000000000000000000000000000000000457").unwrap());
+
+        let mut data = word(250);
+        data.extend(word(64));
+        data.extend(word(2));
+        let mut note = b"ok".to_vec();
+        note.resize(32, 0);
+        data.extend(note);
+
+        let event = decoder
+            .decode("0.0.1001".to_string(), &[topic, user], &data)?
+            .expect("event should be known");
+
+        assert_eq!(event.name, "Deposited");
+        assert_eq!(event.arg("amount"), Some(&json!("250")));
+        assert_eq!(
+            event.args,
+            json!({
+                "user": "0x0000000000000000000000000000000000000457",
+                "amount": "250",
+                "note": "ok"
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn skips_unknown_events() -> CradleResult<()> {
+        let decoder = EventDecoder::new();
+
+        let event = decoder.decode("0.0.1001".to_string(), &[vec![1u8; 32]], &[])?;
+        assert!(event.is_none());
+
+        Ok(())
+    }
+}
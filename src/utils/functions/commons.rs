@@ -1,112 +1,48 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::errors::CradleResult;
+use crate::utils::mirror::MirrorClient;
+use crate::utils::poll::{PollOptions, poll_until};
 use crate::wallet::wallet::ActionWallet;
-use anyhow::{Result, anyhow};
-use hedera::{
-    AccountBalance, AccountBalanceQuery, AccountId, Client, ContractId, ContractInfoQuery,
-};
-use serde_json::json;
+use hedera::{AccountBalance, AccountBalanceQuery, AccountId, Client, ContractId};
 use std::str::FromStr;
-use tokio::time::{Duration, sleep};
 
 pub trait ContractFunctionProcessor<Output> {
-    async fn process(&self, wallet: &mut ActionWallet) -> Result<Output>;
+    async fn process(&self, wallet: &mut ActionWallet) -> CradleResult<Output>;
 }
 
-pub async fn get_contract_addresses(contract_id: &str) -> Result<String> {
-    let client = reqwest::Client::new();
-    let url = format!(
-        "https://testnet.mirrornode.hedera.com/api/v1/contracts/{}",
-        contract_id
-    );
+/// `mirror_node_url` is the mirror node base url, see `ActionWallet::mirror_node_url`.
+pub async fn get_contract_addresses(mirror_node_url: &str, contract_id: &str) -> CradleResult<String> {
+    let contract = MirrorClient::new(mirror_node_url)
+        .contract(contract_id)
+        .await?;
 
-    let response = client.get(&url).send().await?;
-
-    let body = response.json::<serde_json::Value>().await?;
-
-    let evm_address = body
-        .get("evm_address")
-        .ok_or_else(|| anyhow!("Failed to extract"))?
-        .as_str()
-        .ok_or_else(|| anyhow!("Unable to retrieve str"))?;
-
-    Ok(String::from(evm_address))
+    Ok(contract.evm_address)
 }
 
-pub async fn get_contract_id_from_evm_address(evm_address: &str) -> Result<ContractId> {
-    sleep(Duration::from_secs(10)).await;
-    // TODO: update to support mainnet and localhost
-    let client = reqwest::Client::new();
-    let url = format!(
-        "https://testnet.mirrornode.hedera.com/api/v1/contracts/{}",
-        evm_address
-    );
-
-    const MAX_RETRIES: u32 = 10;
-    let mut attempt = 0;
+/// Waits until the mirror node has indexed the contract at `evm_address`, e.g. one a factory
+/// just created. Long-zero addresses are converted without a lookup.
+pub async fn get_contract_id_from_evm_address(
+    mirror_node_url: &str,
+    evm_address: &str,
+) -> CradleResult<ContractId> {
+    let address: CradleAddress = evm_address.parse()?;
+    if !address.is_alias() {
+        return Ok(address.to_contract_id()?);
+    }
 
-    loop {
-        attempt += 1;
+    let mirror = MirrorClient::new(mirror_node_url);
+    let what = format!("contract {} on the mirror node", address);
+    let contract = poll_until(&what, &PollOptions::default(), || {
+        mirror.find_contract(address)
+    })
+    .await?;
 
-        match client.get(&url).send().await {
-            Ok(response) => {
-                match response.json::<serde_json::Value>().await {
-                    Ok(body) => {
-                        if let Some(contract_id_str) =
-                            body.get("contract_id").and_then(|v| v.as_str())
-                        {
-                            let contract_id = ContractId::from_str(contract_id_str)?;
-                            println!("Extracted contract id :: {}", contract_id_str);
-                            return Ok(contract_id);
-                        } else {
-                            // Response was valid but missing contract_id - don't retry
-                            anyhow::bail!(
-                                "Failed to find contract_id in Mirror Node response for EVM address: {}",
-                                evm_address
-                            )
-                        }
-                    }
-                    Err(e) if attempt < MAX_RETRIES => {
-                        // Failed to parse JSON - retry with exponential backoff
-                        let backoff_secs = 2u64.pow(attempt - 1);
-                        println!(
-                            "Attempt {} failed to parse response: {}. Retrying in {} seconds...",
-                            attempt, e, backoff_secs
-                        );
-                        sleep(Duration::from_secs(backoff_secs)).await;
-                        continue;
-                    }
-                    Err(e) => {
-                        // Max retries exceeded
-                        anyhow::bail!(
-                            "Failed to get contract_id after {} attempts: {}",
-                            MAX_RETRIES,
-                            e
-                        )
-                    }
-                }
-            }
-            Err(e) if attempt < MAX_RETRIES => {
-                // Request failed - retry with exponential backoff
-                let backoff_secs = 2u64.pow(attempt - 1);
-                println!(
-                    "Attempt {} failed to fetch: {}. Retrying in {} seconds...",
-                    attempt, e, backoff_secs
-                );
-                sleep(Duration::from_secs(backoff_secs)).await;
-                continue;
-            }
-            Err(e) => {
-                // Max retries exceeded
-                anyhow::bail!(
-                    "Failed to fetch contract after {} attempts: {}",
-                    MAX_RETRIES,
-                    e
-                )
-            }
-        }
-    }
+    let contract_id = ContractId::from_str(&contract.contract_id)?;
+    log::debug!("Resolved {} to contract {}", address, contract_id);
+    Ok(contract_id)
 }
 
-pub async fn get_account_balances(client: &Client, account_id: &str) -> Result<AccountBalance> {
+pub async fn get_account_balances(client: &Client, account_id: &str) -> CradleResult<AccountBalance> {
     let account_value = AccountId::from_str(account_id)?;
     let mut q = AccountBalanceQuery::new();
     q.account_id(account_value);
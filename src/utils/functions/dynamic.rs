@@ -0,0 +1,152 @@
+use crate::utils::abi;
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::utils::functions::FunctionCallOutput;
+use crate::utils::functions::commons::ContractFunctionProcessor;
+use crate::wallet::backend::ContractCallRequest;
+use crate::wallet::wallet::ActionWallet;
+use hedera::{ContractId, Hbar};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::str::FromStr;
+
+const GAS: u64 = 10_000_000;
+
+/// Calls any function of a compiled contract, for functions the typed wrappers don't cover.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DynamicCallArgs {
+    pub contract_id: String,
+    /// Artifact name in `contracts/out`, e.g. `AssetLendingPool`.
+    pub artifact: String,
+    /// Function name, or the full `name(type,...)` signature to pick an overload.
+    pub function: String,
+    #[serde(default)]
+    pub args: Vec<Value>,
+    /// Hbar sent with the call, in tinybars.
+    #[serde(default)]
+    pub payable_amount: Option<i64>,
+}
+
+impl DynamicCallArgs {
+    /// Whether the function is a view or pure one, which `call_abi_function` queries.
+    pub fn is_query(&self) -> CradleResult<bool> {
+        let items = abi::load_abi(&self.artifact)?;
+        let item = find_function(&items, &self.function, self.args.len())?;
+
+        Ok(is_view(item))
+    }
+}
+
+impl ContractFunctionProcessor<FunctionCallOutput<Value>> for DynamicCallArgs {
+    async fn process(
+        &self,
+        wallet: &mut ActionWallet,
+    ) -> CradleResult<FunctionCallOutput<Value>> {
+        let items = abi::load_abi(&self.artifact)?;
+        let item = find_function(&items, &self.function, self.args.len())?;
+
+        let output = call_abi_function(
+            wallet,
+            &self.contract_id,
+            item,
+            &self.args,
+            self.payable_amount,
+        )
+        .await?;
+
+        let outputs = abi::params(item, "outputs");
+        Ok(output.map(|values| abi::named_values(outputs, values)))
+    }
+}
+
+fn is_view(item: &Value) -> bool {
+    matches!(item["stateMutability"].as_str(), Some("view" | "pure"))
+}
+
+/// Looks a function up by name or signature. Overloads are told apart by argument count.
+pub fn find_function<'a>(
+    items: &'a [Value],
+    function: &str,
+    arg_count: usize,
+) -> CradleResult<&'a Value> {
+    let function: String = function.chars().filter(|c| !c.is_whitespace()).collect();
+
+    let candidates: Vec<&Value> = items
+        .iter()
+        .filter(|item| item["type"] == "function")
+        .filter(|item| match function.contains('(') {
+            true => abi::signature(item).is_ok_and(|signature| signature == function),
+            false => item["name"] == function.as_str(),
+        })
+        .collect();
+
+    let matching: Vec<&Value> = match candidates.as_slice() {
+        [item] => return Ok(item),
+        [] => {
+            return Err(CradleError::config(format!(
+                "No function {} in abi",
+                function
+            )));
+        }
+        _ => candidates
+            .into_iter()
+            .filter(|item| abi::params(item, "inputs").len() == arg_count)
+            .collect(),
+    };
+
+    match matching.as_slice() {
+        [item] => Ok(item),
+        _ => Err(CradleError::config(format!(
+            "Function {} is overloaded, call it by signature",
+            function
+        ))),
+    }
+}
+
+/// Encodes `values` against the abi entry `item` and calls it. View and pure functions go
+/// through a query, everything else through a transaction whose record holds the return value.
+/// The output holds the decoded return values, or nothing when the function returns nothing.
+pub async fn call_abi_function(
+    wallet: &mut ActionWallet,
+    contract_id: &str,
+    item: &Value,
+    values: &[Value],
+    payable_amount: Option<i64>,
+) -> CradleResult<FunctionCallOutput<Vec<Value>>> {
+    let contract_id = ContractId::from_str(contract_id)
+        .map_err(|e| CradleError::config(format!("Invalid contract id {}: {}", contract_id, e)))?;
+    let name = item["name"].as_str().unwrap_or_default();
+
+    let mut transaction = ContractCallRequest::new();
+    transaction.contract_id(contract_id);
+    transaction.gas(GAS);
+    transaction.function(name);
+    transaction.function_parameters(abi::encode_function_call(item, values)?);
+
+    if let Some(amount) = payable_amount {
+        transaction.payable_amount(Hbar::from_tinybars(amount));
+    }
+
+    let outputs = abi::params(item, "outputs");
+    let decode = |data: &[u8]| match outputs.is_empty() {
+        true => Ok(None),
+        false => abi::decode_params(outputs, data).map(Some),
+    };
+
+    if is_view(item) {
+        let response = wallet.query_contract(&transaction).await?;
+        let output = decode(&response.result.bytes)?;
+
+        return Ok(FunctionCallOutput::from_query(&response, output));
+    }
+
+    let transaction_id = wallet.execute_contract(&transaction).await?;
+    let record = wallet.get_record(&transaction_id).await?;
+
+    let output = match record.contract_function_result.as_ref() {
+        Some(result) => decode(&result.bytes)?,
+        None if outputs.is_empty() => None,
+        None => return Err(CradleError::decoding("Failed to find contract result")),
+    };
+
+    Ok(FunctionCallOutput::from_record(&record, output))
+}
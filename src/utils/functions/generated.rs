@@ -0,0 +1,166 @@
+//! Bindings generated at build time from the abi in `contracts/out`, one module per artifact
+//! holding one module per function, e.g. `generated::asset_lending_pool::get_pool_stats`. Each
+//! has the function `NAME` and `SIGNATURE`, `params(..)` encoding the inputs, an `Args` struct
+//! holding them and `decode(..)` reading the return values. The artifact module also has the
+//! `*FunctionsInput` and `*FunctionsOutput` enums, e.g. `AssetLendingPoolFunctionsInput`, which
+//! run through `ContractFunctionProcessor` like any other wrapper.
+
+use crate::utils::abi::{self, ParamType};
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::{TokenAmount, U256};
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::utils::functions::FunctionCallOutput;
+use crate::utils::functions::commons::ContractFunctionProcessor;
+use crate::wallet::backend::ContractCallRequest;
+use crate::wallet::wallet::ActionWallet;
+use hedera::{ContractFunctionParameters, ContractFunctionResult, ContractId, Hbar};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// Only used by bindings of functions taking or returning wide signed integers.
+#[allow(unused_imports)]
+use num_bigint::BigInt;
+
+/// Gas a generated call starts from, before the wallet's gas policy adjusts it.
+const EXECUTE_GAS: u64 = 10_000_000;
+const QUERY_GAS: u64 = 5_000_000;
+
+pub fn missing_value(signature: &str, index: usize) -> CradleError {
+    CradleError::decoding(format!("Missing return value {} of {}", index, signature))
+}
+
+pub fn add_address_array(params: &mut ContractFunctionParameters, addresses: &[CradleAddress]) {
+    let addresses: Vec<String> = addresses.iter().map(|a| a.to_evm_address()).collect();
+    let addresses: Vec<&str> = addresses.iter().map(|a| a.as_str()).collect();
+    params.add_address_array(&addresses);
+}
+
+pub fn add_uint256_array(params: &mut ContractFunctionParameters, values: &[U256]) {
+    let values: Vec<_> = values.iter().map(|v| v.to_biguint()).collect();
+    params.add_uint256_array(&values);
+}
+
+/// Request calling `function_name` with `params`, with `payable_amount` tinybars attached.
+pub fn call_request(
+    contract_id: ContractId,
+    function_name: &str,
+    params: &ContractFunctionParameters,
+    query: bool,
+    payable_amount: i64,
+) -> ContractCallRequest {
+    let mut request = ContractCallRequest::new();
+    request.contract_id(contract_id);
+    request.gas(if query { QUERY_GAS } else { EXECUTE_GAS });
+    request.function_with_parameters(function_name, params);
+    if payable_amount > 0 {
+        request.payable_amount(Hbar::from_tinybars(payable_amount));
+    }
+
+    request
+}
+
+pub async fn query<T>(
+    wallet: &ActionWallet,
+    request: &ContractCallRequest,
+    decode: impl FnOnce(&ContractFunctionResult) -> CradleResult<T>,
+) -> CradleResult<FunctionCallOutput<T>> {
+    let response = wallet.query_contract(request).await?;
+    let output = decode(&response.result)?;
+
+    Ok(FunctionCallOutput::from_query(&response, Some(output)))
+}
+
+/// Submits `request` and waits for its record, for functions returning nothing.
+pub async fn execute(
+    wallet: &ActionWallet,
+    request: &ContractCallRequest,
+) -> CradleResult<FunctionCallOutput<()>> {
+    let transaction_id = wallet.execute_contract(request).await?;
+    let record = wallet.get_record(&transaction_id).await?;
+
+    Ok(FunctionCallOutput::from_record(&record, None))
+}
+
+/// Submits `request` and decodes the return values from its record.
+pub async fn execute_decoded<T>(
+    wallet: &ActionWallet,
+    request: &ContractCallRequest,
+    decode: impl FnOnce(&ContractFunctionResult) -> CradleResult<T>,
+) -> CradleResult<FunctionCallOutput<T>> {
+    let transaction_id = wallet.execute_contract(request).await?;
+    let record = wallet.get_record(&transaction_id).await?;
+
+    let result = record
+        .contract_function_result
+        .as_ref()
+        .ok_or_else(|| CradleError::decoding("Failed to get function result"))?;
+    let output = decode(result)?;
+
+    Ok(FunctionCallOutput::from_record(&record, Some(output)))
+}
+
+/// Return value `index` as an `address[]`, which the sdk has no getter for.
+pub fn address_array(result: &ContractFunctionResult, index: usize) -> Option<Vec<CradleAddress>> {
+    let kind = ParamType::Array(Box::new(ParamType::Address));
+
+    match abi::decode_slot(&kind, &result.bytes, index).ok()? {
+        Value::Array(values) => values
+            .iter()
+            .map(|v| CradleAddress::from_solidity_address(v.as_str()?).ok())
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Signature and abi inputs of the function whose selector starts `data`.
+pub fn function_by_selector(data: &[u8]) -> Option<(&'static str, Vec<Value>)> {
+    let (signature, inputs) = FUNCTIONS
+        .iter()
+        .find(|(signature, _)| data.starts_with(&abi::selector(signature)))?;
+
+    match serde_json::from_str(inputs).ok()? {
+        Value::Array(inputs) => Some((signature, inputs)),
+        _ => None,
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/contracts.rs"));
+
+/// The token denominated figures of the pool stats, in units of the pool asset.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PoolStatsAmounts {
+    pub total_supplied: TokenAmount,
+    pub total_borrowed: TokenAmount,
+    pub liquidity: TokenAmount,
+}
+
+impl asset_lending_pool::get_pool_stats::Output {
+    pub fn amounts(&self, decimals: u8) -> PoolStatsAmounts {
+        PoolStatsAmounts {
+            total_supplied: TokenAmount::new(self.total_supplied.clone(), decimals),
+            total_borrowed: TokenAmount::new(self.total_borrowed.clone(), decimals),
+            liquidity: TokenAmount::new(self.liquidity.clone(), decimals),
+        }
+    }
+}
+
+/// The listing stats in human units. Everything but `raised` is counted in the listed asset,
+/// `raised` in the purchase asset.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ListingStatsAmounts {
+    pub total_distributed: TokenAmount,
+    pub remaining: TokenAmount,
+    pub raised: TokenAmount,
+    pub balance: TokenAmount,
+}
+
+impl cradle_native_listing::get_listing_stats::Output {
+    pub fn amounts(&self, listing_decimals: u8, purchase_decimals: u8) -> ListingStatsAmounts {
+        ListingStatsAmounts {
+            total_distributed: TokenAmount::new(self.total_distributed.clone(), listing_decimals),
+            remaining: TokenAmount::new(self.remaining.clone(), listing_decimals),
+            raised: TokenAmount::new(self.raised.clone(), purchase_decimals),
+            balance: TokenAmount::new(self.balance.clone(), listing_decimals),
+        }
+    }
+}
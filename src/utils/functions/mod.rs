@@ -1,101 +1,232 @@
 use serde::{Deserialize, Serialize};
 
-use crate::utils::functions::access_controller::{
-    AccessControllerFunctionsInput, AccessControllerFunctionsOutput,
-};
-use crate::utils::functions::asset_factory::{
-    AssetFactoryFunctionInput, AssetFactoryFunctionOutput,
-};
-use crate::utils::functions::asset_issuer::{
-    AssetIssuerFunctionsInput, AssetIssuerFunctionsOutput,
-};
-use crate::utils::functions::asset_lending::{
-    AssetLendingPoolFunctionsInput, AssetLendingPoolFunctionsOutput,
-};
-use crate::utils::functions::asset_lending_pool_factory::{
-    AssetLendingPoolFactoryFunctionInput, AssetLendingPoolFactoryFunctionOutput,
-};
-use crate::utils::functions::asset_manager::{
-    AssetManagerFunctionInput, AssetManagerFunctionOutput,
-};
+use crate::utils::address::CradleAddress;
+use crate::utils::errors::CradleResult;
+use crate::utils::events::{DecodedEvent, EventDecoder};
 use crate::utils::functions::commons::ContractFunctionProcessor;
-use crate::utils::functions::cradle_account::{
-    CradleAccountFunctionInput, CradleAccountFunctionOutput,
+use crate::utils::functions::dynamic::DynamicCallArgs;
+use crate::wallet::backend::{CallRecord, QueryResponse, TransactionRequest};
+use crate::wallet::wallet::ActionWallet;
+use hedera::ContractLogInfo;
+use serde_json::Value;
+use std::convert::Infallible;
+use time::OffsetDateTime;
+
+pub mod commons;
+pub mod dynamic;
+pub mod generated;
+
+pub use generated::{
+    access_controller, asset_factory, asset_lending_pool, asset_manager, bridged_asset_issuer,
+    cradle_account, cradle_account_factory, cradle_listing_factory, cradle_native_listing,
+    cradle_order_book_settler, lending_pool_factory, native_asset_issuer,
 };
-use crate::utils::functions::cradle_account_factory::{
+
+use access_controller::{AccessControllerFunctionsInput, AccessControllerFunctionsOutput};
+use asset_factory::{AssetFactoryFunctionsInput, AssetFactoryFunctionsOutput};
+use asset_lending_pool::{AssetLendingPoolFunctionsInput, AssetLendingPoolFunctionsOutput};
+use asset_manager::{AssetManagerFunctionsInput, AssetManagerFunctionsOutput};
+use bridged_asset_issuer::{BridgedAssetIssuerFunctionsInput, BridgedAssetIssuerFunctionsOutput};
+use cradle_account::{CradleAccountFunctionsInput, CradleAccountFunctionsOutput};
+use cradle_account_factory::{
     CradleAccountFactoryFunctionsInput, CradleAccountFactoryFunctionsOutput,
 };
-use crate::utils::functions::cradle_native_listing::{
-    CradleNativeListingFunctionsInput, CradleNativeListingFunctionsOutput,
-};
-use crate::utils::functions::listing_factory::{
+use cradle_listing_factory::{
     CradleListingFactoryFunctionsInput, CradleListingFactoryFunctionsOutput,
 };
-use crate::utils::functions::orderbook_settler::{
-    OrderBookSettlerFunctionInput, OrderBookSettlerFunctionOutput,
+use cradle_native_listing::{
+    CradleNativeListingFunctionsInput, CradleNativeListingFunctionsOutput,
 };
-use crate::wallet::wallet::ActionWallet;
-
-pub mod access_controller;
-pub mod asset_factory;
-pub mod asset_issuer;
-pub mod asset_lending;
-pub mod asset_lending_pool_factory;
-pub mod asset_manager;
-pub mod commons;
-pub mod cradle_account;
-pub mod cradle_account_factory;
-pub mod cradle_native_listing;
-pub mod listing_factory;
-pub mod orderbook_settler;
+use cradle_order_book_settler::{
+    CradleOrderBookSettlerFunctionsInput, CradleOrderBookSettlerFunctionsOutput,
+};
+use lending_pool_factory::{LendingPoolFactoryFunctionsInput, LendingPoolFactoryFunctionsOutput};
+use native_asset_issuer::{NativeAssetIssuerFunctionsInput, NativeAssetIssuerFunctionsOutput};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct FunctionCallOutput<T> {
+    /// Transaction id of the call, or of the query payment for queries.
     pub transaction_id: String,
     pub output: Option<T>,
-    // TODO: add additional fields that may be useful
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub gas_used: Option<u64>,
+    /// Transaction fee charged, in tinybars.
+    #[serde(default)]
+    pub transaction_fee: Option<i64>,
+    /// `seconds.nanoseconds`, the same format the mirror node uses.
+    #[serde(default)]
+    pub consensus_timestamp: Option<String>,
+    #[serde(default)]
+    pub logs: Vec<ContractLog>,
+    /// Logs matched against the event abi of the artifacts in `contracts/out`.
+    #[serde(default)]
+    pub events: Vec<DecodedEvent>,
+    /// Query payment, in tinybars. Only set for queries.
+    #[serde(default)]
+    pub query_cost: Option<i64>,
+}
+
+impl<T> FunctionCallOutput<T> {
+    pub fn from_record(record: &CallRecord, output: Option<T>) -> Self {
+        let result = record.contract_function_result.as_ref();
+
+        Self {
+            transaction_id: record.receipt.transaction_id.to_string(),
+            output,
+            status: Some(record.receipt.status.as_str_name().to_string()),
+            gas_used: result.map(|r| r.gas_used),
+            transaction_fee: Some(record.transaction_fee.to_tinybars()),
+            consensus_timestamp: Some(format_timestamp(record.consensus_timestamp)),
+            logs: result
+                .map(|r| r.logs.iter().map(ContractLog::from).collect())
+                .unwrap_or_default(),
+            events: result
+                .map(|r| EventDecoder::artifacts().decode_logs(&r.logs))
+                .unwrap_or_default(),
+            query_cost: None,
+        }
+    }
+
+    pub fn from_query(response: &QueryResponse, output: Option<T>) -> Self {
+        Self {
+            transaction_id: response
+                .payment_transaction_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            output,
+            status: Some(hedera::Status::Success.as_str_name().to_string()),
+            gas_used: Some(response.result.gas_used),
+            transaction_fee: None,
+            consensus_timestamp: None,
+            logs: response.result.logs.iter().map(ContractLog::from).collect(),
+            events: EventDecoder::artifacts().decode_logs(&response.result.logs),
+            query_cost: Some(response.cost.to_tinybars()),
+        }
+    }
+
+    /// Converts the decoded output, keeping the rest of the call details.
+    pub fn try_map<U, E>(
+        self,
+        f: impl FnOnce(T) -> Result<U, E>,
+    ) -> Result<FunctionCallOutput<U>, E> {
+        Ok(FunctionCallOutput {
+            transaction_id: self.transaction_id,
+            output: self.output.map(f).transpose()?,
+            status: self.status,
+            gas_used: self.gas_used,
+            transaction_fee: self.transaction_fee,
+            consensus_timestamp: self.consensus_timestamp,
+            logs: self.logs,
+            events: self.events,
+            query_cost: self.query_cost,
+        })
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> FunctionCallOutput<U> {
+        let Ok(output) = self.try_map(|output| Ok::<U, Infallible>(f(output)));
+        output
+    }
+
+    /// First decoded event with the given name, e.g. `Borrowed` on a borrow call.
+    pub fn event(&self, name: &str) -> Option<&DecodedEvent> {
+        self.events.iter().find(|event| event.name == name)
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct WithContractId<Rest> {
+pub struct ContractLog {
     pub contract_id: String,
-    pub rest: Option<Rest>,
+    /// Hex encoded topics, the first one being the event signature hash.
+    pub topics: Vec<String>,
+    pub data: String,
+}
+
+impl From<&ContractLogInfo> for ContractLog {
+    fn from(log: &ContractLogInfo) -> Self {
+        Self {
+            contract_id: log.contract_id.to_string(),
+            topics: log
+                .topics
+                .iter()
+                .map(|topic| format!("0x{}", hex::encode(topic)))
+                .collect(),
+            data: format!("0x{}", hex::encode(&log.data)),
+        }
+    }
+}
+
+pub fn format_timestamp(timestamp: OffsetDateTime) -> String {
+    format!(
+        "{}.{:09}",
+        timestamp.unix_timestamp(),
+        timestamp.nanosecond()
+    )
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum ContractCallInput {
     AccessController(AccessControllerFunctionsInput),
     CradleAccountFactory(CradleAccountFactoryFunctionsInput),
-    BridgedAssetIssuer(AssetIssuerFunctionsInput),
-    NativeAssetIssuer(AssetIssuerFunctionsInput),
+    BridgedAssetIssuer(BridgedAssetIssuerFunctionsInput),
+    NativeAssetIssuer(NativeAssetIssuerFunctionsInput),
     AssetLendingPool(AssetLendingPoolFunctionsInput),
-    CradleAccount(CradleAccountFunctionInput),
-    AssetManager(AssetManagerFunctionInput),
-    AssetFactory(AssetFactoryFunctionInput),
-    OrderBookSettler(OrderBookSettlerFunctionInput),
-    AssetLendingPoolFactory(AssetLendingPoolFactoryFunctionInput),
+    CradleAccount(CradleAccountFunctionsInput),
+    AssetManager(AssetManagerFunctionsInput),
+    AssetFactory(AssetFactoryFunctionsInput),
+    OrderBookSettler(CradleOrderBookSettlerFunctionsInput),
+    AssetLendingPoolFactory(LendingPoolFactoryFunctionsInput),
     CradleListingFactory(CradleListingFactoryFunctionsInput),
     CradleNativeListing(CradleNativeListingFunctionsInput),
+    /// Associates the operator account with a token, e.g. before receiving it.
+    TokenAssociate(CradleAddress),
+    Dynamic(DynamicCallArgs),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum ContractCallOutput {
     AccessController(AccessControllerFunctionsOutput),
     CradleAccountFactory(CradleAccountFactoryFunctionsOutput),
-    BridgedAssetIssuer(AssetIssuerFunctionsOutput),
-    NativeAssetIssuer(AssetIssuerFunctionsOutput),
+    BridgedAssetIssuer(BridgedAssetIssuerFunctionsOutput),
+    NativeAssetIssuer(NativeAssetIssuerFunctionsOutput),
     AssetLendingPool(AssetLendingPoolFunctionsOutput),
-    CradleAccount(CradleAccountFunctionOutput),
-    AssetManager(AssetManagerFunctionOutput),
-    AssetFactory(AssetFactoryFunctionOutput),
-    OrderBookSettler(OrderBookSettlerFunctionOutput),
-    AssetLendingPoolFactory(AssetLendingPoolFactoryFunctionOutput),
+    CradleAccount(CradleAccountFunctionsOutput),
+    AssetManager(AssetManagerFunctionsOutput),
+    AssetFactory(AssetFactoryFunctionsOutput),
+    OrderBookSettler(CradleOrderBookSettlerFunctionsOutput),
+    AssetLendingPoolFactory(LendingPoolFactoryFunctionsOutput),
     CradleListingFactory(CradleListingFactoryFunctionsOutput),
     CradleNativeListing(CradleNativeListingFunctionsOutput),
+    TokenAssociate(FunctionCallOutput<()>),
+    Dynamic(FunctionCallOutput<Value>),
+}
+
+impl ContractCallInput {
+    /// Whether the call only queries, submitting no transaction, so it can be refused up front
+    /// where state changes aren't allowed.
+    pub fn is_query(&self) -> CradleResult<bool> {
+        Ok(match self {
+            ContractCallInput::AccessController(args) => args.is_query(),
+            ContractCallInput::CradleAccountFactory(args) => args.is_query(),
+            ContractCallInput::BridgedAssetIssuer(args) => args.is_query(),
+            ContractCallInput::NativeAssetIssuer(args) => args.is_query(),
+            ContractCallInput::AssetLendingPool(args) => args.is_query(),
+            ContractCallInput::CradleAccount(args) => args.is_query(),
+            ContractCallInput::AssetManager(args) => args.is_query(),
+            ContractCallInput::AssetFactory(args) => args.is_query(),
+            ContractCallInput::OrderBookSettler(args) => args.is_query(),
+            ContractCallInput::AssetLendingPoolFactory(args) => args.is_query(),
+            ContractCallInput::CradleListingFactory(args) => args.is_query(),
+            ContractCallInput::CradleNativeListing(args) => args.is_query(),
+            ContractCallInput::TokenAssociate(_) => false,
+            ContractCallInput::Dynamic(args) => args.is_query()?,
+        })
+    }
 }
 
 impl ContractFunctionProcessor<ContractCallOutput> for ContractCallInput {
-    async fn process(&self, wallet: &mut ActionWallet) -> anyhow::Result<ContractCallOutput> {
+    async fn process(&self, wallet: &mut ActionWallet) -> CradleResult<ContractCallOutput> {
         match self {
             ContractCallInput::AccessController(args) => {
                 let output = args.process(wallet).await?;
@@ -145,6 +276,23 @@ impl ContractFunctionProcessor<ContractCallOutput> for ContractCallInput {
                 let output = args.process(wallet).await?;
                 Ok(ContractCallOutput::CradleNativeListing(output))
             }
+            ContractCallInput::TokenAssociate(token) => {
+                let transaction = TransactionRequest::TokenAssociate {
+                    account_id: wallet.account_id.parse()?,
+                    token_ids: vec![token.to_token_id()?],
+                };
+
+                let transaction_id = wallet.execute_transaction(&transaction).await?;
+                let record = wallet.get_record(&transaction_id).await?;
+
+                let output = FunctionCallOutput::from_record(&record, None);
+
+                Ok(ContractCallOutput::TokenAssociate(output))
+            }
+            ContractCallInput::Dynamic(args) => {
+                let output = args.process(wallet).await?;
+                Ok(ContractCallOutput::Dynamic(output))
+            }
         }
     }
 }
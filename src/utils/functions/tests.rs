@@ -0,0 +1,479 @@
+use crate::utils::abi;
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::U256;
+use crate::utils::errors::{CradleError, ERROR_STRING_SELECTOR, PANIC_SELECTOR, RevertReason};
+use crate::utils::functions::access_controller::{
+    AccessControllerFunctionsInput, AccessControllerFunctionsOutput, has_access,
+};
+use crate::utils::functions::asset_factory::{
+    AssetFactoryFunctionsInput, AssetFactoryFunctionsOutput, create_asset,
+};
+use crate::utils::functions::asset_lending_pool::{
+    AssetLendingPoolFunctionsInput, AssetLendingPoolFunctionsOutput, borrow, get_pool_stats,
+};
+use crate::utils::functions::cradle_native_listing::{
+    CradleNativeListingFunctionsInput, CradleNativeListingFunctionsOutput, get_listing_stats,
+};
+use crate::utils::functions::cradle_order_book_settler::{
+    CradleOrderBookSettlerFunctionsInput, settle_order,
+};
+use crate::utils::functions::dynamic::{call_abi_function, find_function};
+use crate::utils::functions::native_asset_issuer::{NativeAssetIssuerFunctionsInput, lock_asset};
+use crate::utils::functions::*;
+use crate::wallet::backend::TransactionRequest;
+use crate::wallet::mock_backend::RecordedCall;
+use crate::wallet::test_fixtures::{ASSET, USER, mock_wallet};
+use crate::wallet::wallet::ActionWallet;
+use anyhow::Result;
+use hedera::{ContractFunctionParameters, ContractId, Hbar};
+use num_bigint::BigUint;
+use serde_json::json;
+
+#[tokio::test]
+async fn has_access_is_sent_as_query() -> Result<()> {
+    let (backend, mut wallet) = mock_wallet();
+
+    let mut returned = ContractFunctionParameters::new();
+    returned.add_bool(true);
+    backend.push_return(returned.to_bytes(None));
+
+    let res = wallet
+        .execute(ContractCallInput::AccessController(
+            AccessControllerFunctionsInput::HasAccess(has_access::Args {
+                level: 1,
+                account: USER.parse()?,
+            }),
+        ))
+        .await?;
+
+    let call = backend.last_call().expect("no call recorded");
+    assert!(matches!(call, RecordedCall::Query(_)));
+    assert_eq!(call.function_name(), Some("hasAccess"));
+
+    let mut expected = ContractFunctionParameters::new();
+    expected.add_uint64(1);
+    expected.add_address(USER);
+    assert_eq!(
+        call.parameters(),
+        Some(expected.to_bytes(Some("hasAccess")).as_slice())
+    );
+
+    match res {
+        ContractCallOutput::AccessController(AccessControllerFunctionsOutput::HasAccess(o)) => {
+            assert_eq!(o.query_cost, Some(0));
+            assert!(!o.transaction_id.is_empty());
+            assert!(o.output.expect("missing output"));
+        }
+        _ => panic!("unexpected output"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn borrow_decodes_record_result() -> Result<()> {
+    let (backend, mut wallet) = mock_wallet();
+
+    let mut returned = ContractFunctionParameters::new();
+    returned.add_uint256(BigUint::from(1_000_000u64));
+    returned.add_uint256(BigUint::from(250u64));
+    backend.push_return(returned.to_bytes(None));
+
+    let res = wallet
+        .execute(ContractCallInput::AssetLendingPool(
+            AssetLendingPoolFunctionsInput::Borrow(borrow::Args {
+                user: USER.parse()?,
+                collateral_amount: U256::from(500u64),
+                collateral_asset: ASSET.parse()?,
+                contract_id: "0.0.2001".parse()?,
+            }),
+        ))
+        .await?;
+
+    let call = backend.last_call().expect("no call recorded");
+    assert!(matches!(call, RecordedCall::Execute(_)));
+    assert_eq!(call.function_name(), Some("borrow"));
+
+    let mut expected = ContractFunctionParameters::new();
+    expected.add_address(USER);
+    expected.add_uint256(BigUint::from(500u64));
+    expected.add_address(ASSET);
+    assert_eq!(
+        call.parameters(),
+        Some(expected.to_bytes(Some("borrow")).as_slice())
+    );
+
+    match res {
+        ContractCallOutput::AssetLendingPool(AssetLendingPoolFunctionsOutput::Borrow(o)) => {
+            assert_eq!(o.status.as_deref(), Some("SUCCESS"));
+            assert_eq!(o.transaction_fee, Some(0));
+            assert!(o.consensus_timestamp.is_some());
+            assert!(o.query_cost.is_none());
+
+            let output = o.output.expect("missing output");
+            assert_eq!(output.borrow_index, U256::from(1_000_000u64));
+            assert_eq!(output.borrowed_amount, U256::from(250u64));
+        }
+        _ => panic!("unexpected output"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn borrow_revert_is_decoded() -> Result<()> {
+    let (backend, mut wallet) = mock_wallet();
+
+    let mut reason = ContractFunctionParameters::new();
+    reason.add_string("insufficient collateral");
+    let mut data = ERROR_STRING_SELECTOR.to_vec();
+    data.extend(reason.to_bytes(None));
+    backend.push_revert(data);
+
+    let err = wallet
+        .execute(ContractCallInput::AssetLendingPool(
+            AssetLendingPoolFunctionsInput::Borrow(borrow::Args {
+                user: USER.parse()?,
+                collateral_amount: U256::from(500u64),
+                collateral_asset: ASSET.parse()?,
+                contract_id: "0.0.2001".parse()?,
+            }),
+        ))
+        .await
+        .expect_err("borrow should revert");
+
+    let revert = err.revert().expect("expected a contract revert");
+    assert_eq!(revert.message(), Some("insufficient collateral"));
+    assert!(revert.transaction_id.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn query_panic_is_decoded() -> Result<()> {
+    let (backend, mut wallet) = mock_wallet();
+
+    let mut code = ContractFunctionParameters::new();
+    code.add_uint256(BigUint::from(0x11u64));
+    let mut data = PANIC_SELECTOR.to_vec();
+    data.extend(code.to_bytes(None));
+    backend.push_revert(data);
+
+    let err = wallet
+        .execute(ContractCallInput::AccessController(
+            AccessControllerFunctionsInput::HasAccess(has_access::Args {
+                level: 1,
+                account: USER.parse()?,
+            }),
+        ))
+        .await
+        .expect_err("query should revert");
+
+    assert_eq!(
+        err.revert().map(|r| r.reason.clone()),
+        Some(RevertReason::Panic(0x11))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn missing_return_value_is_a_decoding_error() -> Result<()> {
+    let (backend, mut wallet) = mock_wallet();
+
+    backend.push_return(Vec::new());
+
+    let err = wallet
+        .execute(ContractCallInput::AccessController(
+            AccessControllerFunctionsInput::HasAccess(has_access::Args {
+                level: 1,
+                account: USER.parse()?,
+            }),
+        ))
+        .await
+        .expect_err("empty response should not decode");
+
+    assert!(matches!(err, CradleError::Decoding(_)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn settle_order_targets_settler_contract() -> Result<()> {
+    let (backend, mut wallet) = mock_wallet();
+
+    wallet
+        .execute(ContractCallInput::OrderBookSettler(
+            CradleOrderBookSettlerFunctionsInput::SettleOrder(settle_order::Args {
+                bidder: USER.parse()?,
+                asker: USER.parse()?,
+                bid_asset: ASSET.parse()?,
+                ask_asset: ASSET.parse()?,
+                bid_asset_amount: U256::from(10u64),
+                ask_asset_amount: U256::from(20u64),
+            }),
+        ))
+        .await?;
+
+    match backend.last_call().expect("no call recorded") {
+        RecordedCall::Execute(TransactionRequest::ContractExecute(request)) => {
+            assert_eq!(request.function_name, "settleOrder");
+            assert_eq!(request.contract_id, Some(ContractId::new(0, 0, 1006)));
+        }
+        call => panic!("unexpected call {:?}", call),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn token_associate_uses_wallet_account() -> Result<()> {
+    let (backend, mut wallet) = mock_wallet();
+
+    let res = wallet
+        .execute(ContractCallInput::TokenAssociate("0.0.3001".parse()?))
+        .await?;
+
+    match backend.last_call().expect("no call recorded") {
+        RecordedCall::Execute(TransactionRequest::TokenAssociate {
+            account_id,
+            token_ids,
+        }) => {
+            assert_eq!(account_id.to_string(), "0.0.2");
+            assert_eq!(token_ids.len(), 1);
+            assert_eq!(token_ids[0].to_string(), "0.0.3001");
+        }
+        call => panic!("unexpected call {:?}", call),
+    }
+
+    assert!(matches!(res, ContractCallOutput::TokenAssociate(_)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lock_asset_calls_lock_asset() -> Result<()> {
+    let (backend, mut wallet) = mock_wallet();
+
+    wallet
+        .execute(ContractCallInput::NativeAssetIssuer(
+            NativeAssetIssuerFunctionsInput::LockAsset(lock_asset::Args {
+                contract_id: "0.0.2002".parse()?,
+                user: USER.parse()?,
+                asset: ASSET.parse()?,
+                amount: U256::from(10u64),
+            }),
+        ))
+        .await?;
+
+    let call = backend.last_call().expect("no call recorded");
+    assert_eq!(call.function_name(), Some("lockAsset"));
+    assert_eq!(
+        call.parameters().map(|p| &p[..4]),
+        Some(abi::selector("lockAsset(address,address,uint256)").as_slice())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dynamic_calls_execute_state_changing_functions() -> Result<()> {
+    let (backend, mut wallet) = mock_wallet();
+
+    let item = json!({
+        "type": "function",
+        "name": "setReserveFactor",
+        "stateMutability": "nonpayable",
+        "inputs": [{ "name": "factor", "type": "uint64" }],
+        "outputs": []
+    });
+
+    let output = call_abi_function(&mut wallet, "0.0.2001", &item, &[json!(1000)], None).await?;
+
+    let call = backend.last_call().expect("no call recorded");
+    assert!(matches!(call, RecordedCall::Execute(_)));
+    assert_eq!(call.function_name(), Some("setReserveFactor"));
+
+    let mut expected = ContractFunctionParameters::new();
+    expected.add_uint64(1000);
+    assert_eq!(
+        call.parameters(),
+        Some(expected.to_bytes(Some("setReserveFactor")).as_slice())
+    );
+
+    assert_eq!(output.status.as_deref(), Some("SUCCESS"));
+    assert!(output.output.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn find_function_resolves_overloads() -> Result<()> {
+    let items = vec![
+        json!({
+            "type": "function",
+            "name": "mint",
+            "inputs": [{ "name": "a", "type": "uint256" }]
+        }),
+        json!({
+            "type": "function",
+            "name": "mint",
+            "inputs": [{ "name": "to", "type": "address" }, { "name": "a", "type": "uint256" }]
+        }),
+    ];
+
+    let by_count = find_function(&items, "mint", 2)?;
+    assert_eq!(abi::params(by_count, "inputs").len(), 2);
+
+    let by_signature = find_function(&items, "mint(uint256)", 2)?;
+    assert_eq!(abi::params(by_signature, "inputs").len(), 1);
+
+    assert!(matches!(
+        find_function(&items, "burn", 1),
+        Err(CradleError::Config(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn generated_params_match_the_abi_signature() -> Result<()> {
+    let asset: CradleAddress = ASSET.parse()?;
+
+    let params = cradle_account::lock_asset::params(&asset, &U256::from(5u64));
+    let call = params.to_bytes(Some(cradle_account::lock_asset::NAME));
+    assert_eq!(
+        call[..4],
+        abi::selector(cradle_account::lock_asset::SIGNATURE)
+    );
+
+    let call = asset_lending_pool::get_pool_stats::params()
+        .to_bytes(Some(asset_lending_pool::get_pool_stats::NAME));
+    assert_eq!(
+        call,
+        abi::selector(asset_lending_pool::get_pool_stats::SIGNATURE)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn listing_stats_are_decoded_by_the_binding() -> Result<()> {
+    let (backend, mut wallet) = mock_wallet();
+
+    let mut returned = ContractFunctionParameters::new();
+    for value in 1..=4u64 {
+        returned.add_uint256(BigUint::from(value));
+    }
+    returned.add_uint8(3);
+    backend.push_return(returned.to_bytes(None));
+
+    let res = wallet
+        .execute(ContractCallInput::CradleNativeListing(
+            CradleNativeListingFunctionsInput::GetListingStats(get_listing_stats::Args {
+                contract_id: "0.0.3001".parse()?,
+            }),
+        ))
+        .await?;
+
+    let call = backend.last_call().expect("no call recorded");
+    assert_eq!(
+        call.parameters().map(|p| &p[..4]),
+        Some(abi::selector(cradle_native_listing::get_listing_stats::SIGNATURE).as_slice())
+    );
+
+    match res {
+        ContractCallOutput::CradleNativeListing(
+            CradleNativeListingFunctionsOutput::GetListingStats(o),
+        ) => {
+            let stats = o.output.expect("missing output");
+            assert_eq!(stats.remaining, U256::from(2u64));
+            assert_eq!(stats.balance, U256::from(4u64));
+            assert_eq!(stats.status, 3);
+        }
+        _ => panic!("unexpected output"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pool_stats_keep_values_beyond_u64() -> Result<()> {
+    let (backend, mut wallet) = mock_wallet();
+
+    // Ray-scaled indices are well beyond u64::MAX.
+    let ray = BigUint::from(10u64).pow(27);
+    let mut returned = ContractFunctionParameters::new();
+    for _ in 0..8 {
+        returned.add_uint256(ray.clone());
+    }
+    backend.push_return(returned.to_bytes(None));
+
+    let res = wallet
+        .execute(ContractCallInput::AssetLendingPool(
+            AssetLendingPoolFunctionsInput::GetPoolStats(get_pool_stats::Args {
+                contract_id: "0.0.2001".parse()?,
+            }),
+        ))
+        .await?;
+
+    match res {
+        ContractCallOutput::AssetLendingPool(AssetLendingPoolFunctionsOutput::GetPoolStats(o)) => {
+            let stats = o.output.expect("missing output");
+            assert_eq!(stats.borrow_index, U256::try_from(ray)?);
+
+            let json = serde_json::to_value(&stats)?;
+            assert_eq!(json["borrow_index"], json!("1000000000000000000000000000"));
+        }
+        _ => panic!("unexpected output"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn token_decimals_are_queried_once() -> Result<()> {
+    let (backend, wallet) = mock_wallet();
+
+    let mut returned = ContractFunctionParameters::new();
+    returned.add_uint8(6);
+    backend.push_return(returned.to_bytes(None));
+
+    let token: CradleAddress = "0.0.3001".parse()?;
+    let amount = wallet.token_amount(&token, "1.5").await?;
+    assert_eq!(amount.raw, U256::from(1_500_000u64));
+
+    let formatted = wallet
+        .format_amount(&token, &U256::from(2_000_000u64))
+        .await?;
+    assert_eq!(formatted.to_human(), "2");
+
+    let calls = backend.calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].function_name(), Some("decimals"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_asset() -> Result<()> {
+    dotenvy::dotenv()?;
+
+    let mut wallet = ActionWallet::from_env()?;
+
+    let res = wallet
+        .execute(ContractCallInput::AssetFactory(
+            AssetFactoryFunctionsInput::CreateAsset(create_asset::Args {
+                name: "Cradle Base Reserve".to_string(),
+                symbol: "CBR".to_string(),
+                payable_amount: Hbar::new(50).to_tinybars(),
+            }),
+        ))
+        .await?;
+
+    if let ContractCallOutput::AssetFactory(AssetFactoryFunctionsOutput::CreateAsset(o)) = res {
+        println!("Transaction ID:: {}", o.transaction_id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,798 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::errors::{ContractRevert, CradleError, CradleResult, RevertReason};
+use crate::utils::events::{DecodedEvent, EventDecoder};
+use crate::wallet::backend::ContractCallRequest;
+use crate::wallet::network::CradleNetwork;
+use hedera::{AccountId, Status, TransactionId};
+use reqwest::{RequestBuilder, StatusCode, Url};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::fmt::Display;
+use tokio::time::{Duration, sleep};
+
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// Typed client for the mirror node rest api (`/api/v1`). List calls follow `links.next` until
+/// the mirror node runs out of pages or the query's `max_items` is reached.
+///
+/// Failed requests, 5xx and 429 responses and unreadable bodies are retried with exponential
+/// backoff; any other error status fails straight away.
+#[derive(Clone, Debug)]
+pub struct MirrorClient {
+    base_url: String,
+    http: reqwest::Client,
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl MirrorClient {
+    /// `base_url` without `/api/v1`, e.g. `https://testnet.mirrornode.hedera.com`.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            http: reqwest::Client::new(),
+            max_attempts: 10,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+
+    pub fn for_network(network: &CradleNetwork) -> Self {
+        Self::new(network.mirror_node_url())
+    }
+
+    /// Attempts per request, doubling `initial_backoff` between them.
+    pub fn with_retries(mut self, max_attempts: u32, initial_backoff: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Upper bound on the wait between two attempts, 30 seconds by default.
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// A contract by id or evm address. Bytecode is only included here, not in listings.
+    pub async fn contract(&self, contract: impl Display) -> CradleResult<ContractInfo> {
+        self.get(&format!("/api/v1/contracts/{}", contract)).await
+    }
+
+    /// Like `contract`, but `None` while the mirror node doesn't know the contract yet.
+    pub async fn find_contract(
+        &self,
+        contract: impl Display,
+    ) -> CradleResult<Option<ContractInfo>> {
+        self.find(&format!("/api/v1/contracts/{}", contract)).await
+    }
+
+    pub async fn contracts(&self, query: &MirrorQuery) -> CradleResult<Vec<ContractInfo>> {
+        self.list("/api/v1/contracts", "contracts", query).await
+    }
+
+    pub async fn contract_results(
+        &self,
+        contract: impl Display,
+        query: &MirrorQuery,
+    ) -> CradleResult<Vec<ContractResult>> {
+        let path = format!("/api/v1/contracts/{}/results", contract);
+        self.list(&path, "results", query).await
+    }
+
+    /// Result of one transaction, by transaction id (`0.0.2-1700000000-000000000`) or hash.
+    pub async fn contract_result(&self, transaction: impl Display) -> CradleResult<ContractResult> {
+        self.get(&format!("/api/v1/contracts/results/{}", transaction)).await
+    }
+
+    pub async fn contract_logs(
+        &self,
+        contract: impl Display,
+        query: &MirrorQuery,
+    ) -> CradleResult<Vec<ContractLog>> {
+        let path = format!("/api/v1/contracts/{}/results/logs", contract);
+        self.list(&path, "logs", query).await
+    }
+
+    pub async fn account(&self, account: impl Display) -> CradleResult<AccountInfo> {
+        self.get(&format!("/api/v1/accounts/{}", account)).await
+    }
+
+    pub async fn accounts(&self, query: &MirrorQuery) -> CradleResult<Vec<AccountInfo>> {
+        self.list("/api/v1/accounts", "accounts", query).await
+    }
+
+    pub async fn token(&self, token: impl Display) -> CradleResult<TokenInfo> {
+        self.get(&format!("/api/v1/tokens/{}", token)).await
+    }
+
+    pub async fn tokens(&self, query: &MirrorQuery) -> CradleResult<Vec<TokenInfo>> {
+        self.list("/api/v1/tokens", "tokens", query).await
+    }
+
+    /// Holders of a token and their balances.
+    pub async fn token_balances(
+        &self,
+        token: impl Display,
+        query: &MirrorQuery,
+    ) -> CradleResult<Vec<TokenBalance>> {
+        let path = format!("/api/v1/tokens/{}/balances", token);
+        self.list(&path, "balances", query).await
+    }
+
+    pub async fn transactions(&self, query: &MirrorQuery) -> CradleResult<Vec<TransactionInfo>> {
+        self.list("/api/v1/transactions", "transactions", query).await
+    }
+
+    pub async fn schedule(&self, schedule: impl Display) -> CradleResult<ScheduleInfo> {
+        self.get(&format!("/api/v1/schedules/{}", schedule)).await
+    }
+
+    pub async fn schedules(&self, query: &MirrorQuery) -> CradleResult<Vec<ScheduleInfo>> {
+        self.list("/api/v1/schedules", "schedules", query).await
+    }
+
+    /// Every transaction with this id: the transaction itself and any child transactions.
+    pub async fn transaction(&self, transaction_id: &str) -> CradleResult<Vec<TransactionInfo>> {
+        let page: Value = self.get(&format!("/api/v1/transactions/{}", transaction_id)).await?;
+        items(page, "transactions")
+    }
+
+    /// The transaction submitted as `transaction_id`, leaving out its child transactions.
+    /// `None` if the mirror node hasn't seen it.
+    pub async fn find_transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> CradleResult<Option<TransactionInfo>> {
+        let path = format!("/api/v1/transactions/{}", mirror_transaction_id(transaction_id));
+        let Some(page) = self.find::<Value>(&path).await? else {
+            return Ok(None);
+        };
+
+        let transactions: Vec<TransactionInfo> = items(page, "transactions")?;
+        Ok(transactions
+            .into_iter()
+            .find(|transaction| transaction.nonce == 0 && !transaction.scheduled))
+    }
+
+    /// Runs a call against current state through `POST /api/v1/contracts/call`, without a
+    /// transaction or fee. Returns the call result, or the gas estimate when `estimate` is set.
+    pub async fn call(&self, request: &MirrorCallRequest) -> CradleResult<Vec<u8>> {
+        let url = self.url("/api/v1/contracts/call", &[])?;
+
+        match self.send(&url, || self.http.post(url.clone()).json(request)).await? {
+            (status, body) if status.is_success() => {
+                let result = body["result"].as_str().unwrap_or("0x");
+                hex_bytes(result)
+            }
+            (StatusCode::BAD_REQUEST, body) if revert_data(&body).is_some() => {
+                let data = revert_data(&body).unwrap_or("0x");
+
+                Err(CradleError::Revert(ContractRevert {
+                    transaction_id: None,
+                    status: Status::ContractRevertExecuted,
+                    reason: RevertReason::decode(&hex_bytes(data)?),
+                }))
+            }
+            (status, body) => Err(CradleError::mirror(format!(
+                "{} returned {}: {}",
+                url, status, body
+            ))),
+        }
+    }
+
+    /// Gas the mirror node estimates the call needs.
+    pub async fn estimate_gas(&self, request: &MirrorCallRequest) -> CradleResult<u64> {
+        let mut request = request.clone();
+        request.estimate = true;
+
+        let estimate = self.call(&request).await?;
+        Ok(estimate
+            .iter()
+            .fold(0u64, |gas, byte| gas.saturating_mul(256).saturating_add(*byte as u64)))
+    }
+
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) -> CradleResult<T> {
+        self.find(path)
+            .await?
+            .ok_or_else(|| CradleError::mirror(format!("{} not found", path)))
+    }
+
+    /// `None` on 404.
+    pub async fn find<T: DeserializeOwned>(&self, path: &str) -> CradleResult<Option<T>> {
+        let url = self.url(path, &[])?;
+
+        match self.fetch(url).await? {
+            Some(body) => Ok(Some(serde_json::from_value(body).map_err(|e| {
+                CradleError::decoding(format!("Unexpected mirror response for {}: {}", path, e))
+            })?)),
+            None => Ok(None),
+        }
+    }
+
+    /// One page of a listing, with the path of the next page if there is one.
+    pub async fn page<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        key: &str,
+        query: &MirrorQuery,
+    ) -> CradleResult<Page<T>> {
+        self.fetch_page(self.url(path, &query.params)?, key).await
+    }
+
+    /// Every item of a listing, following `links.next`.
+    pub async fn list<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        key: &str,
+        query: &MirrorQuery,
+    ) -> CradleResult<Vec<T>> {
+        let mut items = Vec::new();
+        let mut url = self.url(path, &query.params)?;
+
+        loop {
+            let page = self.fetch_page::<T>(url, key).await?;
+            items.extend(page.items);
+
+            if let Some(max_items) = query.max_items {
+                if items.len() >= max_items {
+                    items.truncate(max_items);
+                    return Ok(items);
+                }
+            }
+
+            match page.next {
+                Some(next) => url = self.url(&next, &[])?,
+                None => return Ok(items),
+            }
+        }
+    }
+
+    async fn fetch_page<T: DeserializeOwned>(&self, url: Url, key: &str) -> CradleResult<Page<T>> {
+        let path = url.path().to_string();
+        let body = self
+            .fetch(url)
+            .await?
+            .ok_or_else(|| CradleError::mirror(format!("{} not found", path)))?;
+
+        let next = body["links"]["next"].as_str().map(str::to_string);
+        Ok(Page {
+            items: items(body, key)?,
+            next,
+        })
+    }
+
+    fn url(&self, path: &str, params: &[(String, String)]) -> CradleResult<Url> {
+        let url = format!("{}{}", self.base_url, path);
+        let mut url = Url::parse(&url)
+            .map_err(|e| CradleError::config(format!("Invalid mirror node url {}: {}", url, e)))?;
+
+        if !params.is_empty() {
+            url.query_pairs_mut().extend_pairs(params);
+        }
+
+        Ok(url)
+    }
+
+    async fn fetch(&self, url: Url) -> CradleResult<Option<Value>> {
+        match self.send(&url, || self.http.get(url.clone())).await? {
+            (status, body) if status.is_success() => Ok(Some(body)),
+            (StatusCode::NOT_FOUND, _) => Ok(None),
+            (status, body) => Err(CradleError::mirror(format!(
+                "{} returned {}: {}",
+                url, status, body
+            ))),
+        }
+    }
+
+    /// Sends until the mirror node gives an answer worth returning: a readable success or any
+    /// error status that retrying won't fix.
+    async fn send(
+        &self,
+        url: &Url,
+        request: impl Fn() -> RequestBuilder,
+    ) -> CradleResult<(StatusCode, Value)> {
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+
+            let error = match request().send().await {
+                Ok(response) if is_retryable(response.status()) => {
+                    format!("status {}", response.status())
+                }
+                Ok(response) if !response.status().is_success() => {
+                    let status = response.status();
+                    let body = response.text().await.unwrap_or_default();
+                    let body = serde_json::from_str(&body).unwrap_or(Value::String(body));
+                    return Ok((status, body));
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    match response.json::<Value>().await {
+                        Ok(body) => return Ok((status, body)),
+                        Err(e) => format!("unreadable response: {}", e),
+                    }
+                }
+                Err(e) => e.to_string(),
+            };
+
+            if attempt >= self.max_attempts {
+                return Err(CradleError::mirror(format!(
+                    "{} failed after {} attempts: {}",
+                    url, attempt, error
+                )));
+            }
+
+            let backoff = self.backoff(attempt);
+            log::warn!(
+                "Attempt {} to fetch {} failed: {}. Retrying in {:?}...",
+                attempt, url, error, backoff
+            );
+            sleep(backoff).await;
+        }
+    }
+
+    /// Wait after failed attempt `attempt`, doubling from `initial_backoff` up to `max_backoff`.
+    fn backoff(&self, attempt: u32) -> Duration {
+        2u32.checked_pow(attempt.saturating_sub(1))
+            .and_then(|factor| self.initial_backoff.checked_mul(factor))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+}
+
+/// Revert data of a failed `contracts/call`, reported in the first status message.
+fn revert_data(body: &Value) -> Option<&str> {
+    body["_status"]["messages"][0]["data"].as_str()
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+/// A transaction id the way the mirror node takes it, e.g. `0.0.2-1767225600-000000000`.
+pub fn mirror_transaction_id(transaction_id: &TransactionId) -> String {
+    format!(
+        "{}-{}-{:09}",
+        transaction_id.account_id,
+        transaction_id.valid_start.unix_timestamp(),
+        transaction_id.valid_start.nanosecond()
+    )
+}
+
+fn items<T: DeserializeOwned>(mut body: Value, key: &str) -> CradleResult<Vec<T>> {
+    serde_json::from_value(body[key].take()).map_err(|e| {
+        CradleError::decoding(format!("Unexpected mirror {} listing: {}", key, e))
+    })
+}
+
+/// Body of `POST /api/v1/contracts/call`. Addresses are `0x` prefixed evm addresses, `data`
+/// the encoded call with its selector.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MirrorCallRequest {
+    pub to: String,
+    pub data: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas: Option<u64>,
+    /// Tinybars sent along with the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<i64>,
+    pub estimate: bool,
+    pub block: String,
+}
+
+impl MirrorCallRequest {
+    pub fn new(to: &CradleAddress, data: &[u8]) -> Self {
+        Self {
+            to: to.to_evm_address(),
+            data: format!("0x{}", hex::encode(data)),
+            from: None,
+            gas: None,
+            value: None,
+            estimate: false,
+            block: "latest".to_string(),
+        }
+    }
+
+    /// The same call a `ContractExecuteTransaction` of `call` would make, sent by `operator`.
+    pub fn for_call(call: &ContractCallRequest, operator: AccountId) -> CradleResult<Self> {
+        let contract_id = call
+            .contract_id
+            .ok_or_else(|| CradleError::config("Contract id is required"))?;
+
+        let mut request = Self::new(&contract_id.into(), &call.parameters);
+        request.from = CradleAddress::try_from(operator)
+            .ok()
+            .map(|operator| operator.to_evm_address());
+        request.gas = Some(call.gas);
+        request.value = call.payable_amount.map(|amount| amount.to_tinybars());
+
+        Ok(request)
+    }
+}
+
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Path and query of the next page, for `MirrorClient::page` or `list`.
+    pub next: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+/// Filters of a listing, passed through as mirror node query parameters, e.g.
+/// `MirrorQuery::new().param("timestamp", "gte:1700000000").order(Order::Desc)`.
+#[derive(Clone, Debug, Default)]
+pub struct MirrorQuery {
+    params: Vec<(String, String)>,
+    max_items: Option<usize>,
+}
+
+impl MirrorQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn param(mut self, name: &str, value: impl Display) -> Self {
+        self.params.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Page size, capped at the mirror node's maximum of 100.
+    pub fn limit(self, limit: u32) -> Self {
+        self.param("limit", limit.min(MAX_PAGE_SIZE))
+    }
+
+    pub fn order(self, order: Order) -> Self {
+        match order {
+            Order::Asc => self.param("order", "asc"),
+            Order::Desc => self.param("order", "desc"),
+        }
+    }
+
+    /// Stops following pages once this many items were read.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ContractInfo {
+    pub contract_id: String,
+    pub evm_address: String,
+    #[serde(default)]
+    pub file_id: Option<String>,
+    #[serde(default)]
+    pub created_timestamp: Option<String>,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub memo: String,
+    #[serde(default)]
+    pub bytecode: Option<String>,
+    #[serde(default)]
+    pub runtime_bytecode: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ContractResult {
+    #[serde(default)]
+    pub contract_id: Option<String>,
+    #[serde(default)]
+    pub from: Option<String>,
+    #[serde(default)]
+    pub to: Option<String>,
+    #[serde(default)]
+    pub amount: i64,
+    #[serde(default)]
+    pub gas_limit: u64,
+    #[serde(default)]
+    pub gas_used: Option<u64>,
+    #[serde(default)]
+    pub function_parameters: Option<String>,
+    #[serde(default)]
+    pub call_result: Option<String>,
+    #[serde(default)]
+    pub error_message: Option<String>,
+    /// `SUCCESS`, or the failure status such as `CONTRACT_REVERT_EXECUTED`.
+    #[serde(default)]
+    pub result: Option<String>,
+    #[serde(default)]
+    pub hash: Option<String>,
+    pub timestamp: String,
+    #[serde(default)]
+    pub block_number: Option<u64>,
+}
+
+impl ContractResult {
+    pub fn is_success(&self) -> bool {
+        self.result.as_deref() == Some("SUCCESS")
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ContractLog {
+    pub address: String,
+    #[serde(default)]
+    pub contract_id: Option<String>,
+    #[serde(default)]
+    pub data: Option<String>,
+    #[serde(default)]
+    pub index: u32,
+    #[serde(default)]
+    pub topics: Vec<String>,
+    pub timestamp: String,
+    #[serde(default)]
+    pub transaction_hash: Option<String>,
+    #[serde(default)]
+    pub block_number: Option<u64>,
+}
+
+impl ContractLog {
+    /// Decodes the log with the abi events the decoder knows, `None` for unknown events.
+    pub fn decode(&self, decoder: &EventDecoder) -> CradleResult<Option<DecodedEvent>> {
+        let topics = self
+            .topics
+            .iter()
+            .map(|topic| hex_bytes(topic))
+            .collect::<CradleResult<Vec<_>>>()?;
+        let data = match &self.data {
+            Some(data) => hex_bytes(data)?,
+            None => Vec::new(),
+        };
+
+        let contract_id = self.contract_id.clone().unwrap_or_else(|| self.address.clone());
+        decoder.decode(contract_id, &topics, &data)
+    }
+}
+
+fn hex_bytes(value: &str) -> CradleResult<Vec<u8>> {
+    hex::decode(value.trim_start_matches("0x"))
+        .map_err(|e| CradleError::decoding(format!("Invalid hex {}: {}", value, e)))
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccountInfo {
+    pub account: String,
+    #[serde(default)]
+    pub evm_address: Option<String>,
+    #[serde(default)]
+    pub alias: Option<String>,
+    #[serde(default)]
+    pub balance: Option<AccountBalanceInfo>,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub memo: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccountBalanceInfo {
+    /// Tinybars.
+    pub balance: i64,
+    #[serde(default)]
+    pub timestamp: Option<String>,
+    #[serde(default)]
+    pub tokens: Vec<AccountTokenBalance>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccountTokenBalance {
+    pub token_id: String,
+    pub balance: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TokenInfo {
+    pub token_id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub symbol: String,
+    /// Listings return a number, single tokens a string.
+    #[serde(deserialize_with = "number_or_string")]
+    pub decimals: u64,
+    /// `FUNGIBLE_COMMON` or `NON_FUNGIBLE_UNIQUE`.
+    #[serde(rename = "type", default)]
+    pub token_type: String,
+    #[serde(default)]
+    pub total_supply: Option<String>,
+    #[serde(default)]
+    pub treasury_account_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TokenBalance {
+    pub account: String,
+    pub balance: u64,
+    #[serde(default)]
+    pub decimals: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TransactionInfo {
+    pub transaction_id: String,
+    pub consensus_timestamp: String,
+    /// Transaction type, e.g. `CONTRACTCALL`.
+    pub name: String,
+    /// Receipt status, e.g. `SUCCESS`.
+    pub result: String,
+    #[serde(default)]
+    pub charged_tx_fee: u64,
+    #[serde(default)]
+    pub entity_id: Option<String>,
+    #[serde(default)]
+    pub transaction_hash: Option<String>,
+    #[serde(default)]
+    pub valid_start_timestamp: Option<String>,
+    #[serde(default)]
+    pub scheduled: bool,
+    #[serde(default)]
+    pub nonce: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ScheduleInfo {
+    pub schedule_id: String,
+    #[serde(default)]
+    pub creator_account_id: Option<String>,
+    #[serde(default)]
+    pub payer_account_id: Option<String>,
+    #[serde(default)]
+    pub memo: String,
+    /// `seconds.nanoseconds`, like every mirror node timestamp.
+    #[serde(default)]
+    pub consensus_timestamp: Option<String>,
+    #[serde(default)]
+    pub expiration_time: Option<String>,
+    /// Set once the scheduled transaction has run.
+    #[serde(default)]
+    pub executed_timestamp: Option<String>,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub wait_for_expiry: bool,
+    #[serde(default)]
+    pub signatures: Vec<ScheduleSignature>,
+    /// The scheduled transaction body, base64 encoded.
+    #[serde(default)]
+    pub transaction_body: Option<String>,
+}
+
+impl ScheduleInfo {
+    pub fn is_executed(&self) -> bool {
+        self.executed_timestamp.is_some()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ScheduleSignature {
+    pub consensus_timestamp: String,
+    /// Base64 encoded prefix of the signing public key.
+    pub public_key_prefix: String,
+    /// `ED25519` or `ECDSA_SECP256K1`.
+    #[serde(rename = "type", default)]
+    pub signature_type: String,
+}
+
+fn number_or_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::Number(number) => number
+            .as_u64()
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid number {}", number))),
+        Value::String(value) => value.parse().map_err(serde::de::Error::custom),
+        other => Err(serde::de::Error::custom(format!("expected a number, got {}", other))),
+    }
+}
+
+#[cfg(test)]
+mod mirror_tests {
+    use super::*;
+    use serde_json::json;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serves one canned json body per connection, in order, and returns the mirror base url.
+    async fn serve(bodies: Vec<(u16, Value)>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let address = listener.local_addr().expect("address");
+
+        tokio::spawn(async move {
+            for (status, body) in bodies {
+                let (mut socket, _) = listener.accept().await.expect("accept");
+                let mut request = [0u8; 4096];
+                let _ = socket.read(&mut request).await;
+
+                let body = body.to_string();
+                let response = format!(
+                    "HTTP/1.1 {} X\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\
+                     connection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        format!("http://{}", address)
+    }
+
+    fn contract(num: u64) -> Value {
+        json!({ "contract_id": format!("0.0.{}", num), "evm_address": format!("0x{:040x}", num) })
+    }
+
+    #[tokio::test]
+    async fn follows_next_links_across_pages() -> CradleResult<()> {
+        let url = serve(vec![
+            (200, json!({ "contracts": [contract(1), contract(2)],
+                          "links": { "next": "/api/v1/contracts?limit=2&contract.id=gt:0.0.2" } })),
+            (200, json!({ "contracts": [contract(3)], "links": { "next": null } })),
+        ])
+        .await;
+
+        let mirror = MirrorClient::new(url);
+        let contracts = mirror.contracts(&MirrorQuery::new().limit(2)).await?;
+
+        let ids: Vec<&str> = contracts.iter().map(|c| c.contract_id.as_str()).collect();
+        assert_eq!(ids, ["0.0.1", "0.0.2", "0.0.3"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors_and_maps_not_found() -> CradleResult<()> {
+        let url = serve(vec![
+            (503, json!({})),
+            (200, contract(7)),
+            (404, json!({ "_status": { "messages": [{ "message": "Not found" }] } })),
+        ])
+        .await;
+
+        let mirror = MirrorClient::new(url).with_retries(3, Duration::from_millis(1));
+
+        assert_eq!(mirror.contract("0.0.7").await?.evm_address, format!("0x{:040x}", 7));
+        assert!(mirror.find_contract("0.0.8").await?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let mirror = MirrorClient::new("http://localhost")
+            .with_retries(100, Duration::from_secs(1))
+            .with_max_backoff(Duration::from_secs(30));
+
+        assert_eq!(mirror.backoff(1), Duration::from_secs(1));
+        assert_eq!(mirror.backoff(4), Duration::from_secs(8));
+        assert_eq!(mirror.backoff(6), Duration::from_secs(30));
+        assert_eq!(mirror.backoff(40), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn token_decimals_are_read_from_numbers_and_strings() -> serde_json::Result<()> {
+        let listed: TokenInfo = serde_json::from_value(
+            json!({ "token_id": "0.0.5", "decimals": 6, "type": "FUNGIBLE_COMMON" }),
+        )?;
+        let single: TokenInfo =
+            serde_json::from_value(json!({ "token_id": "0.0.5", "decimals": "6" }))?;
+
+        assert_eq!(listed.decimals, 6);
+        assert_eq!(single.decimals, 6);
+        assert_eq!(listed.token_type, "FUNGIBLE_COMMON");
+
+        Ok(())
+    }
+}
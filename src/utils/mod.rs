@@ -1,3 +1,10 @@
 pub mod script_utils;
 pub mod contract;
-pub mod functions;
\ No newline at end of file
+pub mod functions;
+pub mod errors;
+pub mod abi;
+pub mod events;
+pub mod amount;
+pub mod address;
+pub mod mirror;
+pub mod poll;
\ No newline at end of file
@@ -0,0 +1,94 @@
+use crate::utils::errors::{CradleError, CradleResult};
+use std::future::Future;
+use tokio::time::{Duration, Instant, sleep};
+
+/// How long to keep checking a condition and how often. The interval doubles after every
+/// miss, up to `max_interval`.
+#[derive(Clone, Copy, Debug)]
+pub struct PollOptions {
+    pub timeout: Duration,
+    pub interval: Duration,
+    pub max_interval: Duration,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(60),
+            interval: Duration::from_millis(500),
+            max_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+impl PollOptions {
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Runs `check` until it returns a value or the deadline passes. Errors from `check` end the
+/// polling straight away, so it should only return `None` for "not yet".
+pub async fn poll_until<T, F, Fut>(
+    what: &str,
+    options: &PollOptions,
+    mut check: F,
+) -> CradleResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = CradleResult<Option<T>>>,
+{
+    let deadline = Instant::now() + options.timeout;
+    let mut interval = options.interval;
+
+    loop {
+        if let Some(value) = check().await? {
+            return Ok(value);
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(CradleError::Timeout(format!("{} after {:?}", what, options.timeout)));
+        }
+
+        sleep(interval.min(deadline - now)).await;
+        interval = (interval * 2).min(options.max_interval);
+    }
+}
+
+#[cfg(test)]
+mod poll_tests {
+    use super::*;
+
+    fn quick() -> PollOptions {
+        PollOptions {
+            timeout: Duration::from_millis(50),
+            interval: Duration::from_millis(1),
+            max_interval: Duration::from_millis(4),
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_as_soon_as_the_condition_holds() -> CradleResult<()> {
+        let mut checks = 0;
+
+        let value = poll_until("the third check", &quick(), || {
+            checks += 1;
+            let ready = (checks == 3).then_some(checks);
+            async move { Ok(ready) }
+        })
+        .await?;
+
+        assert_eq!(value, 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_with_a_timeout_at_the_deadline() {
+        let result = poll_until("nothing", &quick(), || async { Ok(None::<()>) }).await;
+
+        assert!(matches!(result, Err(CradleError::Timeout(_))));
+    }
+}
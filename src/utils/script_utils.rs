@@ -1,6 +1,15 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::{TokenAmount, U256};
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::wallet::keys::ThresholdKey;
+use crate::wallet::signer::{KeystoreSigner, Signer};
+use crate::wallet::wallet::ActionWallet;
 use clap::Parser;
-use hedera::{AccountId, ContractId, PrivateKey};
+use dialoguer::{Input, Password};
+use hedera::{AccountId, ContractId, Key, PrivateKey};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
 
 #[derive(Parser, Debug)]
 pub struct GetClientArgs {
@@ -10,6 +19,55 @@ pub struct GetClientArgs {
     pub operator_key: PrivateKey,
     #[clap(long, env, default_value = "testnet")]
     pub network: String,
+    /// Admin key of deployed contracts as `<threshold>:<key>,<key>,...`, instead of the
+    /// operator key.
+    #[clap(long, env)]
+    pub admin_threshold_key: Option<ThresholdKey>,
+    /// Keystores of admin key holders who co-sign contract creation, comma separated.
+    #[clap(long, env, value_delimiter = ',')]
+    pub admin_keystores: Vec<PathBuf>,
+}
+
+impl GetClientArgs {
+    pub fn admin_key(&self) -> CradleResult<Key> {
+        match &self.admin_threshold_key {
+            Some(key) => key.to_key(),
+            None => Ok(self.operator_key.public_key().into()),
+        }
+    }
+
+    /// Opens the admin keystores, asking for each password, and checks that together with the
+    /// operator they meet the threshold. The network requires the admin key to sign contract
+    /// creation.
+    pub fn admin_signers(&self) -> CradleResult<Vec<Arc<dyn Signer>>> {
+        let Some(admin_key) = &self.admin_threshold_key else {
+            return Ok(Vec::new());
+        };
+
+        let mut signers: Vec<Arc<dyn Signer>> = Vec::new();
+        for path in &self.admin_keystores {
+            let password = Password::new()
+                .with_prompt(format!("Password of {}", path.display()))
+                .interact()
+                .map_err(|e| CradleError::config(format!("Failed to read password: {}", e)))?;
+            signers.push(Arc::new(KeystoreSigner::open(path, &password)?));
+        }
+
+        let signed_by: Vec<String> = signers
+            .iter()
+            .map(|signer| signer.public_key())
+            .chain([self.operator_key.public_key()])
+            .map(|key| key.to_string_der())
+            .collect();
+        if !admin_key.is_satisfied_by(&signed_by) {
+            return Err(CradleError::config(format!(
+                "{} more admin keystores are needed to sign",
+                admin_key.missing(&signed_by)
+            )));
+        }
+
+        Ok(signers)
+    }
 }
 
 #[derive(Parser, Debug)]
@@ -85,3 +143,28 @@ pub struct BaseAssetConstructor {
     #[clap(long, env)]
     pub access_controller_contract_id: String,
 }
+
+/// Asks for the token an amount is denominated in and returns its decimals.
+pub async fn prompt_decimals(wallet: &ActionWallet, prompt: &str) -> anyhow::Result<u8> {
+    let token: CradleAddress = Input::new()
+        .with_prompt(format!("{} (token id or address)", prompt))
+        .interact()?;
+
+    Ok(wallet.token_decimals(&token).await?)
+}
+
+/// Asks for an amount in human units, e.g. `12.5`, and scales it by the token's decimals.
+/// When `token` is not known yet it is asked for first.
+pub async fn prompt_token_amount(
+    wallet: &ActionWallet,
+    prompt: &str,
+    token: Option<&CradleAddress>,
+) -> anyhow::Result<U256> {
+    let decimals = match token {
+        Some(token) => wallet.token_decimals(token).await?,
+        None => prompt_decimals(wallet, &format!("{} token", prompt)).await?,
+    };
+
+    let value: String = Input::new().with_prompt(prompt).interact()?;
+    Ok(TokenAmount::from_human(&value, decimals)?.raw)
+}
@@ -0,0 +1,543 @@
+use crate::utils::errors::{ContractRevert, CradleError, CradleResult, RevertReason};
+use crate::wallet::policy::CallPolicy;
+use crate::wallet::schedule::ScheduleOptions;
+use crate::wallet::signer::Signer;
+use async_trait::async_trait;
+use hedera::{
+    AccountId, Client, ContractCallQuery, ContractExecuteTransaction, ContractFunctionParameters,
+    ContractFunctionResult, ContractId, Hbar, ScheduleCreateTransaction, ScheduleDeleteTransaction,
+    ScheduleId, ScheduleSignTransaction, Status, TokenAssociateTransaction, TokenId,
+    TransactionId, TransactionReceiptQuery, TransactionRecordQuery,
+};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use time::{Duration, OffsetDateTime};
+
+/// Everything needed to run a contract function, either as a `ContractExecuteTransaction`
+/// or as a `ContractCallQuery`. Setters mirror the hedera builders so wrappers read the same.
+#[derive(Clone, Debug, Default)]
+pub struct ContractCallRequest {
+    pub contract_id: Option<ContractId>,
+    pub function_name: String,
+    pub parameters: Vec<u8>,
+    pub gas: u64,
+    pub payable_amount: Option<Hbar>,
+    pub max_transaction_fee: Option<Hbar>,
+    pub payment_amount: Option<Hbar>,
+}
+
+impl ContractCallRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn contract_id(&mut self, contract_id: ContractId) -> &mut Self {
+        self.contract_id = Some(contract_id);
+        self
+    }
+
+    pub fn gas(&mut self, gas: u64) -> &mut Self {
+        self.gas = gas;
+        self
+    }
+
+    pub fn payable_amount(&mut self, amount: Hbar) -> &mut Self {
+        self.payable_amount = Some(amount);
+        self
+    }
+
+    pub fn max_transaction_fee(&mut self, fee: Hbar) -> &mut Self {
+        self.max_transaction_fee = Some(fee);
+        self
+    }
+
+    pub fn payment_amount(&mut self, amount: Hbar) -> &mut Self {
+        self.payment_amount = Some(amount);
+        self
+    }
+
+    pub fn function(&mut self, name: &str) -> &mut Self {
+        self.function_with_parameters(name, &ContractFunctionParameters::new())
+    }
+
+    pub fn function_with_parameters(
+        &mut self,
+        name: &str,
+        parameters: &ContractFunctionParameters,
+    ) -> &mut Self {
+        self.function_name = name.to_string();
+        self.parameters = parameters.to_bytes(Some(name));
+        self
+    }
+
+    /// Raw encoded parameters, selector included.
+    pub fn function_parameters(&mut self, data: Vec<u8>) -> &mut Self {
+        self.parameters = data;
+        self
+    }
+
+    pub fn to_execute_transaction(&self) -> ContractExecuteTransaction {
+        let mut transaction = ContractExecuteTransaction::new();
+        if let Some(contract_id) = self.contract_id {
+            transaction.contract_id(contract_id);
+        }
+        transaction.gas(self.gas);
+        transaction.function_parameters(self.parameters.clone());
+        if let Some(amount) = self.payable_amount {
+            transaction.payable_amount(amount);
+        }
+        if let Some(fee) = self.max_transaction_fee {
+            transaction.max_transaction_fee(fee);
+        }
+
+        transaction
+    }
+
+    pub fn to_query(&self) -> ContractCallQuery {
+        let mut query = ContractCallQuery::new();
+        if let Some(contract_id) = self.contract_id {
+            query.contract_id(contract_id);
+        }
+        query.gas(self.gas);
+        query.function_parameters(self.parameters.clone());
+        if let Some(amount) = self.payment_amount {
+            query.payment_amount(amount);
+        }
+
+        query
+    }
+}
+
+/// A successful result carrying `bytes` as return data, for backends that don't run on a node.
+pub fn function_result(contract_id: ContractId, bytes: Vec<u8>) -> ContractFunctionResult {
+    ContractFunctionResult {
+        contract_id,
+        evm_address: None,
+        bytes,
+        error_message: None,
+        bloom: Vec::new(),
+        gas_used: 0,
+        logs: Vec::new(),
+        hbar_amount: 0,
+        contract_function_parameters_bytes: Vec::new(),
+        sender_account_id: None,
+        gas: 0,
+        signer_nonce: None,
+    }
+}
+
+/// A state changing request submitted through a backend.
+#[derive(Clone, Debug)]
+pub enum TransactionRequest {
+    ContractExecute(ContractCallRequest),
+    TokenAssociate {
+        account_id: AccountId,
+        token_ids: Vec<TokenId>,
+    },
+    /// A contract execute wrapped in a `ScheduleCreateTransaction`.
+    ScheduleCreate {
+        call: ContractCallRequest,
+        options: ScheduleOptions,
+    },
+    ScheduleSign {
+        schedule_id: ScheduleId,
+    },
+    ScheduleDelete {
+        schedule_id: ScheduleId,
+    },
+}
+
+impl TransactionRequest {
+    /// Short description for reports, e.g. `tokenAssociate(0.0.1234)`.
+    pub fn description(&self) -> String {
+        match self {
+            TransactionRequest::ContractExecute(call) => call.function_name.clone(),
+            TransactionRequest::TokenAssociate { account_id, .. } => {
+                format!("tokenAssociate({})", account_id)
+            }
+            TransactionRequest::ScheduleCreate { call, .. } => {
+                format!("scheduleCreate({})", call.function_name)
+            }
+            TransactionRequest::ScheduleSign { schedule_id } => {
+                format!("scheduleSign({})", schedule_id)
+            }
+            TransactionRequest::ScheduleDelete { schedule_id } => {
+                format!("scheduleDelete({})", schedule_id)
+            }
+        }
+    }
+
+    /// What tells the transaction apart when a call is built again, e.g.
+    /// `settleOrder@0.0.1006:<call data>:0` with the payable tinybars last. Gas limits are
+    /// picked per run and left out.
+    pub fn fingerprint(&self) -> String {
+        match self {
+            TransactionRequest::ContractExecute(call)
+            | TransactionRequest::ScheduleCreate { call, .. } => format!(
+                "{}@{}:{}:{}",
+                self.description(),
+                call.contract_id.map(|id| id.to_string()).unwrap_or_default(),
+                hex::encode(&call.parameters),
+                call.payable_amount.map(|amount| amount.to_tinybars()).unwrap_or(0)
+            ),
+            _ => self.description(),
+        }
+    }
+
+    /// Whether `other` submits the same transaction; see `fingerprint`.
+    pub fn matches(&self, other: &TransactionRequest) -> bool {
+        self.fingerprint() == other.fingerprint()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CallReceipt {
+    pub transaction_id: TransactionId,
+    pub status: Status,
+    /// Set by schedule creations.
+    pub schedule_id: Option<ScheduleId>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CallRecord {
+    pub receipt: CallReceipt,
+    pub transaction_fee: Hbar,
+    pub consensus_timestamp: OffsetDateTime,
+    pub contract_function_result: Option<ContractFunctionResult>,
+}
+
+/// Result of a `ContractCallQuery` along with the payment made for it.
+#[derive(Clone, Debug)]
+pub struct QueryResponse {
+    pub payment_transaction_id: Option<TransactionId>,
+    pub cost: Hbar,
+    pub result: ContractFunctionResult,
+}
+
+/// Transport used by `ActionWallet` to reach the network. The hedera client is the default,
+/// tests can swap in `MockBackend`.
+///
+/// Receipts and records that do not end in `SUCCESS` are returned as errors, with contract
+/// reverts decoded into `CradleError::Revert`.
+#[async_trait]
+pub trait ContractBackend: Debug + Send + Sync {
+    async fn execute(&self, request: &TransactionRequest) -> CradleResult<TransactionId>;
+
+    async fn query(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse>;
+
+    async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt>;
+
+    async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord>;
+
+    /// Id for the next transaction of the operator, for callers that record it before
+    /// submitting with `execute_with_id`.
+    fn next_transaction_id(&self) -> CradleResult<TransactionId> {
+        Err(CradleError::config("The backend doesn't hand out transaction ids"))
+    }
+
+    /// Submits `request` under `transaction_id` rather than a fresh id.
+    async fn execute_with_id(
+        &self,
+        request: &TransactionRequest,
+        transaction_id: TransactionId,
+    ) -> CradleResult<TransactionId> {
+        Err(CradleError::config(format!(
+            "The backend can't submit {} as {}",
+            request.description(),
+            transaction_id
+        )))
+    }
+}
+
+/// Hands out transaction ids with strictly increasing valid starts, so transactions submitted
+/// concurrently by one operator never share an id and fail as `DUPLICATE_TRANSACTION`.
+#[derive(Debug, Default)]
+pub struct TransactionIds {
+    last_valid_start: Mutex<Option<OffsetDateTime>>,
+}
+
+impl TransactionIds {
+    pub fn next(&self, account_id: AccountId) -> TransactionId {
+        let generated = TransactionId::generate(account_id);
+
+        let mut last_valid_start = self.last_valid_start.lock().unwrap();
+        let valid_start = match *last_valid_start {
+            Some(last) if generated.valid_start <= last => last + Duration::nanoseconds(1),
+            _ => generated.valid_start,
+        };
+        *last_valid_start = Some(valid_start);
+
+        TransactionId {
+            valid_start,
+            ..generated
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HederaBackend {
+    client: Client,
+    policy: CallPolicy,
+    transaction_ids: Arc<TransactionIds>,
+    signer_error: Arc<Mutex<Option<CradleError>>>,
+}
+
+impl HederaBackend {
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            policy: CallPolicy::default(),
+            transaction_ids: Arc::default(),
+            signer_error: Arc::default(),
+        }
+    }
+
+    /// Makes `account_id` the client's operator, signing through `signer`. The client can't
+    /// fail a signature, so a signer error is kept and returned by the call that needed it.
+    pub fn with_operator(self, account_id: AccountId, signer: Arc<dyn Signer>) -> Self {
+        let signer_error = self.signer_error.clone();
+        self.client
+            .set_operator_with(account_id, signer.public_key(), move |message| {
+                signer.sign(message).unwrap_or_else(|e| {
+                    *signer_error.lock().unwrap() = Some(e);
+                    Vec::new()
+                })
+            });
+
+        self
+    }
+
+    /// Timeouts handed to the hedera client. `ActionWallet` enforces the rest of the policy.
+    pub fn with_policy(mut self, policy: CallPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Error for a failed client call. An unsigned transaction is rejected by the node with
+    /// `INVALID_SIGNATURE`, so the signer's own error is returned in its place.
+    fn failed_call(&self, error: hedera::Error) -> CradleError {
+        self.signer_error
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap_or_else(|| error.into())
+    }
+
+    async fn failure(
+        &self,
+        transaction_id: TransactionId,
+        status: Status,
+        result: Option<ContractFunctionResult>,
+    ) -> CradleError {
+        if status != Status::ContractRevertExecuted {
+            return CradleError::Receipt {
+                status,
+                transaction_id: Some(transaction_id),
+            };
+        }
+
+        let result = match result {
+            Some(result) => Some(result),
+            None => TransactionRecordQuery::new()
+                .transaction_id(transaction_id)
+                .execute(&self.client)
+                .await
+                .ok()
+                .and_then(|record| record.contract_function_result),
+        };
+
+        let reason = result
+            .and_then(|result| result.error_message)
+            .map(|message| RevertReason::from_error_message(&message))
+            .unwrap_or(RevertReason::Unknown(String::new()));
+
+        CradleError::Revert(ContractRevert {
+            transaction_id: Some(transaction_id),
+            status,
+            reason,
+        })
+    }
+}
+
+#[async_trait]
+impl ContractBackend for HederaBackend {
+    async fn execute(&self, request: &TransactionRequest) -> CradleResult<TransactionId> {
+        self.execute_with_id(request, self.next_transaction_id()?).await
+    }
+
+    fn next_transaction_id(&self) -> CradleResult<TransactionId> {
+        let account_id = self
+            .client
+            .get_operator_account_id()
+            .ok_or_else(|| CradleError::config("The client has no operator to pay"))?;
+
+        Ok(self.transaction_ids.next(account_id))
+    }
+
+    async fn execute_with_id(
+        &self,
+        request: &TransactionRequest,
+        transaction_id: TransactionId,
+    ) -> CradleResult<TransactionId> {
+        let response = match request {
+            TransactionRequest::ContractExecute(call) => {
+                let mut transaction = call.to_execute_transaction();
+                transaction.transaction_id(transaction_id);
+
+                transaction
+                    .execute_with_timeout(&self.client, self.policy.execute_timeout)
+                    .await
+                    .map_err(|e| self.failed_call(e))?
+            }
+            TransactionRequest::TokenAssociate {
+                account_id,
+                token_ids,
+            } => {
+                let mut transaction = TokenAssociateTransaction::new();
+                transaction.transaction_id(transaction_id);
+                transaction.account_id(*account_id);
+                transaction.token_ids(token_ids.clone());
+
+                transaction
+                    .execute_with_timeout(&self.client, self.policy.execute_timeout)
+                    .await
+                    .map_err(|e| self.failed_call(e))?
+            }
+            TransactionRequest::ScheduleCreate { call, options } => {
+                let mut transaction = ScheduleCreateTransaction::new();
+                transaction.transaction_id(transaction_id);
+                transaction.scheduled_transaction(call.to_execute_transaction());
+                transaction.wait_for_expiry(options.wait_for_expiry);
+                if let Some(memo) = &options.memo {
+                    transaction.schedule_memo(memo);
+                }
+                if let Some(expiration_time) = options.expiration_time {
+                    transaction.expiration_time(expiration_time);
+                }
+                if let Some(admin_key) = &options.admin_key {
+                    transaction.admin_key(admin_key.to_key()?);
+                }
+                if let Some(payer_account_id) = options.payer_account_id {
+                    transaction.payer_account_id(payer_account_id);
+                }
+
+                transaction
+                    .execute_with_timeout(&self.client, self.policy.execute_timeout)
+                    .await
+                    .map_err(|e| self.failed_call(e))?
+            }
+            TransactionRequest::ScheduleSign { schedule_id } => {
+                ScheduleSignTransaction::new()
+                    .transaction_id(transaction_id)
+                    .schedule_id(*schedule_id)
+                    .execute_with_timeout(&self.client, self.policy.execute_timeout)
+                    .await
+                    .map_err(|e| self.failed_call(e))?
+            }
+            TransactionRequest::ScheduleDelete { schedule_id } => {
+                ScheduleDeleteTransaction::new()
+                    .transaction_id(transaction_id)
+                    .schedule_id(*schedule_id)
+                    .execute_with_timeout(&self.client, self.policy.execute_timeout)
+                    .await
+                    .map_err(|e| self.failed_call(e))?
+            }
+        };
+
+        Ok(response.transaction_id)
+    }
+
+    async fn query(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse> {
+        let mut query = request.to_query();
+
+        // Pin the payment so the cost and payment transaction can be reported back.
+        let cost = match request.payment_amount {
+            Some(amount) => amount,
+            None => {
+                let cost = query
+                    .get_cost(&self.client)
+                    .await
+                    .map_err(|e| self.failed_call(e))?;
+                query.payment_amount(cost);
+                cost
+            }
+        };
+
+        let payment_transaction_id = self.next_transaction_id().ok().map(|transaction_id| {
+            query.payment_transaction_id(transaction_id);
+            transaction_id
+        });
+
+        let result = query
+            .execute_with_timeout(&self.client, self.policy.query_timeout)
+            .await
+            .map_err(|e| self.failed_call(e))?;
+
+        Ok(QueryResponse {
+            payment_transaction_id,
+            cost,
+            result,
+        })
+    }
+
+    async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt> {
+        let receipt = TransactionReceiptQuery::new()
+            .transaction_id(*transaction_id)
+            .execute_with_timeout(&self.client, self.policy.receipt_timeout)
+            .await?;
+
+        if receipt.status != Status::Success {
+            return Err(self.failure(*transaction_id, receipt.status, None).await);
+        }
+
+        Ok(CallReceipt {
+            transaction_id: receipt.transaction_id.unwrap_or(*transaction_id),
+            status: receipt.status,
+            schedule_id: receipt.schedule_id,
+        })
+    }
+
+    async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord> {
+        let record = TransactionRecordQuery::new()
+            .transaction_id(*transaction_id)
+            .execute_with_timeout(&self.client, self.policy.receipt_timeout)
+            .await?;
+
+        if record.receipt.status != Status::Success {
+            let status = record.receipt.status;
+            return Err(self
+                .failure(*transaction_id, status, record.contract_function_result)
+                .await);
+        }
+
+        Ok(CallRecord {
+            receipt: CallReceipt {
+                transaction_id: record.transaction_id,
+                status: record.receipt.status,
+                schedule_id: record.receipt.schedule_id,
+            },
+            transaction_fee: record.transaction_fee,
+            consensus_timestamp: record.consensus_timestamp,
+            contract_function_result: record.contract_function_result,
+        })
+    }
+}
+
+#[cfg(test)]
+mod backend_tests {
+    use super::*;
+
+    #[test]
+    fn transaction_ids_of_one_operator_never_repeat() {
+        let ids = TransactionIds::default();
+        let operator = AccountId::new(0, 0, 2);
+
+        let generated: Vec<TransactionId> = (0..1000).map(|_| ids.next(operator)).collect();
+
+        assert!(generated.windows(2).all(|pair| pair[0].valid_start < pair[1].valid_start));
+    }
+}
@@ -0,0 +1,256 @@
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::utils::functions::{ContractCallInput, ContractCallOutput};
+use crate::wallet::wallet::ActionWallet;
+use futures::stream::FuturesUnordered;
+use futures::{FutureExt, StreamExt};
+use std::collections::BTreeSet;
+use std::panic::AssertUnwindSafe;
+
+/// One call of a batch. `depends_on` lists earlier items, by index, that must have succeeded
+/// before this one starts, e.g. an airdrop after the association of its token.
+#[derive(Clone, Debug)]
+pub struct BatchItem {
+    pub input: ContractCallInput,
+    pub depends_on: Vec<usize>,
+}
+
+impl BatchItem {
+    pub fn new(input: ContractCallInput) -> Self {
+        Self {
+            input,
+            depends_on: Vec::new(),
+        }
+    }
+
+    /// Waits for item `index` to succeed.
+    pub fn after(mut self, index: usize) -> Self {
+        self.depends_on.push(index);
+        self
+    }
+}
+
+impl From<ContractCallInput> for BatchItem {
+    fn from(input: ContractCallInput) -> Self {
+        Self::new(input)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BatchOptions {
+    /// Calls in flight at once.
+    pub concurrency: usize,
+    /// Skip every item not yet started once one fails.
+    pub stop_on_error: bool,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 8,
+            stop_on_error: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BatchOutcome {
+    Succeeded(ContractCallOutput),
+    Failed(CradleError),
+    /// Not run, because a dependency didn't succeed or an earlier item failed with
+    /// `stop_on_error` set.
+    Skipped,
+}
+
+impl BatchOutcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, BatchOutcome::Succeeded(_))
+    }
+
+    pub fn output(&self) -> Option<&ContractCallOutput> {
+        match self {
+            BatchOutcome::Succeeded(output) => Some(output),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&CradleError> {
+        match self {
+            BatchOutcome::Failed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Runs `items` with at most `options.concurrency` in flight, starting them in input order as
+/// their dependencies complete. Every call returns after its receipt or record, so a dependent
+/// item only starts once the state it relies on has reached consensus. Outcomes come back in
+/// input order; an item that panics fails on its own without taking the batch down.
+///
+/// Transactions of one operator get distinct, increasing valid starts from the wallet's
+/// backend, so concurrent submissions don't collide as duplicates.
+pub async fn execute_batch(
+    wallet: &ActionWallet,
+    items: Vec<BatchItem>,
+    options: &BatchOptions,
+) -> CradleResult<Vec<BatchOutcome>> {
+    for (index, item) in items.iter().enumerate() {
+        if let Some(dependency) = item.depends_on.iter().find(|dependency| **dependency >= index) {
+            return Err(CradleError::config(format!(
+                "Batch item {} can only depend on earlier items, not {}",
+                index, dependency
+            )));
+        }
+    }
+
+    let concurrency = options.concurrency.max(1);
+    let mut outcomes: Vec<Option<BatchOutcome>> = items.iter().map(|_| None).collect();
+    let mut stopped = false;
+    let mut running = FuturesUnordered::new();
+
+    // Dependencies each item still waits for, and the items waiting on each one
+    let mut waiting: Vec<usize> = items.iter().map(|item| item.depends_on.len()).collect();
+    let mut dependents = vec![Vec::new(); items.len()];
+    for (index, item) in items.iter().enumerate() {
+        for dependency in &item.depends_on {
+            dependents[*dependency].push(index);
+        }
+    }
+    let mut ready: BTreeSet<usize> =
+        (0..items.len()).filter(|index| waiting[*index] == 0).collect();
+
+    loop {
+        while !stopped && running.len() < concurrency {
+            let Some(index) = ready.pop_first() else {
+                break;
+            };
+
+            let mut item_wallet = wallet.clone();
+            let input = items[index].input.clone();
+            running.push(async move {
+                let result = AssertUnwindSafe(item_wallet.execute(input))
+                    .catch_unwind()
+                    .await
+                    .unwrap_or_else(|panic| {
+                        Err(CradleError::panicked(&format!("Batch item {}", index), panic))
+                    });
+                (index, result)
+            });
+        }
+
+        let Some((index, result)) = running.next().await else {
+            break;
+        };
+
+        match result {
+            Ok(output) => {
+                outcomes[index] = Some(BatchOutcome::Succeeded(output));
+                for dependent in &dependents[index] {
+                    waiting[*dependent] -= 1;
+                    if waiting[*dependent] == 0 && outcomes[*dependent].is_none() {
+                        ready.insert(*dependent);
+                    }
+                }
+            }
+            Err(error) => {
+                stopped |= options.stop_on_error;
+                outcomes[index] = Some(BatchOutcome::Failed(error));
+
+                let mut skipped = dependents[index].clone();
+                while let Some(dependent) = skipped.pop() {
+                    if outcomes[dependent].is_none() {
+                        outcomes[dependent] = Some(BatchOutcome::Skipped);
+                        skipped.extend(&dependents[dependent]);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(outcomes
+        .into_iter()
+        .map(|outcome| outcome.unwrap_or(BatchOutcome::Skipped))
+        .collect())
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use super::*;
+    use crate::wallet::test_fixtures::{mock_wallet, settle_order};
+    use anyhow::Result;
+
+    #[tokio::test]
+    async fn batches_report_outcomes_in_input_order() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+        backend.push_return(Vec::new()).push_failure("node unavailable");
+
+        let items = vec![
+            BatchItem::new(settle_order()?),
+            BatchItem::new(settle_order()?),
+            BatchItem::new(settle_order()?).after(1),
+            BatchItem::new(settle_order()?).after(0),
+        ];
+        let options = BatchOptions {
+            concurrency: 1,
+            ..BatchOptions::default()
+        };
+        let outcomes = wallet.execute_batch(items, &options).await?;
+
+        assert!(outcomes[0].is_success());
+        assert!(outcomes[1].error().is_some());
+        assert!(matches!(outcomes[2], BatchOutcome::Skipped));
+        assert!(outcomes[3].is_success());
+        assert_eq!(backend.calls().len(), 3);
+
+        let outcomes = wallet
+            .execute_batch(vec![settle_order()?.into(); 6], &BatchOptions::default())
+            .await?;
+        assert!(outcomes.iter().all(BatchOutcome::is_success));
+
+        let backwards = vec![BatchItem::new(settle_order()?).after(0)];
+        assert!(wallet.execute_batch(backwards, &BatchOptions::default()).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn a_panicking_item_fails_alone() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+        backend.push_panic("backend bug");
+
+        let items = vec![
+            BatchItem::new(settle_order()?),
+            BatchItem::new(settle_order()?).after(0),
+            BatchItem::new(settle_order()?),
+        ];
+        let options = BatchOptions {
+            concurrency: 1,
+            ..BatchOptions::default()
+        };
+        let outcomes = wallet.execute_batch(items, &options).await?;
+
+        let error = outcomes[0].error().expect("the panic is an error");
+        assert!(error.to_string().contains("Batch item 0 panicked"));
+        assert!(matches!(outcomes[1], BatchOutcome::Skipped));
+        assert!(outcomes[2].is_success());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batches_stop_on_the_first_error_when_asked() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+        backend.push_failure("node unavailable");
+
+        let options = BatchOptions {
+            concurrency: 1,
+            stop_on_error: true,
+        };
+        let outcomes = wallet.execute_batch(vec![settle_order()?.into(); 3], &options).await?;
+
+        assert!(outcomes[0].error().is_some());
+        assert!(outcomes[1..].iter().all(|outcome| matches!(outcome, BatchOutcome::Skipped)));
+        assert_eq!(backend.calls().len(), 1);
+
+        Ok(())
+    }
+}
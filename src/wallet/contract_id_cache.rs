@@ -0,0 +1,211 @@
+use crate::utils::address::CradleAddress;
+use crate::utils::errors::{CradleError, CradleResult};
+use hedera::ContractId;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+/// Evm addresses of contracts with their `ContractId`, kept per mirror node so testnet and
+/// mainnet ids never mix. Long-zero addresses resolve without a lookup; everything else is
+/// remembered once resolved and, for a file backed cache, written straight to disk.
+///
+/// The file holds `{ "<mirror node url>": { "0x<evm address>": "0.0.1234" } }`.
+#[derive(Debug, Default)]
+pub struct ContractIdCache {
+    path: Option<PathBuf>,
+    scope: String,
+    entries: Mutex<HashMap<[u8; 20], ContractId>>,
+}
+
+impl ContractIdCache {
+    /// A cache that only lives as long as the process.
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Reads the entries of `scope` from `path`. A missing or unreadable file is an empty cache.
+    pub fn load(path: impl Into<PathBuf>, scope: &str) -> CradleResult<Self> {
+        let path = path.into();
+        let entries = read_file(&path)?
+            .remove(scope)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(address, id)| {
+                let address = CradleAddress::from_str(&address).ok()?;
+                Some((address.evm_bytes(), ContractId::from_str(&id).ok()?))
+            })
+            .collect();
+
+        Ok(Self {
+            path: Some(path),
+            scope: scope.to_string(),
+            entries: Mutex::new(entries),
+        })
+    }
+
+    /// The id behind `address`, if it is known without asking the mirror node.
+    pub fn get(&self, address: &CradleAddress) -> Option<ContractId> {
+        if !address.is_alias() {
+            return address.to_contract_id().ok();
+        }
+
+        self.entries.lock().unwrap().get(&address.evm_bytes()).copied()
+    }
+
+    /// The evm address a contract was resolved from, if any.
+    pub fn evm_address(&self, contract_id: &ContractId) -> Option<CradleAddress> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(_, id)| *id == contract_id)
+            .map(|(bytes, _)| CradleAddress::Alias(*bytes))
+    }
+
+    pub fn insert(&self, address: &CradleAddress, contract_id: ContractId) -> CradleResult<()> {
+        if !address.is_alias() {
+            return Ok(());
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        if entries.insert(address.evm_bytes(), contract_id) == Some(contract_id) {
+            return Ok(());
+        }
+
+        match &self.path {
+            Some(path) => self.persist(path, &entries),
+            None => Ok(()),
+        }
+    }
+
+    /// Merges each entry into the file rather than overwriting it, so other scopes and ids
+    /// written by other processes survive. Holds a lock on `<path>.lock` throughout, so
+    /// processes sharing the file don't drop each other's ids.
+    fn persist(&self, path: &Path, entries: &HashMap<[u8; 20], ContractId>) -> CradleResult<()> {
+        let write_error = |e: std::io::Error| {
+            CradleError::config(format!("Failed to write {}: {}", path.display(), e))
+        };
+
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(write_error)?;
+        }
+
+        let lock = File::create(path.with_extension("json.lock")).map_err(write_error)?;
+        lock.lock().map_err(write_error)?;
+
+        let mut file = read_file(path)?;
+        let scope = file.entry(self.scope.clone()).or_default();
+        for (bytes, id) in entries {
+            scope.insert(CradleAddress::Alias(*bytes).to_evm_address(), id.to_string());
+        }
+
+        let data = serde_json::to_string_pretty(&file)
+            .map_err(|e| CradleError::decoding(format!("Failed to encode cache: {}", e)))?;
+
+        // Written next to the cache and renamed over it, so a crash never leaves half a file
+        let temporary = path.with_extension("json.tmp");
+        fs::write(&temporary, data).map_err(write_error)?;
+        fs::rename(&temporary, path).map_err(write_error)
+    }
+}
+
+type CacheFile = BTreeMap<String, BTreeMap<String, String>>;
+
+fn read_file(path: &Path) -> CradleResult<CacheFile> {
+    match fs::read_to_string(path) {
+        // Only a cache: a corrupt file is started over rather than failing every wallet
+        Ok(data) => Ok(serde_json::from_str(&data).unwrap_or_else(|e| {
+            log::warn!("Ignoring invalid contract id cache {}: {}", path.display(), e);
+            CacheFile::new()
+        })),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(CacheFile::new()),
+        Err(e) => Err(CradleError::config(format!(
+            "Failed to read contract id cache {}: {}",
+            path.display(),
+            e
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod contract_id_cache_tests {
+    use super::*;
+    use std::env;
+    use uuid::Uuid;
+
+    const POOL: &str = "0x5b38da6a701c568545dcfcb03fcb875f56beddc4";
+
+    #[test]
+    fn long_zero_addresses_resolve_offline() -> CradleResult<()> {
+        let cache = ContractIdCache::in_memory();
+        let address: CradleAddress = "0x00000000000000000000000000000000000004d2".parse()?;
+
+        assert_eq!(cache.get(&address), Some(ContractId::new(0, 0, 1234)));
+        assert_eq!(cache.get(&POOL.parse()?), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolved_aliases_survive_a_reload() -> CradleResult<()> {
+        let path = env::temp_dir().join(format!("cradle-cache-{}/ids.json", Uuid::new_v4()));
+        let pool: CradleAddress = POOL.parse()?;
+
+        let cache = ContractIdCache::load(&path, "https://testnet.mirrornode.hedera.com")?;
+        cache.insert(&pool, ContractId::new(0, 0, 4321))?;
+
+        let reloaded = ContractIdCache::load(&path, "https://testnet.mirrornode.hedera.com")?;
+        let other_network = ContractIdCache::load(&path, "http://localhost:5551")?;
+
+        assert_eq!(reloaded.get(&pool), Some(ContractId::new(0, 0, 4321)));
+        assert_eq!(reloaded.evm_address(&ContractId::new(0, 0, 4321)), Some(pool));
+        assert_eq!(other_network.get(&pool), None);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+
+        Ok(())
+    }
+
+    #[test]
+    fn caches_sharing_a_file_keep_each_others_ids() -> CradleResult<()> {
+        let path = env::temp_dir().join(format!("cradle-cache-{}.json", Uuid::new_v4()));
+        let scope = "http://localhost:5551";
+        let pool: CradleAddress = POOL.parse()?;
+        let listing: CradleAddress = "0x4b20993bc481177ec7e8f571cecae8a9e22c02db".parse()?;
+
+        let first = ContractIdCache::load(&path, scope)?;
+        let second = ContractIdCache::load(&path, scope)?;
+        first.insert(&pool, ContractId::new(0, 0, 4321))?;
+        second.insert(&listing, ContractId::new(0, 0, 8765))?;
+
+        let reloaded = ContractIdCache::load(&path, scope)?;
+        assert_eq!(reloaded.get(&pool), Some(ContractId::new(0, 0, 4321)));
+        assert_eq!(reloaded.get(&listing), Some(ContractId::new(0, 0, 8765)));
+
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(path.with_extension("json.lock"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn an_invalid_cache_file_is_started_over() -> CradleResult<()> {
+        let path = env::temp_dir().join(format!("cradle-cache-{}.json", Uuid::new_v4()));
+        fs::write(&path, "{ not json").unwrap();
+        let pool: CradleAddress = POOL.parse()?;
+
+        let cache = ContractIdCache::load(&path, "http://localhost:5551")?;
+        assert_eq!(cache.get(&pool), None);
+
+        cache.insert(&pool, ContractId::new(0, 0, 4321))?;
+        let reloaded = ContractIdCache::load(&path, "http://localhost:5551")?;
+        assert_eq!(reloaded.get(&pool), Some(ContractId::new(0, 0, 4321)));
+
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(path.with_extension("json.lock"));
+
+        Ok(())
+    }
+}
@@ -1,22 +1,111 @@
+use crate::utils::errors::{CradleError, CradleResult};
 use clap::Parser;
 use hedera::ContractId;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::str::FromStr;
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Serialize, Deserialize, Debug, Clone)]
 pub struct CradleContractIds {
     #[clap(long, env)]
+    #[serde(with = "contract_id")]
     pub access_controller_contract_id: ContractId,
     #[clap(long, env)]
+    #[serde(with = "contract_id")]
     pub bridged_asset_issuer_contract_id: ContractId,
     #[clap(long, env)]
+    #[serde(with = "contract_id")]
     pub native_asset_issuer_contract_id: ContractId,
     #[clap(long, env)]
+    #[serde(with = "contract_id")]
     pub cradle_account_factory_contract_id: ContractId,
     #[clap(long, env)]
+    #[serde(with = "contract_id")]
     pub asset_factory: ContractId,
     #[clap(long, env)]
+    #[serde(with = "contract_id")]
     pub cradle_order_book_settler_contract_id: ContractId,
     #[clap(long, env)]
+    #[serde(with = "contract_id")]
     pub asset_lending_pool_factory: ContractId,
     #[clap(long, env)]
+    #[serde(with = "contract_id")]
     pub cradle_listing_factory_contract_id: ContractId,
 }
+
+impl CradleContractIds {
+    /// Builds the ids from a lookup keyed by environment variable name, e.g.
+    /// `ACCESS_CONTROLLER_CONTRACT_ID`, the same names `deploy_all` records.
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> CradleResult<Self> {
+        let id = |name: &str| -> CradleResult<ContractId> {
+            let value = lookup(name)
+                .ok_or_else(|| CradleError::config(format!("{} is not set", name)))?;
+
+            ContractId::from_str(value.trim())
+                .map_err(|e| CradleError::config(format!("Invalid {} {}: {}", name, value, e)))
+        };
+
+        Ok(Self {
+            access_controller_contract_id: id("ACCESS_CONTROLLER_CONTRACT_ID")?,
+            bridged_asset_issuer_contract_id: id("BRIDGED_ASSET_ISSUER_CONTRACT_ID")?,
+            native_asset_issuer_contract_id: id("NATIVE_ASSET_ISSUER_CONTRACT_ID")?,
+            cradle_account_factory_contract_id: id("CRADLE_ACCOUNT_FACTORY_CONTRACT_ID")?,
+            asset_factory: id("ASSET_FACTORY")?,
+            cradle_order_book_settler_contract_id: id("CRADLE_ORDER_BOOK_SETTLER_CONTRACT_ID")?,
+            asset_lending_pool_factory: id("ASSET_LENDING_POOL_FACTORY")?,
+            cradle_listing_factory_contract_id: id("CRADLE_LISTING_FACTORY_CONTRACT_ID")?,
+        })
+    }
+
+    /// Reads the ids from the environment only, never from the process arguments.
+    pub fn from_env() -> CradleResult<Self> {
+        Self::from_lookup(|name| env::var(name).ok())
+    }
+
+    /// Replaces any id whose environment variable is set.
+    pub fn with_env_overrides(self) -> CradleResult<Self> {
+        let entries = self.entries();
+
+        Self::from_lookup(|name| {
+            env::var(name).ok().or_else(|| {
+                entries
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, id)| id.to_string())
+            })
+        })
+    }
+
+    /// Each id with its environment variable name.
+    pub fn entries(&self) -> [(&'static str, ContractId); 8] {
+        [
+            ("ACCESS_CONTROLLER_CONTRACT_ID", self.access_controller_contract_id),
+            ("BRIDGED_ASSET_ISSUER_CONTRACT_ID", self.bridged_asset_issuer_contract_id),
+            ("NATIVE_ASSET_ISSUER_CONTRACT_ID", self.native_asset_issuer_contract_id),
+            ("CRADLE_ACCOUNT_FACTORY_CONTRACT_ID", self.cradle_account_factory_contract_id),
+            ("ASSET_FACTORY", self.asset_factory),
+            ("CRADLE_ORDER_BOOK_SETTLER_CONTRACT_ID", self.cradle_order_book_settler_contract_id),
+            ("ASSET_LENDING_POOL_FACTORY", self.asset_lending_pool_factory),
+            ("CRADLE_LISTING_FACTORY_CONTRACT_ID", self.cradle_listing_factory_contract_id),
+        ]
+    }
+}
+
+/// `ContractId` as its `shard.realm.num` string.
+mod contract_id {
+    use hedera::ContractId;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(id: &ContractId, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&id.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ContractId, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        ContractId::from_str(&value).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,213 @@
+use hedera::Hbar;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// How much gas contract calls get. Each function starts from the limit its wrapper asks for
+/// and moves to the gas it actually used once the wallet has seen it run, or to the mirror
+/// node's estimate when `mirror_estimate` is on. A call that runs out of gas counts as having
+/// used its whole limit. Either way the limit is scaled by the safety multiplier and clamped
+/// to the caps.
+///
+/// Profiles can set any of this per function, keyed by the solidity function name:
+///
+/// ```toml
+/// [testnet.gas]
+/// multiplier = 1.3
+///
+/// [testnet.gas.functions.liquidate]
+/// max_gas = 12_000_000
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct GasPolicy {
+    pub multiplier: f64,
+    pub min_gas: u64,
+    pub max_gas: u64,
+    /// Ask the mirror node for an estimate when a function hasn't been seen yet.
+    pub mirror_estimate: bool,
+    /// Fixed payment for queries in tinybars. Unset, the node is asked for the cost.
+    pub query_payment: Option<i64>,
+    pub functions: HashMap<String, GasProfile>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct GasProfile {
+    /// Always use this limit, skipping estimation.
+    pub gas: Option<u64>,
+    pub multiplier: Option<f64>,
+    pub min_gas: Option<u64>,
+    pub max_gas: Option<u64>,
+}
+
+impl Default for GasPolicy {
+    fn default() -> Self {
+        Self {
+            multiplier: 1.2,
+            min_gas: 50_000,
+            // Per transaction gas limit of the network
+            max_gas: 15_000_000,
+            mirror_estimate: false,
+            query_payment: None,
+            functions: HashMap::new(),
+        }
+    }
+}
+
+impl GasPolicy {
+    pub fn with_function(mut self, function_name: &str, profile: GasProfile) -> Self {
+        self.functions.insert(function_name.to_string(), profile);
+        self
+    }
+
+    pub fn query_payment(&self) -> Option<Hbar> {
+        self.query_payment.map(Hbar::from_tinybars)
+    }
+
+    /// The fixed limit configured for `function_name`, if any.
+    pub fn fixed_gas(&self, function_name: &str) -> Option<u64> {
+        self.functions.get(function_name).and_then(|profile| profile.gas)
+    }
+
+    /// Limit for a call that used, or is estimated to use, `gas`.
+    pub fn with_margin(&self, function_name: &str, gas: u64) -> u64 {
+        let profile = self.functions.get(function_name);
+        let multiplier = profile
+            .and_then(|profile| profile.multiplier)
+            .unwrap_or(self.multiplier);
+
+        self.clamp(function_name, (gas as f64 * multiplier).ceil() as u64)
+    }
+
+    pub fn clamp(&self, function_name: &str, gas: u64) -> u64 {
+        let profile = self.functions.get(function_name);
+        let min_gas = profile.and_then(|profile| profile.min_gas).unwrap_or(self.min_gas);
+        let max_gas = profile.and_then(|profile| profile.max_gas).unwrap_or(self.max_gas);
+
+        gas.max(min_gas).min(max_gas)
+    }
+}
+
+#[cfg(test)]
+mod gas_tests {
+    use super::*;
+    use crate::utils::amount::U256;
+    use crate::utils::functions::ContractCallInput;
+    use crate::utils::functions::asset_lending_pool::{
+        AssetLendingPoolFunctionsInput, borrow, get_pool_stats,
+    };
+    use crate::wallet::backend::TransactionRequest;
+    use crate::wallet::mock_backend::RecordedCall;
+    use crate::wallet::test_fixtures::{ASSET, USER, mock_wallet, settle_order};
+    use anyhow::Result;
+    use hedera::{ContractFunctionParameters, Status};
+    use num_bigint::BigUint;
+
+    #[test]
+    fn margins_respect_function_caps() {
+        let policy = GasPolicy::default().with_function(
+            "liquidate",
+            GasProfile {
+                max_gas: Some(8_000_000),
+                multiplier: Some(2.0),
+                ..GasProfile::default()
+            },
+        );
+
+        assert_eq!(policy.with_margin("borrow", 100_000), 120_000);
+        assert_eq!(policy.with_margin("borrow", 10), 50_000);
+        assert_eq!(policy.with_margin("liquidate", 3_000_000), 6_000_000);
+        assert_eq!(policy.with_margin("liquidate", 5_000_000), 8_000_000);
+        assert_eq!(policy.clamp("settleOrder", 20_000_000), 15_000_000);
+    }
+
+    #[test]
+    fn reads_per_function_profiles() {
+        let policy: GasPolicy = toml::from_str(
+            "multiplier = 1.5\n[functions.settleOrder]\ngas = 700000\n",
+        )
+        .expect("valid gas policy");
+
+        assert_eq!(policy.fixed_gas("settleOrder"), Some(700_000));
+        assert_eq!(policy.fixed_gas("borrow"), None);
+        assert_eq!(policy.max_gas, 15_000_000);
+    }
+
+    #[tokio::test]
+    async fn gas_limits_follow_the_gas_policy() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+        let fixed = GasProfile {
+            gas: Some(700_000),
+            ..GasProfile::default()
+        };
+        let mut wallet = wallet
+            .with_gas_policy(GasPolicy::default().with_function("settleOrder", fixed))
+            .with_gas_usage([("borrow".to_string(), 400_000)].into());
+
+        wallet.execute(settle_order()?).await?;
+
+        let mut returned = ContractFunctionParameters::new();
+        returned.add_uint256(BigUint::from(1u64));
+        returned.add_uint256(BigUint::from(2u64));
+        backend.push_return(returned.to_bytes(None));
+
+        wallet
+            .execute(ContractCallInput::AssetLendingPool(
+                AssetLendingPoolFunctionsInput::Borrow(borrow::Args {
+                    user: USER.parse()?,
+                    collateral_amount: U256::from(500u64),
+                    collateral_asset: ASSET.parse()?,
+                    contract_id: "0.0.2001".parse()?,
+                }),
+            ))
+            .await?;
+
+        let gas: Vec<u64> = backend
+            .calls()
+            .iter()
+            .filter_map(|call| match call {
+                RecordedCall::Execute(TransactionRequest::ContractExecute(request)) => {
+                    Some(request.gas)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(gas, [700_000, 480_000]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn running_out_of_gas_raises_the_next_limit() -> Result<()> {
+        let (backend, mut wallet) = mock_wallet();
+        let pool_stats = || {
+            ContractCallInput::AssetLendingPool(AssetLendingPoolFunctionsInput::GetPoolStats(
+                get_pool_stats::Args {
+                    contract_id: "0.0.2001".parse().expect("valid contract id"),
+                },
+            ))
+        };
+
+        backend.push_precheck(Status::InsufficientGas);
+        assert!(wallet.execute(pool_stats()).await.is_err());
+
+        let mut returned = ContractFunctionParameters::new();
+        for _ in 0..8 {
+            returned.add_uint256(BigUint::from(1u64));
+        }
+        backend.push_return(returned.to_bytes(None));
+        wallet.execute(pool_stats()).await?;
+
+        let gas: Vec<u64> = backend
+            .calls()
+            .iter()
+            .filter_map(|call| match call {
+                RecordedCall::Query(request) => Some(request.gas),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(gas, [5_000_000, 6_000_000]);
+
+        Ok(())
+    }
+}
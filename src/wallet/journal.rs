@@ -0,0 +1,544 @@
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::utils::mirror::MirrorClient;
+use crate::wallet::backend::{
+    CallReceipt, CallRecord, ContractBackend, ContractCallRequest, QueryResponse,
+    TransactionRequest,
+};
+use crate::wallet::offline::MAX_VALID_DURATION;
+use async_trait::async_trait;
+use hedera::{Status, TransactionId};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use time::{Duration, OffsetDateTime};
+
+pub const DEFAULT_JOURNAL: &str = ".cradle/journal.jsonl";
+
+/// How far the mirror node may lag behind consensus.
+const MIRROR_DELAY: Duration = Duration::seconds(60);
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalStatus {
+    /// Written before submission. The transaction may or may not have reached consensus.
+    Pending,
+    Succeeded,
+    /// Known not to have changed any state, so the step may be submitted again.
+    Failed,
+}
+
+/// One transaction of a journaled call, as a line of the journal. Later lines for the same key
+/// and step replace earlier ones.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct JournalEntry {
+    /// Idempotency key chosen by the caller, e.g. the id of the order being settled.
+    pub key: String,
+    /// Position of the transaction within the call, for calls submitting more than one.
+    pub step: usize,
+    pub transaction_id: String,
+    /// `TransactionRequest::description` of the submitted request.
+    pub request: String,
+    /// `TransactionRequest::fingerprint` of the submitted request, which reruns must match.
+    pub fingerprint: String,
+    pub status: JournalStatus,
+    /// Why a failed entry failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// The journaled `ContractCallInput` and its decoded output, set on the entry of the call's
+    /// last transaction once the whole call succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<Value>,
+    /// Unix timestamp of the line.
+    pub recorded_at: i64,
+}
+
+impl JournalEntry {
+    fn pending(
+        key: &str,
+        step: usize,
+        transaction_id: TransactionId,
+        request: &TransactionRequest,
+    ) -> Self {
+        Self {
+            key: key.to_string(),
+            step,
+            transaction_id: transaction_id.to_string(),
+            request: request.description(),
+            fingerprint: request.fingerprint(),
+            status: JournalStatus::Pending,
+            detail: None,
+            call: None,
+            output: None,
+            recorded_at: OffsetDateTime::now_utc().unix_timestamp(),
+        }
+    }
+
+    fn with_status(&self, status: JournalStatus, detail: Option<String>) -> Self {
+        Self {
+            status,
+            detail,
+            recorded_at: OffsetDateTime::now_utc().unix_timestamp(),
+            ..self.clone()
+        }
+    }
+
+    fn with_output(&self, call: Value, output: Value) -> Self {
+        Self {
+            call: Some(call),
+            output: Some(output),
+            recorded_at: OffsetDateTime::now_utc().unix_timestamp(),
+            ..self.clone()
+        }
+    }
+
+    pub fn transaction_id(&self) -> CradleResult<TransactionId> {
+        TransactionId::from_str(&self.transaction_id).map_err(|e| {
+            CradleError::config(format!("Invalid transaction id {}: {}", self.transaction_id, e))
+        })
+    }
+
+    fn matches(&self, request: &TransactionRequest) -> bool {
+        self.fingerprint == request.fingerprint()
+    }
+}
+
+/// Append-only record of journaled transactions, one json line each, synced to disk before
+/// the transaction it describes is submitted. A crash can at worst leave the last line half
+/// written, which is dropped on the next open.
+///
+/// The file stays exclusively locked while the journal is open, so two processes can't both
+/// run the same key.
+#[derive(Debug)]
+pub struct Journal {
+    path: PathBuf,
+    file: File,
+    entries: Mutex<BTreeMap<(String, usize), JournalEntry>>,
+}
+
+impl Journal {
+    /// Reads and locks the journal at `path`. A missing file is an empty journal. Fails while
+    /// the journal is open elsewhere, e.g. in another process.
+    pub fn open(path: impl Into<PathBuf>) -> CradleResult<Self> {
+        let path = path.into();
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| io_error("create", &path, e))?;
+        }
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(&path)
+            .map_err(|e| io_error("open", &path, e))?;
+        file.try_lock().map_err(|e| match e {
+            TryLockError::WouldBlock => {
+                CradleError::config(format!("Journal {} is already open", path.display()))
+            }
+            TryLockError::Error(e) => io_error("lock", &path, e),
+        })?;
+
+        let mut data = String::new();
+        file.read_to_string(&mut data).map_err(|e| io_error("read", &path, e))?;
+
+        let mut entries = BTreeMap::new();
+        let mut complete = 0;
+        for line in data.split_inclusive('\n') {
+            let entry: JournalEntry = match serde_json::from_str(line.trim_end()) {
+                Ok(entry) => entry,
+                Err(_) if !line.ends_with('\n') => break,
+                Err(e) => {
+                    return Err(CradleError::config(format!(
+                        "Invalid journal line in {}: {}",
+                        path.display(),
+                        e
+                    )));
+                }
+            };
+            complete += line.len();
+            entries.insert((entry.key.clone(), entry.step), entry);
+        }
+
+        if complete < data.len() {
+            log::warn!("Dropping the torn last line of {}", path.display());
+            file.set_len(complete as u64).map_err(|e| io_error("truncate", &path, e))?;
+        }
+
+        Ok(Self {
+            path,
+            file,
+            entries: Mutex::new(entries),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn entry(&self, key: &str, step: usize) -> Option<JournalEntry> {
+        self.entries.lock().unwrap().get(&(key.to_string(), step)).cloned()
+    }
+
+    /// The latest state of every journaled transaction, by key and step.
+    pub fn entries(&self) -> Vec<JournalEntry> {
+        self.entries.lock().unwrap().values().cloned().collect()
+    }
+
+    /// Transactions whose outcome isn't known yet.
+    pub fn pending(&self) -> Vec<JournalEntry> {
+        self.entries()
+            .into_iter()
+            .filter(|entry| entry.status == JournalStatus::Pending)
+            .collect()
+    }
+
+    /// The entry holding the output of the call journaled under `key`, once the call succeeded.
+    pub fn completed(&self, key: &str) -> Option<JournalEntry> {
+        self.entries
+            .lock()
+            .unwrap()
+            .values()
+            .find(|entry| entry.key == key && entry.output.is_some())
+            .cloned()
+    }
+
+    /// Stores `call` and its decoded `output` on the last transaction of `key`. Calls that
+    /// submitted no transaction have nothing journaled and are left alone.
+    pub fn complete(&self, key: &str, call: Value, output: Value) -> CradleResult<()> {
+        let last = self
+            .entries
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|entry| entry.key == key)
+            .max_by_key(|entry| entry.step)
+            .cloned();
+
+        match last {
+            Some(entry) => self.record(entry.with_output(call, output)),
+            None => Ok(()),
+        }
+    }
+
+    /// Appends `entry` and waits for it to reach the disk.
+    pub fn record(&self, entry: JournalEntry) -> CradleResult<()> {
+        let mut entries = self.entries.lock().unwrap();
+
+        let mut line = serde_json::to_string(&entry)
+            .map_err(|e| CradleError::decoding(format!("Failed to encode journal entry: {}", e)))?;
+        line.push('\n');
+
+        self.append(line.as_bytes()).map_err(|e| io_error("write", &self.path, e))?;
+        entries.insert((entry.key.clone(), entry.step), entry);
+
+        Ok(())
+    }
+
+    fn append(&self, line: &[u8]) -> std::io::Result<()> {
+        (&self.file).write_all(line)?;
+        self.file.sync_data()
+    }
+}
+
+fn io_error(action: &str, path: &Path, error: std::io::Error) -> CradleError {
+    CradleError::config(format!("Failed to {} journal {}: {}", action, path.display(), error))
+}
+
+/// Status an entry settles on from its receipt or record, `None` while still unknown.
+fn settled_status<T>(result: &CradleResult<T>) -> Option<(JournalStatus, Option<String>)> {
+    match result {
+        Ok(_) => Some((JournalStatus::Succeeded, None)),
+        Err(CradleError::Revert(revert)) => {
+            Some((JournalStatus::Failed, Some(format!("reverted: {}", revert.reason))))
+        }
+        Err(CradleError::Receipt { status, .. })
+            if !matches!(
+                status,
+                Status::Unknown | Status::ReceiptNotFound | Status::RecordNotFound
+            ) =>
+        {
+            Some((JournalStatus::Failed, Some(format!("{:?}", status))))
+        }
+        Err(_) => None,
+    }
+}
+
+/// Settles a pending entry from its receipt or, once nodes no longer keep it, the mirror node.
+/// A transaction neither of them knows of after its valid duration has passed can no longer
+/// reach consensus and is marked failed. Entries that aren't pending come back unchanged.
+pub async fn reconcile(
+    backend: &dyn ContractBackend,
+    mirror: &MirrorClient,
+    journal: &Journal,
+    entry: JournalEntry,
+) -> CradleResult<JournalEntry> {
+    if entry.status != JournalStatus::Pending {
+        return Ok(entry);
+    }
+
+    let transaction_id = entry.transaction_id()?;
+    let receipt = backend.get_receipt(&transaction_id).await;
+    let settled = match settled_status(&receipt) {
+        Some(settled) => Some(settled),
+        None => match mirror.find_transaction(&transaction_id).await? {
+            Some(transaction) if transaction.result == "SUCCESS" => {
+                Some((JournalStatus::Succeeded, None))
+            }
+            Some(transaction) => Some((JournalStatus::Failed, Some(transaction.result))),
+            None if OffsetDateTime::now_utc()
+                > transaction_id.valid_start + MAX_VALID_DURATION + MIRROR_DELAY =>
+            {
+                Some((JournalStatus::Failed, Some("never reached consensus".to_string())))
+            }
+            None => None,
+        },
+    };
+
+    match settled {
+        Some((status, detail)) => {
+            let entry = entry.with_status(status, detail);
+            journal.record(entry.clone())?;
+            Ok(entry)
+        }
+        None => Ok(entry),
+    }
+}
+
+/// Backend of a journaled call. Every transaction is written to the journal under the call's
+/// key before it is submitted and settled from its receipt or record.
+///
+/// A key whose call completed is answered from the journal by `ActionWallet::execute_once`
+/// without reaching the backend. Running an unfinished key again skips the transactions that
+/// already succeeded, answering with their recorded ids, and resubmits only those known to have
+/// failed. While an earlier attempt's outcome can't be told, the call is refused.
+#[derive(Debug)]
+pub struct JournalBackend {
+    inner: Arc<dyn ContractBackend>,
+    journal: Arc<Journal>,
+    mirror: MirrorClient,
+    key: String,
+    step: Mutex<usize>,
+}
+
+impl JournalBackend {
+    pub fn new(
+        inner: Arc<dyn ContractBackend>,
+        journal: Arc<Journal>,
+        mirror: MirrorClient,
+        key: &str,
+    ) -> Self {
+        Self {
+            inner,
+            journal,
+            mirror,
+            key: key.to_string(),
+            step: Mutex::default(),
+        }
+    }
+
+    /// Moves to the next step once the current one's transaction has succeeded.
+    fn settle<T>(&self, transaction_id: &TransactionId, result: &CradleResult<T>) {
+        let step = *self.step.lock().unwrap();
+        let Some(entry) = self.journal.entry(&self.key, step) else {
+            return;
+        };
+        if entry.transaction_id != transaction_id.to_string() {
+            return;
+        }
+
+        let Some((status, detail)) = settled_status(result) else {
+            return;
+        };
+        if status != entry.status {
+            if let Err(e) = self.journal.record(entry.with_status(status, detail)) {
+                log::error!("Failed to journal the outcome of {}: {}", transaction_id, e);
+            }
+        }
+        if status == JournalStatus::Succeeded {
+            *self.step.lock().unwrap() += 1;
+        }
+    }
+}
+
+#[async_trait]
+impl ContractBackend for JournalBackend {
+    async fn execute(&self, request: &TransactionRequest) -> CradleResult<TransactionId> {
+        let step = *self.step.lock().unwrap();
+
+        if let Some(entry) = self.journal.entry(&self.key, step) {
+            if !entry.matches(request) {
+                return Err(CradleError::config(format!(
+                    "Key {} was used for {} rather than {}",
+                    self.key,
+                    entry.request,
+                    request.description()
+                )));
+            }
+
+            let entry = reconcile(&*self.inner, &self.mirror, &self.journal, entry).await?;
+            match entry.status {
+                JournalStatus::Succeeded => return entry.transaction_id(),
+                JournalStatus::Pending => {
+                    return Err(CradleError::config(format!(
+                        "The outcome of {} for key {} isn't known yet, retry once it is",
+                        entry.transaction_id, self.key
+                    )));
+                }
+                JournalStatus::Failed => {}
+            }
+        }
+
+        let transaction_id = self.inner.next_transaction_id()?;
+        let entry = JournalEntry::pending(&self.key, step, transaction_id, request);
+        self.journal.record(entry.clone())?;
+
+        match self.inner.execute_with_id(request, transaction_id).await {
+            // Turned away before consensus, unless a node already had it
+            Err(error @ CradleError::Precheck { status, .. })
+                if status != Status::DuplicateTransaction =>
+            {
+                let detail = Some(format!("{:?}", status));
+                self.journal.record(entry.with_status(JournalStatus::Failed, detail))?;
+                Err(error)
+            }
+            result => result,
+        }
+    }
+
+    async fn query(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse> {
+        self.inner.query(request).await
+    }
+
+    async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt> {
+        let result = self.inner.get_receipt(transaction_id).await;
+        self.settle(transaction_id, &result);
+        result
+    }
+
+    async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord> {
+        let result = self.inner.get_record(transaction_id).await;
+        self.settle(transaction_id, &result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod journal_tests {
+    use super::*;
+    use crate::utils::functions::ContractCallInput;
+    use crate::utils::functions::access_controller::{
+        AccessControllerFunctionsInput, clear_level,
+    };
+    use crate::wallet::test_fixtures::{mock_wallet, settle_order};
+    use anyhow::Result;
+    use hedera::{AccountId, ContractId};
+    use std::env;
+    use uuid::Uuid;
+
+    #[test]
+    fn a_torn_last_line_is_dropped_on_open() -> CradleResult<()> {
+        let path = env::temp_dir().join(format!("cradle-journal-{}.jsonl", Uuid::new_v4()));
+        let request = TransactionRequest::ScheduleSign {
+            schedule_id: hedera::ScheduleId::new(0, 0, 5000),
+        };
+        let transaction_id = TransactionId::generate(AccountId::new(0, 0, 2));
+
+        let journal = Journal::open(&path)?;
+        journal.record(JournalEntry::pending("sign", 0, transaction_id, &request))?;
+
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"key\":\"sign\",\"st").unwrap();
+        drop(journal);
+
+        let reopened = Journal::open(&path)?;
+        assert_eq!(reopened.pending().len(), 1);
+
+        let entry = reopened.entry("sign", 0).unwrap();
+        reopened.record(entry.with_status(JournalStatus::Succeeded, None))?;
+        drop(reopened);
+        assert!(Journal::open(&path)?.pending().is_empty());
+
+        let _ = fs::remove_file(&path);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn journaled_calls_run_once_per_key() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+        let path = env::temp_dir().join(format!("cradle-journal-{}.jsonl", Uuid::new_v4()));
+
+        let journal = Arc::new(Journal::open(&path)?);
+        let first = wallet.execute_once(&journal, "order-1", settle_order()?).await?;
+        assert_eq!(backend.calls().len(), 1);
+        assert_eq!(journal.entry("order-1", 0).unwrap().status, JournalStatus::Succeeded);
+        assert!(journal.completed("order-1").is_some());
+        drop(journal);
+
+        let reopened = Arc::new(Journal::open(&path)?);
+        let second = wallet.execute_once(&reopened, "order-1", settle_order()?).await?;
+        assert_eq!(backend.calls().len(), 1);
+        assert_eq!(serde_json::to_value(&first)?, serde_json::to_value(&second)?);
+
+        let clear_level = ContractCallInput::AccessController(
+            AccessControllerFunctionsInput::ClearLevel(clear_level::Args { level: 3 }),
+        );
+        assert!(wallet.execute_once(&reopened, "order-1", clear_level).await.is_err());
+
+        let _ = fs::remove_file(&path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn a_journal_is_open_only_once() -> CradleResult<()> {
+        let path = env::temp_dir().join(format!("cradle-journal-{}.jsonl", Uuid::new_v4()));
+
+        let journal = Journal::open(&path)?;
+        assert!(Journal::open(&path).is_err());
+
+        drop(journal);
+        assert!(Journal::open(&path).is_ok());
+
+        let _ = fs::remove_file(&path);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pending_journal_entries_settle_from_their_receipts() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+        let path = env::temp_dir().join(format!("cradle-journal-{}.jsonl", Uuid::new_v4()));
+        let journal = Journal::open(&path)?;
+
+        let mut call = ContractCallRequest::new();
+        call.contract_id(ContractId::new(0, 0, 1004)).function("settleOrder");
+        let transaction_id = backend.execute(&TransactionRequest::ContractExecute(call)).await?;
+
+        journal.record(JournalEntry {
+            key: "order-2".to_string(),
+            step: 0,
+            transaction_id: transaction_id.to_string(),
+            request: "settleOrder".to_string(),
+            fingerprint: "settleOrder".to_string(),
+            status: JournalStatus::Pending,
+            detail: None,
+            call: None,
+            output: None,
+            recorded_at: 0,
+        })?;
+
+        assert!(wallet.reconcile_journal(&journal).await?.is_empty());
+        assert_eq!(journal.entry("order-2", 0).unwrap().status, JournalStatus::Succeeded);
+
+        let _ = fs::remove_file(&path);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use crate::utils::errors::{CradleError, CradleResult};
+use hedera::{Key, KeyList, PublicKey};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// An M of N key: any `threshold` of `public_keys` can sign. Used as the admin key of deployed
+/// contracts and as the key of accounts holding privileged access levels, so no single leaked
+/// key can act for them.
+///
+/// Written as `<threshold>:<key>,<key>,...` with DER hex public keys, e.g. `2:302a...,302a...`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdKey {
+    pub threshold: u32,
+    /// DER hex public keys.
+    pub public_keys: Vec<String>,
+}
+
+impl ThresholdKey {
+    pub fn new(threshold: u32, public_keys: &[PublicKey]) -> CradleResult<Self> {
+        let key = Self {
+            threshold,
+            public_keys: public_keys.iter().map(|key| key.to_string_der()).collect(),
+        };
+        key.validate()?;
+
+        Ok(key)
+    }
+
+    fn validate(&self) -> CradleResult<()> {
+        if self.threshold == 0 || self.threshold as usize > self.public_keys.len() {
+            return Err(CradleError::config(format!(
+                "Threshold {} doesn't fit {} keys",
+                self.threshold,
+                self.public_keys.len()
+            )));
+        }
+
+        self.keys().map(|_| ())
+    }
+
+    pub fn keys(&self) -> CradleResult<Vec<PublicKey>> {
+        self.public_keys
+            .iter()
+            .map(|key| {
+                PublicKey::from_str(key)
+                    .map_err(|e| CradleError::config(format!("Invalid public key {}: {}", key, e)))
+            })
+            .collect()
+    }
+
+    /// The key as set on a contract or account.
+    pub fn to_key(&self) -> CradleResult<Key> {
+        self.validate()?;
+
+        Ok(Key::KeyList(KeyList {
+            keys: self.keys()?.into_iter().map(Key::from).collect(),
+            threshold: Some(self.threshold),
+        }))
+    }
+
+    pub fn contains(&self, public_key: &str) -> bool {
+        self.public_keys.iter().any(|key| key == public_key)
+    }
+
+    /// Signatures still needed, given the DER hex keys that have signed.
+    pub fn missing(&self, signed_by: &[String]) -> u32 {
+        let signed = self.public_keys.iter().filter(|key| signed_by.contains(key)).count();
+        self.threshold.saturating_sub(signed as u32)
+    }
+
+    pub fn is_satisfied_by(&self, signed_by: &[String]) -> bool {
+        self.missing(signed_by) == 0
+    }
+}
+
+impl FromStr for ThresholdKey {
+    type Err = CradleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (threshold, keys) = s
+            .split_once(':')
+            .ok_or_else(|| CradleError::config(format!("Expected <threshold>:<keys>, got {}", s)))?;
+
+        let key = Self {
+            threshold: threshold
+                .trim()
+                .parse()
+                .map_err(|_| CradleError::config(format!("Invalid threshold {}", threshold)))?,
+            public_keys: keys
+                .split(',')
+                .map(|key| key.trim().to_string())
+                .filter(|key| !key.is_empty())
+                .collect(),
+        };
+        key.validate()?;
+
+        Ok(key)
+    }
+}
+
+impl Display for ThresholdKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.threshold, self.public_keys.join(","))
+    }
+}
+
+#[cfg(test)]
+mod keys_tests {
+    use super::*;
+    use hedera::PrivateKey;
+
+    #[test]
+    fn thresholds_count_distinct_listed_signers() -> CradleResult<()> {
+        let keys: Vec<PublicKey> =
+            (0..3).map(|_| PrivateKey::generate_ed25519().public_key()).collect();
+        let key = ThresholdKey::new(2, &keys)?;
+        let outsider = PrivateKey::generate_ed25519().public_key().to_string_der();
+
+        assert_eq!(key.missing(&[]), 2);
+        assert_eq!(key.missing(&[key.public_keys[0].clone(), outsider]), 1);
+        assert!(key.is_satisfied_by(&key.public_keys[1..]));
+        assert_eq!(key.to_string().parse::<ThresholdKey>()?, key);
+
+        assert!(ThresholdKey::new(4, &keys).is_err());
+        assert!(ThresholdKey::new(0, &keys).is_err());
+
+        Ok(())
+    }
+}
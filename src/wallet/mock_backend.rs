@@ -0,0 +1,306 @@
+use crate::utils::errors::{ContractRevert, CradleError, CradleResult, RevertReason};
+use crate::wallet::backend::{
+    CallReceipt, CallRecord, ContractBackend, ContractCallRequest, QueryResponse,
+    TransactionIds, TransactionRequest, function_result,
+};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use hedera::{
+    AccountId, ContractFunctionResult, ContractId, Hbar, ScheduleId, Status, TransactionId,
+};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+use time::OffsetDateTime;
+
+#[derive(Clone, Debug)]
+pub enum RecordedCall {
+    Execute(TransactionRequest),
+    Query(ContractCallRequest),
+}
+
+impl RecordedCall {
+    pub fn function_name(&self) -> Option<&str> {
+        match self {
+            RecordedCall::Execute(TransactionRequest::ContractExecute(request))
+            | RecordedCall::Execute(TransactionRequest::ScheduleCreate { call: request, .. })
+            | RecordedCall::Query(request) => Some(request.function_name.as_str()),
+            RecordedCall::Execute(_) => None,
+        }
+    }
+
+    pub fn parameters(&self) -> Option<&[u8]> {
+        match self {
+            RecordedCall::Execute(TransactionRequest::ContractExecute(request))
+            | RecordedCall::Execute(TransactionRequest::ScheduleCreate { call: request, .. })
+            | RecordedCall::Query(request) => Some(request.parameters.as_slice()),
+            RecordedCall::Execute(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum MockResponse {
+    /// ABI encoded return data, as produced by `ContractFunctionParameters::to_bytes(None)`.
+    Return(Vec<u8>),
+    /// Raw revert data, surfaced as `CradleError::Revert` from the receipt or query.
+    Revert(Vec<u8>),
+    Fail(String),
+    /// Turned away by the node before consensus, as `CradleError::Precheck`.
+    Precheck(Status),
+    /// Panics in the backend call, e.g. to check that callers contain it.
+    Panic(String),
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    calls: Vec<RecordedCall>,
+    responses: VecDeque<MockResponse>,
+    records: HashMap<TransactionId, Result<Option<ContractFunctionResult>, Vec<u8>>>,
+    schedules: HashMap<TransactionId, ScheduleId>,
+}
+
+/// In-memory backend that records every request and replays scripted responses in order.
+/// Queries consume a response when they run, executes consume one when submitted and
+/// hand it back through `get_record`. Scheduled calls run as soon as they are scheduled, their
+/// response being the record of the scheduled transaction id.
+#[derive(Debug)]
+pub struct MockBackend {
+    operator: AccountId,
+    transaction_ids: TransactionIds,
+    state: Mutex<MockState>,
+}
+
+impl Default for MockBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockBackend {
+    pub fn new() -> Self {
+        Self {
+            operator: AccountId::new(0, 0, 2),
+            transaction_ids: TransactionIds::default(),
+            state: Mutex::new(MockState::default()),
+        }
+    }
+
+    pub fn push_return(&self, data: Vec<u8>) -> &Self {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .push_back(MockResponse::Return(data));
+        self
+    }
+
+    pub fn push_revert(&self, data: Vec<u8>) -> &Self {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .push_back(MockResponse::Revert(data));
+        self
+    }
+
+    pub fn push_failure(&self, message: &str) -> &Self {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .push_back(MockResponse::Fail(message.to_string()));
+        self
+    }
+
+    pub fn push_precheck(&self, status: Status) -> &Self {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .push_back(MockResponse::Precheck(status));
+        self
+    }
+
+    pub fn push_panic(&self, message: &str) -> &Self {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .push_back(MockResponse::Panic(message.to_string()));
+        self
+    }
+
+    pub fn calls(&self) -> Vec<RecordedCall> {
+        self.state.lock().unwrap().calls.clone()
+    }
+
+    pub fn last_call(&self) -> Option<RecordedCall> {
+        self.state.lock().unwrap().calls.last().cloned()
+    }
+
+    /// Raises a scripted panic, after releasing the lock so the backend stays usable.
+    fn panic_if_scripted(mut state: MutexGuard<'_, MockState>) -> MutexGuard<'_, MockState> {
+        if let Some(MockResponse::Panic(_)) = state.responses.front() {
+            let response = state.responses.pop_front();
+            drop(state);
+            panic!("{:?}", response);
+        }
+
+        state
+    }
+
+    fn next_response(state: &mut MockState) -> MockResponse {
+        state
+            .responses
+            .pop_front()
+            .unwrap_or(MockResponse::Return(Vec::new()))
+    }
+
+    fn call_result(
+        state: &mut MockState,
+        call: &ContractCallRequest,
+    ) -> CradleResult<Result<Option<ContractFunctionResult>, Vec<u8>>> {
+        match Self::next_response(state) {
+            MockResponse::Return(data) => {
+                let contract_id = call.contract_id.unwrap_or(ContractId::new(0, 0, 0));
+                Ok(Ok(Some(function_result(contract_id, data))))
+            }
+            MockResponse::Revert(data) => Ok(Err(data)),
+            MockResponse::Fail(message) => Err(CradleError::Other(anyhow!(message))),
+            MockResponse::Precheck(status) => Err(precheck(status)),
+            MockResponse::Panic(_) => unreachable!("scripted panics are raised up front"),
+        }
+    }
+
+    fn stored_record(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> CradleResult<Option<ContractFunctionResult>> {
+        let state = self.state.lock().unwrap();
+        let stored = state
+            .records
+            .get(transaction_id)
+            .cloned()
+            .ok_or_else(|| anyhow!("No record for transaction {}", transaction_id))
+            .map_err(CradleError::Other)?;
+
+        stored.map_err(|data| revert(Some(*transaction_id), &data))
+    }
+}
+
+fn precheck(status: Status) -> CradleError {
+    CradleError::Precheck {
+        status,
+        transaction_id: None,
+    }
+}
+
+fn revert(transaction_id: Option<TransactionId>, data: &[u8]) -> CradleError {
+    CradleError::Revert(ContractRevert {
+        transaction_id,
+        status: Status::ContractRevertExecuted,
+        reason: RevertReason::decode(data),
+    })
+}
+
+#[async_trait]
+impl ContractBackend for MockBackend {
+    async fn execute(&self, request: &TransactionRequest) -> CradleResult<TransactionId> {
+        self.execute_with_id(request, self.next_transaction_id()?).await
+    }
+
+    fn next_transaction_id(&self) -> CradleResult<TransactionId> {
+        Ok(self.transaction_ids.next(self.operator))
+    }
+
+    async fn execute_with_id(
+        &self,
+        request: &TransactionRequest,
+        transaction_id: TransactionId,
+    ) -> CradleResult<TransactionId> {
+        let mut state = self.state.lock().unwrap();
+        state.calls.push(RecordedCall::Execute(request.clone()));
+        let mut state = Self::panic_if_scripted(state);
+
+        let call = match request {
+            TransactionRequest::ContractExecute(call) => call,
+            TransactionRequest::ScheduleCreate { call, .. } => {
+                let schedule_id = ScheduleId::new(0, 0, 5000 + state.schedules.len() as u64);
+                state.schedules.insert(transaction_id, schedule_id);
+
+                let stored = Self::call_result(&mut state, call)?;
+                let scheduled = TransactionId {
+                    scheduled: true,
+                    ..transaction_id
+                };
+                state.records.insert(scheduled, stored);
+                state.records.insert(transaction_id, Ok(None));
+                return Ok(transaction_id);
+            }
+            _ => {
+                state.records.insert(transaction_id, Ok(None));
+                return Ok(transaction_id);
+            }
+        };
+
+        let stored = Self::call_result(&mut state, call)?;
+        state.records.insert(transaction_id, stored);
+
+        Ok(transaction_id)
+    }
+
+    async fn query(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse> {
+        let mut state = self.state.lock().unwrap();
+        state.calls.push(RecordedCall::Query(request.clone()));
+        let mut state = Self::panic_if_scripted(state);
+
+        let contract_id = request.contract_id.unwrap_or(ContractId::new(0, 0, 0));
+
+        match Self::next_response(&mut state) {
+            MockResponse::Return(data) => Ok(QueryResponse {
+                payment_transaction_id: Some(self.transaction_ids.next(self.operator)),
+                cost: request.payment_amount.unwrap_or(Hbar::from_tinybars(0)),
+                result: function_result(contract_id, data),
+            }),
+            MockResponse::Revert(data) => Err(revert(None, &data)),
+            MockResponse::Fail(message) => Err(CradleError::Other(anyhow!(message))),
+            MockResponse::Precheck(status) => Err(precheck(status)),
+            MockResponse::Panic(_) => unreachable!("scripted panics are raised up front"),
+        }
+    }
+
+    async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt> {
+        let state = self.state.lock().unwrap();
+        match state.records.get(transaction_id) {
+            Some(Err(data)) => return Err(revert(Some(*transaction_id), data)),
+            Some(Ok(_)) => {}
+            None => {
+                return Err(CradleError::Receipt {
+                    status: Status::ReceiptNotFound,
+                    transaction_id: Some(*transaction_id),
+                });
+            }
+        }
+
+        Ok(CallReceipt {
+            transaction_id: *transaction_id,
+            status: Status::Success,
+            schedule_id: state.schedules.get(transaction_id).copied(),
+        })
+    }
+
+    async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord> {
+        let result = self.stored_record(transaction_id)?;
+
+        Ok(CallRecord {
+            receipt: CallReceipt {
+                transaction_id: *transaction_id,
+                status: Status::Success,
+                schedule_id: self.state.lock().unwrap().schedules.get(transaction_id).copied(),
+            },
+            transaction_fee: Hbar::from_tinybars(0),
+            consensus_timestamp: OffsetDateTime::now_utc(),
+            contract_function_result: result,
+        })
+    }
+}
@@ -1,2 +1,21 @@
 pub mod wallet;
-pub mod contracts;
\ No newline at end of file
+pub mod contracts;
+pub mod backend;
+pub mod mock_backend;
+pub mod profile;
+pub mod network;
+pub mod contract_id_cache;
+pub mod simulation;
+pub mod gas;
+pub mod policy;
+pub mod signer;
+pub mod offline;
+pub mod keys;
+pub mod schedule;
+pub mod batch;
+pub mod journal;
+pub mod submission;
+pub mod read_only;
+
+#[cfg(test)]
+pub(crate) mod test_fixtures;
\ No newline at end of file
@@ -0,0 +1,211 @@
+use crate::utils::errors::{CradleError, CradleResult};
+use hedera::{AccountId, Client};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The network a wallet talks to. Picks both the consensus nodes and the mirror node, so the two
+/// can't drift apart.
+///
+/// Written as its name (`mainnet`, `testnet`, `previewnet`, `local`) or, for anything else, as a
+/// table of node addresses:
+///
+/// ```toml
+/// [network]
+/// mirror_node_url = "http://localhost:5551"
+/// nodes = { "127.0.0.1:50211" = "0.0.3" }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CradleNetwork {
+    Mainnet,
+    Testnet,
+    Previewnet,
+    /// A local node as started by `hiero-local-node`.
+    Local,
+    Custom(CustomNetwork),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CustomNetwork {
+    /// `host:port` of each consensus node, mapped to its node account id.
+    pub nodes: BTreeMap<String, String>,
+    /// Base url of the mirror node rest api, without `/api/v1`.
+    pub mirror_node_url: String,
+    /// `host:port` of the mirror node grpc api, when the client needs one.
+    #[serde(default)]
+    pub mirror_network: Vec<String>,
+}
+
+pub const LOCAL_NODE: &str = "127.0.0.1:50211";
+pub const LOCAL_MIRROR_NODE: &str = "127.0.0.1:5600";
+
+impl CradleNetwork {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Mainnet => "mainnet",
+            Self::Testnet => "testnet",
+            Self::Previewnet => "previewnet",
+            Self::Local => "local",
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    pub fn mirror_node_url(&self) -> String {
+        match self {
+            Self::Mainnet => "https://mainnet-public.mirrornode.hedera.com".to_string(),
+            Self::Testnet => "https://testnet.mirrornode.hedera.com".to_string(),
+            Self::Previewnet => "https://previewnet.mirrornode.hedera.com".to_string(),
+            Self::Local => "http://localhost:5551".to_string(),
+            Self::Custom(network) => network.mirror_node_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// A consensus client without operator.
+    pub fn client(&self) -> CradleResult<Client> {
+        let client_error = |e: hedera::Error| {
+            CradleError::config(format!("Failed to create {} client: {}", self.name(), e))
+        };
+
+        match self {
+            Self::Mainnet => Ok(Client::for_mainnet()),
+            Self::Testnet => Ok(Client::for_testnet()),
+            Self::Previewnet => Ok(Client::for_previewnet()),
+            Self::Local => {
+                let nodes = HashMap::from([(LOCAL_NODE.to_string(), AccountId::new(0, 0, 3))]);
+                let client = Client::for_network(nodes).map_err(client_error)?;
+                client.set_mirror_network([LOCAL_MIRROR_NODE.to_string()]);
+                Ok(client)
+            }
+            Self::Custom(network) => {
+                let nodes = network
+                    .nodes
+                    .iter()
+                    .map(|(address, node)| {
+                        let node = AccountId::from_str(node).map_err(|e| {
+                            CradleError::config(format!("Invalid node account {}: {}", node, e))
+                        })?;
+                        Ok((address.clone(), node))
+                    })
+                    .collect::<CradleResult<HashMap<String, AccountId>>>()?;
+
+                if nodes.is_empty() {
+                    return Err(CradleError::config("Custom network has no nodes"));
+                }
+
+                let client = Client::for_network(nodes).map_err(client_error)?;
+                if !network.mirror_network.is_empty() {
+                    client.set_mirror_network(network.mirror_network.clone());
+                }
+                Ok(client)
+            }
+        }
+    }
+}
+
+impl FromStr for CradleNetwork {
+    type Err = CradleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "mainnet" => Ok(Self::Mainnet),
+            "testnet" => Ok(Self::Testnet),
+            "previewnet" => Ok(Self::Previewnet),
+            "local" | "localhost" | "local-node" => Ok(Self::Local),
+            _ => Err(CradleError::config(format!(
+                "Unknown network {}, custom networks need a node map",
+                s
+            ))),
+        }
+    }
+}
+
+/// Named networks show their name, custom ones their mirror node, e.g.
+/// `custom (http://127.0.0.1:5551)`, so two custom networks can be told apart.
+impl Display for CradleNetwork {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Custom(_) => write!(f, "{} ({})", self.name(), self.mirror_node_url()),
+            named => write!(f, "{}", named.name()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum NetworkJson {
+    Named(String),
+    Custom(CustomNetwork),
+}
+
+impl Serialize for CradleNetwork {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Custom(network) => NetworkJson::Custom(network.clone()),
+            named => NetworkJson::Named(named.name().to_string()),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CradleNetwork {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match NetworkJson::deserialize(deserializer)? {
+            NetworkJson::Named(name) => name.parse().map_err(serde::de::Error::custom),
+            NetworkJson::Custom(network) => Ok(Self::Custom(network)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod network_tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Profile {
+        network: CradleNetwork,
+    }
+
+    #[test]
+    fn named_networks_pick_their_mirror_node() -> CradleResult<()> {
+        assert_eq!(
+            "mainnet".parse::<CradleNetwork>()?.mirror_node_url(),
+            "https://mainnet-public.mirrornode.hedera.com"
+        );
+        assert_eq!(CradleNetwork::from_str(" Previewnet ")?, CradleNetwork::Previewnet);
+        assert_eq!(CradleNetwork::from_str("localhost")?, CradleNetwork::Local);
+        assert!("devnet".parse::<CradleNetwork>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn custom_networks_are_read_as_node_maps() {
+        let profile: Profile = toml::from_str(
+            "[network]\nmirror_node_url = \"http://127.0.0.1:8080/\"\n\
+             nodes = { \"127.0.0.1:50211\" = \"0.0.3\" }\n",
+        )
+        .expect("valid network");
+
+        let CradleNetwork::Custom(custom) = &profile.network else {
+            panic!("expected a custom network, got {}", profile.network);
+        };
+        assert_eq!(custom.nodes.get("127.0.0.1:50211").map(String::as_str), Some("0.0.3"));
+        assert_eq!(profile.network.mirror_node_url(), "http://127.0.0.1:8080");
+        assert_eq!(profile.network.to_string(), "custom (http://127.0.0.1:8080)");
+
+        let named: Profile = toml::from_str("network = \"testnet\"").expect("valid network");
+        assert_eq!(named.network, CradleNetwork::Testnet);
+    }
+
+    #[test]
+    fn custom_networks_need_nodes() {
+        let network = CradleNetwork::Custom(CustomNetwork {
+            nodes: BTreeMap::new(),
+            mirror_node_url: "http://localhost:5551".to_string(),
+            mirror_network: Vec::new(),
+        });
+
+        assert!(network.client().is_err());
+    }
+}
@@ -0,0 +1,606 @@
+use crate::utils::abi;
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::utils::functions::ContractCallInput;
+use crate::utils::functions::dynamic::find_function;
+use crate::utils::functions::generated::function_by_selector;
+use crate::wallet::backend::{
+    CallReceipt, CallRecord, ContractBackend, ContractCallRequest, QueryResponse,
+    TransactionRequest,
+};
+use crate::wallet::keys::ThresholdKey;
+use crate::wallet::network::CradleNetwork;
+use crate::wallet::signer::Signer;
+use async_trait::async_trait;
+use hedera::{
+    AccountId, AnyTransaction, Client, ContractExecuteTransaction, ContractId, Hbar, TransactionId,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use time::{Duration, OffsetDateTime};
+
+/// Longest validity the network accepts for a transaction.
+pub const MAX_VALID_DURATION: Duration = Duration::seconds(180);
+
+/// Validity and nodes of a frozen transaction. Signing on an air-gapped machine usually takes
+/// longer than the three minutes a transaction stays valid, so `valid_start` is best set to
+/// when the signed bytes will be submitted.
+#[derive(Clone, Debug)]
+pub struct FreezeOptions {
+    pub valid_start: Option<OffsetDateTime>,
+    pub valid_duration: Duration,
+    /// Nodes the transaction may be submitted to. Defaults to every node of the network, each
+    /// of which needs its own signature.
+    pub node_account_ids: Option<Vec<AccountId>>,
+    /// Signatures to collect before the transaction can be submitted, typically the threshold
+    /// key of the operator account.
+    pub required_signatures: Option<ThresholdKey>,
+}
+
+impl Default for FreezeOptions {
+    fn default() -> Self {
+        Self {
+            valid_start: None,
+            valid_duration: MAX_VALID_DURATION,
+            node_account_ids: None,
+            required_signatures: None,
+        }
+    }
+}
+
+/// A frozen contract call as written to disk between freezing, signing and submitting. The
+/// call and its encoded parameters are kept next to the bytes; `verify` checks they match the
+/// bytes and `decoded_call` shows the signer what the bytes actually call.
+///
+/// For threshold keys the file goes from one signer to the next, each adding a signature, until
+/// `required_signatures` is met.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OfflineTransaction {
+    /// Written as the network's name, or its node table for a custom network, so submitting
+    /// checks the very network it was frozen for.
+    pub network: CradleNetwork,
+    pub transaction_id: String,
+    pub contract_id: String,
+    pub function_name: String,
+    /// Encoded call data, selector included. Hex encoded.
+    pub parameters: String,
+    pub gas: u64,
+    /// Hbar sent with the call, in tinybars.
+    #[serde(default)]
+    pub payable_amount: i64,
+    /// The call the transaction was built for, decoded again by `submit`.
+    pub call: ContractCallInput,
+    /// Public keys that have signed so far, DER hex. Informational: it is read again from the
+    /// transaction bytes on `load` and `sign`, which are what count.
+    #[serde(default)]
+    pub signed_by: Vec<String>,
+    #[serde(default)]
+    pub required_signatures: Option<ThresholdKey>,
+    /// The frozen transaction. Hex encoded.
+    pub transaction: String,
+}
+
+impl OfflineTransaction {
+    /// Record of `request`, frozen into `bytes` as `transaction_id` for `call`.
+    pub fn new(
+        network: CradleNetwork,
+        call: ContractCallInput,
+        request: &ContractCallRequest,
+        transaction_id: TransactionId,
+        bytes: &[u8],
+    ) -> Self {
+        Self {
+            network,
+            transaction_id: transaction_id.to_string(),
+            contract_id: request.contract_id.map(|id| id.to_string()).unwrap_or_default(),
+            function_name: request.function_name.clone(),
+            parameters: hex::encode(&request.parameters),
+            gas: request.gas,
+            payable_amount: payable_tinybars(request),
+            call,
+            signed_by: Vec::new(),
+            required_signatures: None,
+            transaction: hex::encode(bytes),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> CradleResult<Self> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path).map_err(|e| {
+            CradleError::config(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+
+        let mut offline: Self = serde_json::from_str(&data).map_err(|e| {
+            CradleError::config(format!("Invalid offline transaction {}: {}", path.display(), e))
+        })?;
+        offline.signed_by = offline.signers()?;
+
+        Ok(offline)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> CradleResult<()> {
+        let path = path.as_ref();
+        let data = serde_json::to_string_pretty(self).map_err(|e| {
+            CradleError::decoding(format!("Failed to encode offline transaction: {}", e))
+        })?;
+
+        fs::write(path, data).map_err(|e| {
+            CradleError::config(format!("Failed to write {}: {}", path.display(), e))
+        })
+    }
+
+    pub fn transaction_id(&self) -> CradleResult<TransactionId> {
+        TransactionId::from_str(&self.transaction_id).map_err(|e| {
+            CradleError::config(format!("Invalid transaction id {}: {}", self.transaction_id, e))
+        })
+    }
+
+    /// The contract execute recorded in the file, for checking a call built again against it.
+    pub fn request(&self) -> CradleResult<TransactionRequest> {
+        let contract_id = ContractId::from_str(&self.contract_id).map_err(|e| {
+            CradleError::config(format!("Invalid contract id {}: {}", self.contract_id, e))
+        })?;
+
+        let mut call = ContractCallRequest::new();
+        call.contract_id(contract_id)
+            .gas(self.gas)
+            .payable_amount(Hbar::from_tinybars(self.payable_amount));
+        call.function_name = self.function_name.clone();
+        call.parameters = hex::decode(&self.parameters)
+            .map_err(|e| CradleError::config(format!("Invalid call data: {}", e)))?;
+
+        Ok(TransactionRequest::ContractExecute(call))
+    }
+
+    pub fn bytes(&self) -> CradleResult<Vec<u8>> {
+        hex::decode(&self.transaction)
+            .map_err(|e| CradleError::config(format!("Invalid transaction bytes: {}", e)))
+    }
+
+    pub fn to_transaction(&self) -> CradleResult<AnyTransaction> {
+        AnyTransaction::from_bytes(&self.bytes()?)
+            .map_err(|e| CradleError::config(format!("Invalid transaction bytes: {}", e)))
+    }
+
+    /// The function the call data invokes and its arguments by name, decoded from `parameters`
+    /// rather than taken from `call`, which nothing ties to the bytes before submission.
+    pub fn decoded_call(&self) -> CradleResult<(String, Value)> {
+        let data = hex::decode(&self.parameters)
+            .map_err(|e| CradleError::config(format!("Invalid call data: {}", e)))?;
+        let unknown = || CradleError::config("Call data doesn't call a known contract function");
+
+        let (signature, inputs) = match &self.call {
+            ContractCallInput::Dynamic(args) => abi::load_abi(&args.artifact)?
+                .into_iter()
+                .filter(|item| item["type"] == "function")
+                .find_map(|item| {
+                    let signature = abi::signature(&item).ok()?;
+                    data.starts_with(&abi::selector(&signature))
+                        .then(|| (signature, abi::params(&item, "inputs").to_vec()))
+                }),
+            _ => function_by_selector(&data)
+                .map(|(signature, inputs)| (signature.to_string(), inputs)),
+        }
+        .ok_or_else(unknown)?;
+
+        let values = abi::decode_params(&inputs, &data[4..])?;
+        Ok((signature, abi::named_values(&inputs, values)))
+    }
+
+    /// Checks that the bytes hold a contract execute of the recorded contract, call data, gas
+    /// and amount, and that the call data calls the recorded function, so what the file says
+    /// is what gets signed. Dynamic calls are encoded again and compared as well.
+    pub fn verify(&self) -> CradleResult<()> {
+        let mismatch = |field: &str| {
+            CradleError::config(format!("Transaction bytes don't match the recorded {}", field))
+        };
+
+        let transaction: ContractExecuteTransaction = self
+            .to_transaction()?
+            .downcast()
+            .map_err(|_| CradleError::config("Transaction is not a contract execute"))?;
+
+        if transaction.get_transaction_id().map(|id| id.to_string()).as_ref()
+            != Some(&self.transaction_id)
+        {
+            return Err(mismatch("transaction id"));
+        }
+        if transaction.get_contract_id().map(|id| id.to_string()).as_ref()
+            != Some(&self.contract_id)
+        {
+            return Err(mismatch("contract id"));
+        }
+        if hex::encode(transaction.get_function_parameters()) != self.parameters {
+            return Err(mismatch("parameters"));
+        }
+        if transaction.get_gas() != self.gas {
+            return Err(mismatch("gas"));
+        }
+        if transaction.get_payable_amount().to_tinybars() != self.payable_amount {
+            return Err(mismatch("payable amount"));
+        }
+
+        let (signature, _) = self.decoded_call()?;
+        if !signature.starts_with(&format!("{}(", self.function_name)) {
+            return Err(mismatch("function name"));
+        }
+        if let ContractCallInput::Dynamic(args) = &self.call {
+            let items = abi::load_abi(&args.artifact)?;
+            let item = find_function(&items, &signature, args.args.len())?;
+            if hex::encode(abi::encode_function_call(item, &args.args)?) != self.parameters {
+                return Err(mismatch("call"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Public keys, DER hex, whose signatures the transaction bytes carry for every node it
+    /// may be submitted to.
+    pub fn signers(&self) -> CradleResult<Vec<String>> {
+        let signatures = self
+            .to_transaction()?
+            .get_signatures()
+            .map_err(|e| CradleError::config(format!("Invalid transaction signatures: {}", e)))?;
+
+        let mut nodes = signatures.values();
+        let Some(first) = nodes.next() else {
+            return Ok(Vec::new());
+        };
+
+        Ok(first
+            .keys()
+            .filter(|key| nodes.clone().all(|node| node.contains_key(key)))
+            .map(|key| key.to_string_der())
+            .collect())
+    }
+
+    /// Signatures still needed from `required_signatures`, counted from the transaction bytes.
+    pub fn missing_signatures(&self) -> CradleResult<u32> {
+        let Some(required) = &self.required_signatures else {
+            return Ok(0);
+        };
+
+        Ok(required.missing(&self.signers()?))
+    }
+
+    /// Adds the signature of `signer`, after checking the file with `verify`. Signers outside
+    /// `required_signatures` are refused.
+    pub fn sign(&mut self, signer: Arc<dyn Signer>) -> CradleResult<()> {
+        self.verify()?;
+
+        let public_key = signer.public_key();
+        if let Some(required) = &self.required_signatures {
+            if !required.contains(&public_key.to_string_der()) {
+                return Err(CradleError::config(format!(
+                    "{} is not one of the required signers",
+                    public_key
+                )));
+            }
+        }
+        let failure = Arc::new(Mutex::new(None));
+        let mut transaction = self.to_transaction()?;
+
+        let signing_failure = failure.clone();
+        transaction.sign_with(public_key.clone(), move |message| {
+            signer.sign(message).unwrap_or_else(|e| {
+                signing_failure.lock().unwrap().get_or_insert(e);
+                Vec::new()
+            })
+        });
+
+        // Signatures are made as the transaction is serialized
+        let bytes = transaction
+            .to_bytes()
+            .map_err(|e| CradleError::config(format!("Failed to sign transaction: {}", e)))?;
+        if let Some(e) = failure.lock().unwrap().take() {
+            return Err(e);
+        }
+
+        self.transaction = hex::encode(bytes);
+        self.signed_by = self.signers()?;
+
+        Ok(())
+    }
+}
+
+fn payable_tinybars(call: &ContractCallRequest) -> i64 {
+    call.payable_amount.map(|amount| amount.to_tinybars()).unwrap_or(0)
+}
+
+/// Backend of a freezing wallet. The first contract execute is frozen instead of submitted and
+/// the call stops there; queries the wrapper makes before it go to the wrapped backend.
+#[derive(Debug)]
+pub struct FreezeBackend {
+    inner: Arc<dyn ContractBackend>,
+    client: Client,
+    operator: AccountId,
+    options: FreezeOptions,
+    frozen: Mutex<Option<(ContractCallRequest, TransactionId, Vec<u8>)>>,
+}
+
+impl FreezeBackend {
+    /// `client` only provides the nodes of the network; it needs no operator.
+    pub fn new(
+        inner: Arc<dyn ContractBackend>,
+        client: Client,
+        operator: AccountId,
+        options: FreezeOptions,
+    ) -> Self {
+        Self {
+            inner,
+            client,
+            operator,
+            options,
+            frozen: Mutex::default(),
+        }
+    }
+
+    /// The frozen request, its transaction id and bytes.
+    pub fn frozen(&self) -> Option<(ContractCallRequest, TransactionId, Vec<u8>)> {
+        self.frozen.lock().unwrap().clone()
+    }
+
+    fn freeze(&self, call: &ContractCallRequest) -> CradleResult<TransactionId> {
+        if call.contract_id.is_none() {
+            return Err(CradleError::config("Contract id is required"));
+        }
+
+        let valid_start = self.options.valid_start.unwrap_or_else(OffsetDateTime::now_utc);
+        let transaction_id = TransactionId {
+            account_id: self.operator,
+            valid_start,
+            nonce: None,
+            scheduled: false,
+        };
+
+        let mut transaction = call.to_execute_transaction();
+        transaction.transaction_id(transaction_id);
+        transaction.transaction_valid_duration(self.options.valid_duration);
+        if let Some(node_account_ids) = &self.options.node_account_ids {
+            transaction.node_account_ids(node_account_ids.clone());
+        }
+
+        let bytes = transaction
+            .freeze_with(&self.client)
+            .and_then(|transaction| transaction.to_bytes())
+            .map_err(|e| CradleError::config(format!("Failed to freeze transaction: {}", e)))?;
+
+        *self.frozen.lock().unwrap() = Some((call.clone(), transaction_id, bytes));
+        Ok(transaction_id)
+    }
+}
+
+#[async_trait]
+impl ContractBackend for FreezeBackend {
+    async fn execute(&self, request: &TransactionRequest) -> CradleResult<TransactionId> {
+        if self.frozen.lock().unwrap().is_some() {
+            return Err(CradleError::config("Only one transaction can be frozen per call"));
+        }
+
+        match request {
+            TransactionRequest::ContractExecute(call) => self.freeze(call),
+            other => Err(CradleError::config(format!(
+                "Only contract calls can be frozen, not {}",
+                other.description()
+            ))),
+        }
+    }
+
+    async fn query(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse> {
+        self.inner.query(request).await
+    }
+
+    async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt> {
+        Err(CradleError::config(format!("{} is frozen, not submitted", transaction_id)))
+    }
+
+    async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord> {
+        Err(CradleError::config(format!("{} is frozen, not submitted", transaction_id)))
+    }
+}
+
+/// Backend that submits a signed offline transaction in place of the contract execute a
+/// wrapper asks for, once it has checked the two are the same call. Receipts and records come
+/// from the wrapped backend, so the wrapper decodes the result as usual.
+#[derive(Debug)]
+pub struct SignedBackend {
+    inner: Arc<dyn ContractBackend>,
+    client: Client,
+    offline: OfflineTransaction,
+    submitted: Mutex<bool>,
+}
+
+impl SignedBackend {
+    pub fn new(
+        inner: Arc<dyn ContractBackend>,
+        client: Client,
+        offline: OfflineTransaction,
+    ) -> Self {
+        Self {
+            inner,
+            client,
+            offline,
+            submitted: Mutex::new(false),
+        }
+    }
+}
+
+#[async_trait]
+impl ContractBackend for SignedBackend {
+    async fn execute(&self, request: &TransactionRequest) -> CradleResult<TransactionId> {
+        let signed = self.offline.request()?;
+        let call = match request {
+            TransactionRequest::ContractExecute(call) if signed.matches(request) => call,
+            _ => {
+                return Err(CradleError::config(format!(
+                    "The call no longer builds the signed {} transaction",
+                    self.offline.function_name
+                )));
+            }
+        };
+
+        if *self.submitted.lock().unwrap() {
+            return Err(CradleError::config(format!(
+                "{} needs more than the one signed transaction",
+                call.function_name
+            )));
+        }
+
+        // Only marked once accepted, so a failed submission can be retried with the same bytes
+        let response = self.offline.to_transaction()?.execute(&self.client).await?;
+        *self.submitted.lock().unwrap() = true;
+
+        Ok(response.transaction_id)
+    }
+
+    async fn query(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse> {
+        self.inner.query(request).await
+    }
+
+    async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt> {
+        self.inner.get_receipt(transaction_id).await
+    }
+
+    async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord> {
+        self.inner.get_record(transaction_id).await
+    }
+}
+
+#[cfg(test)]
+mod offline_tests {
+    use super::*;
+    use crate::utils::functions::access_controller::{
+        AccessControllerFunctionsInput, grant_access,
+    };
+    use crate::utils::address::CradleAddress;
+    use crate::wallet::signer::InMemorySigner;
+    use crate::wallet::mock_backend::MockBackend;
+    use crate::wallet::network::CustomNetwork;
+    use crate::wallet::test_fixtures::{USER, contract_ids, mock_wallet, settle_order};
+    use crate::wallet::wallet::ActionWallet;
+    use anyhow::Result;
+    use hedera::PrivateKey;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    #[tokio::test]
+    async fn admin_calls_are_frozen_for_offline_signing() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+
+        let grant = ContractCallInput::AccessController(AccessControllerFunctionsInput::GrantAccess(
+            grant_access::Args {
+                level: 0,
+                account: USER.parse()?,
+            },
+        ));
+        let mut offline = wallet.freeze(grant, FreezeOptions::default()).await?;
+
+        assert!(backend.calls().is_empty());
+        assert_eq!(offline.function_name, "grantAccess");
+        assert_eq!(offline.contract_id, "0.0.1001");
+        assert!(offline.transaction_id.starts_with("0.0.2@"));
+
+        let key = PrivateKey::generate_ed25519();
+        offline.sign(Arc::new(InMemorySigner::new(key.clone())))?;
+        assert_eq!(offline.signed_by, [key.public_key().to_string_der()]);
+        offline.verify()?;
+
+        offline.gas += 1;
+        assert!(offline.verify().is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn a_tampered_call_is_shown_and_refused_as_signed() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+        let grant = |account: &str| -> Result<ContractCallInput> {
+            Ok(ContractCallInput::AccessController(
+                AccessControllerFunctionsInput::GrantAccess(grant_access::Args {
+                    level: 0,
+                    account: account.parse()?,
+                }),
+            ))
+        };
+        let mut offline = wallet.freeze(grant(USER)?, FreezeOptions::default()).await?;
+
+        offline.call = grant("0.0.4242")?;
+        offline.sign(Arc::new(InMemorySigner::new(PrivateKey::generate_ed25519())))?;
+
+        let (signature, arguments) = offline.decoded_call()?;
+        assert_eq!(signature, "grantAccess(uint64,address)");
+        assert_eq!(
+            arguments["account"],
+            json!(USER.parse::<CradleAddress>()?.to_evm_address())
+        );
+
+        let refused = wallet.submit_signed(&offline).await.unwrap_err();
+        assert!(refused.to_string().contains("no longer builds"));
+        assert!(backend.calls().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn custom_networks_only_submit_what_was_frozen_for_them() -> Result<()> {
+        let backend = Arc::new(MockBackend::new());
+        let wallet = |mirror_node_url: &str| {
+            let network = CradleNetwork::Custom(CustomNetwork {
+                nodes: BTreeMap::from([("127.0.0.1:50211".to_string(), "0.0.3".to_string())]),
+                mirror_node_url: mirror_node_url.to_string(),
+                mirror_network: Vec::new(),
+            });
+            ActionWallet::with_backend("0.0.2".to_string(), network, backend.clone())
+                .with_contract_ids(contract_ids())
+        };
+
+        let mut offline = wallet("http://10.0.0.1:5551")
+            .freeze(settle_order()?, FreezeOptions::default())
+            .await?;
+        offline.sign(Arc::new(InMemorySigner::new(PrivateKey::generate_ed25519())))?;
+
+        let refused = wallet("http://10.0.0.2:5551").submit_signed(&offline).await.unwrap_err();
+        assert!(refused.to_string().contains("frozen for custom (http://10.0.0.1:5551)"));
+        assert!(backend.calls().is_empty());
+
+        let saved: OfflineTransaction = serde_json::from_str(&serde_json::to_string(&offline)?)?;
+        assert_eq!(saved.network, offline.network);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn threshold_signed_calls_wait_for_enough_signers() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+        let keys: Vec<PrivateKey> = (0..3).map(|_| PrivateKey::generate_ed25519()).collect();
+        let public_keys: Vec<_> = keys.iter().map(PrivateKey::public_key).collect();
+
+        let options = FreezeOptions {
+            required_signatures: Some(ThresholdKey::new(2, &public_keys)?),
+            ..FreezeOptions::default()
+        };
+        let mut offline = wallet.freeze(settle_order()?, options).await?;
+
+        offline.sign(Arc::new(InMemorySigner::new(keys[0].clone())))?;
+        assert_eq!(offline.missing_signatures()?, 1);
+        assert!(wallet.submit_signed(&offline).await.is_err());
+
+        // Claiming a signature in the file doesn't add one to the bytes
+        offline.signed_by.push(public_keys[1].to_string_der());
+        assert_eq!(offline.missing_signatures()?, 1);
+
+        let outsider = InMemorySigner::new(PrivateKey::generate_ed25519());
+        assert!(offline.sign(Arc::new(outsider)).is_err());
+
+        offline.sign(Arc::new(InMemorySigner::new(keys[2].clone())))?;
+        assert_eq!(offline.missing_signatures()?, 0);
+        assert_eq!(offline.signed_by.len(), 2);
+        assert!(backend.calls().is_empty());
+
+        Ok(())
+    }
+}
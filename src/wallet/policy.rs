@@ -0,0 +1,175 @@
+use crate::utils::errors::CradleError;
+use hedera::Status;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Timeouts and retries of the calls an `ActionWallet` makes. Every wrapper goes through the
+/// wallet, so a policy applies to all of them, and a clone of the wallet with another policy
+/// changes it for a single call.
+///
+/// Transactions are only resubmitted when the node turned them away before they reached
+/// consensus (`BUSY`, `PLATFORM_TRANSACTION_NOT_CREATED`, `PLATFORM_NOT_ACTIVE`), so a retry
+/// never executes one twice. Queries, receipts and records don't change state and are also
+/// retried on network errors and timeouts.
+///
+/// In profiles durations are given in milliseconds:
+///
+/// ```toml
+/// [testnet.policy]
+/// execute_timeout = 20000
+/// max_attempts = 5
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct CallPolicy {
+    /// Time one submission may take, the hedera client's own node retries included.
+    #[serde(with = "millis")]
+    pub execute_timeout: Duration,
+    #[serde(with = "millis")]
+    pub query_timeout: Duration,
+    /// Time to wait for a receipt or record to reach consensus.
+    #[serde(with = "millis")]
+    pub receipt_timeout: Duration,
+    /// Attempts per call, the first included.
+    pub max_attempts: u32,
+    /// Backoff before the second attempt, doubling up to `max_backoff`.
+    #[serde(with = "millis")]
+    pub min_backoff: Duration,
+    #[serde(with = "millis")]
+    pub max_backoff: Duration,
+}
+
+impl Default for CallPolicy {
+    fn default() -> Self {
+        Self {
+            execute_timeout: Duration::from_secs(180),
+            query_timeout: Duration::from_secs(180),
+            receipt_timeout: Duration::from_secs(180),
+            max_attempts: 3,
+            min_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(8),
+        }
+    }
+}
+
+impl CallPolicy {
+    /// Short timeouts and a single retry, for latency sensitive services.
+    pub fn low_latency() -> Self {
+        Self {
+            execute_timeout: Duration::from_secs(15),
+            query_timeout: Duration::from_secs(10),
+            receipt_timeout: Duration::from_secs(30),
+            max_attempts: 2,
+            min_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+        }
+    }
+
+    /// Backoff before attempt `attempt + 1`.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.min_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+
+    /// Whether a transaction that failed with `error` can be submitted again.
+    pub fn retries_transaction(error: &CradleError) -> bool {
+        matches!(
+            error,
+            CradleError::Precheck {
+                status: Status::Busy
+                    | Status::PlatformTransactionNotCreated
+                    | Status::PlatformNotActive,
+                ..
+            }
+        )
+    }
+
+    /// Whether a query, receipt or record request that failed with `error` can be repeated.
+    pub fn retries_query(error: &CradleError) -> bool {
+        Self::retries_transaction(error)
+            || matches!(error, CradleError::Network(_) | CradleError::Timeout(_))
+    }
+}
+
+/// `Duration` as whole milliseconds.
+mod millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_millis() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Ok(Duration::from_millis(u64::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod policy_tests {
+    use super::*;
+    use crate::wallet::test_fixtures::{mock_wallet, settle_order};
+    use anyhow::Result;
+
+    #[test]
+    fn backoff_doubles_up_to_the_maximum() {
+        let policy = CallPolicy::default();
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(250));
+        assert_eq!(policy.backoff(3), Duration::from_secs(1));
+        assert_eq!(policy.backoff(40), Duration::from_secs(8));
+    }
+
+    #[test]
+    fn only_unsubmitted_transactions_are_retried() {
+        let busy = CradleError::Precheck {
+            status: Status::Busy,
+            transaction_id: None,
+        };
+        let failed = CradleError::Receipt {
+            status: Status::InsufficientGas,
+            transaction_id: None,
+        };
+        let timeout = CradleError::Timeout("receipt".to_string());
+
+        assert!(CallPolicy::retries_transaction(&busy));
+        assert!(!CallPolicy::retries_transaction(&failed));
+        assert!(!CallPolicy::retries_transaction(&timeout));
+        assert!(CallPolicy::retries_query(&timeout));
+    }
+
+    #[test]
+    fn reads_durations_in_milliseconds() {
+        let policy: CallPolicy =
+            toml::from_str("execute_timeout = 20000\nmax_attempts = 5").expect("valid policy");
+
+        assert_eq!(policy.execute_timeout, Duration::from_secs(20));
+        assert_eq!(policy.max_attempts, 5);
+        assert_eq!(policy.receipt_timeout, CallPolicy::default().receipt_timeout);
+    }
+
+    #[tokio::test]
+    async fn busy_transactions_are_resubmitted_within_the_policy() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+        let mut policy = CallPolicy::low_latency();
+        policy.min_backoff = std::time::Duration::from_millis(1);
+
+        backend.push_precheck(Status::Busy);
+        wallet.execute_with_policy(settle_order()?, policy.clone()).await?;
+        assert_eq!(backend.calls().len(), 2);
+
+        backend.push_precheck(Status::Busy).push_precheck(Status::Busy);
+        let err = wallet
+            .execute_with_policy(settle_order()?, policy)
+            .await
+            .expect_err("attempts should run out");
+        assert_eq!(err.status(), Some(Status::Busy));
+        assert_eq!(backend.calls().len(), 4);
+
+        backend.push_precheck(Status::InsufficientPayerBalance);
+        assert!(wallet.execute_with_policy(settle_order()?, CallPolicy::default()).await.is_err());
+        assert_eq!(backend.calls().len(), 5);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::wallet::contracts::CradleContractIds;
+use crate::wallet::gas::GasPolicy;
+use crate::wallet::policy::CallPolicy;
+use crate::wallet::network::CradleNetwork;
+use crate::wallet::signer::{
+    DEFAULT_KEYSTORE_PASSWORD_ENV, ExternalSigner, InMemorySigner, KeystoreSigner, Signer,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+pub const DEFAULT_PROFILES_FILE: &str = "cradle.toml";
+pub const DEFAULT_OPERATOR_KEY_ENV: &str = "OPERATOR_KEY";
+
+/// One deployment of the cradle contracts: which network it lives on, where its mirror node is
+/// and which ids the contracts got. Profiles live in a toml file, one table per profile:
+///
+/// ```toml
+/// [testnet]
+/// network = "testnet"
+/// mirror_node_url = "https://testnet.mirrornode.hedera.com"
+///
+/// [testnet.operator]
+/// account_id = "0.0.1234"
+/// keystore = "keys/testnet-operator.json"
+///
+/// [testnet.contracts]
+/// access_controller_contract_id = "0.0.7290648"
+/// # ...
+/// ```
+///
+/// `deployer/deployment_state.json`, as written by `deploy_all`, can be loaded as a profile too.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NetworkProfile {
+    pub network: CradleNetwork,
+    /// Defaults to the public mirror node of `network`.
+    #[serde(default)]
+    pub mirror_node_url: Option<String>,
+    #[serde(default)]
+    pub operator: Option<OperatorRef>,
+    pub contracts: CradleContractIds,
+    #[serde(default)]
+    pub gas: GasPolicy,
+    #[serde(default)]
+    pub policy: CallPolicy,
+}
+
+/// Which account operates the wallet and how it signs. Keys never go in a profile: the operator
+/// signs with an external signer when `signer_command` is set, else with `keystore` when set,
+/// else with the key in `key_env`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OperatorRef {
+    pub account_id: String,
+    /// Environment variable holding the operator key.
+    #[serde(default = "default_key_env")]
+    pub key_env: String,
+    /// Encrypted keystore file, see `Keystore`.
+    #[serde(default)]
+    pub keystore: Option<PathBuf>,
+    /// Environment variable holding the keystore password.
+    #[serde(default = "default_password_env")]
+    pub password_env: String,
+    /// Program and arguments of an external signer.
+    #[serde(default)]
+    pub signer_command: Option<Vec<String>>,
+    /// Public key of the external signer, DER hex.
+    #[serde(default)]
+    pub public_key: Option<String>,
+}
+
+fn default_key_env() -> String {
+    DEFAULT_OPERATOR_KEY_ENV.to_string()
+}
+
+fn default_password_env() -> String {
+    DEFAULT_KEYSTORE_PASSWORD_ENV.to_string()
+}
+
+impl OperatorRef {
+    pub fn signer(&self) -> CradleResult<Arc<dyn Signer>> {
+        if let Some(command) = &self.signer_command {
+            let public_key = self.public_key.as_deref().ok_or_else(|| {
+                CradleError::config("An external signer needs the operator public_key")
+            })?;
+            let public_key = public_key
+                .parse()
+                .map_err(|e| CradleError::config(format!("Invalid signer public key: {}", e)))?;
+
+            return Ok(Arc::new(ExternalSigner::new(command.clone(), public_key)?));
+        }
+
+        match &self.keystore {
+            Some(path) => Ok(Arc::new(KeystoreSigner::open_with_env(path, &self.password_env)?)),
+            None => Ok(Arc::new(InMemorySigner::from_env(&self.key_env)?)),
+        }
+    }
+}
+
+impl NetworkProfile {
+    /// Loads profile `name` from a toml profiles file, or from a `deploy_all` state file when
+    /// the path ends in `.json`, in which case `name` is the network it was deployed to.
+    pub fn load(path: impl AsRef<Path>, name: &str) -> CradleResult<Self> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path).map_err(|e| {
+            CradleError::config(format!("Failed to read profiles {}: {}", path.display(), e))
+        })?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_deployment_state(&data, name),
+            _ => Self::from_toml(&data, name),
+        }
+    }
+
+    pub fn from_toml(data: &str, name: &str) -> CradleResult<Self> {
+        let mut profiles: HashMap<String, NetworkProfile> = toml::from_str(data)
+            .map_err(|e| CradleError::config(format!("Invalid profiles: {}", e)))?;
+
+        profiles
+            .remove(name)
+            .ok_or_else(|| CradleError::config(format!("No profile named {}", name)))
+    }
+
+    /// Takes the ids of completed deployments, keyed by their `env_var`.
+    pub fn from_deployment_state(data: &str, network: &str) -> CradleResult<Self> {
+        let state: Value = serde_json::from_str(data)
+            .map_err(|e| CradleError::config(format!("Invalid deployment state: {}", e)))?;
+
+        let deployed: HashMap<&str, &str> = state["deployments"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|deployment| deployment["status"] == "completed")
+            .filter_map(|deployment| {
+                Some((deployment["env_var"].as_str()?, deployment["contract_id"].as_str()?))
+            })
+            .collect();
+
+        Ok(Self {
+            network: network.parse()?,
+            mirror_node_url: None,
+            operator: None,
+            gas: GasPolicy::default(),
+            policy: CallPolicy::default(),
+            contracts: CradleContractIds::from_lookup(|name| {
+                deployed.get(name).map(|id| id.to_string())
+            })?,
+        })
+    }
+
+    /// Operator account id and signer, from the profile's operator reference or the
+    /// `OPERATOR_ACCOUNT_ID` and `OPERATOR_KEY` variables.
+    pub fn operator_signer(&self) -> CradleResult<(String, Arc<dyn Signer>)> {
+        match &self.operator {
+            Some(operator) => Ok((operator.account_id.clone(), operator.signer()?)),
+            None => {
+                let account_id = env::var("OPERATOR_ACCOUNT_ID")
+                    .map_err(|_| CradleError::config("OPERATOR_ACCOUNT_ID is not set"))?;
+
+                Ok((account_id, Arc::new(InMemorySigner::from_env(DEFAULT_OPERATOR_KEY_ENV)?)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod profile_tests {
+    use super::*;
+    use hedera::ContractId;
+    use serde_json::json;
+
+    const CONTRACTS: &str = r#"
+access_controller_contract_id = "0.0.1001"
+bridged_asset_issuer_contract_id = "0.0.1002"
+native_asset_issuer_contract_id = "0.0.1003"
+cradle_account_factory_contract_id = "0.0.1004"
+asset_factory = "0.0.1005"
+cradle_order_book_settler_contract_id = "0.0.1006"
+asset_lending_pool_factory = "0.0.1007"
+cradle_listing_factory_contract_id = "0.0.1008"
+"#;
+
+    #[test]
+    fn loads_named_profile_from_toml() -> CradleResult<()> {
+        let data = format!(
+            "[local]\nnetwork = \"testnet\"\nmirror_node_url = \"http://localhost:5551\"\n\n\
+             [local.operator]\naccount_id = \"0.0.2\"\n\n[local.contracts]{}",
+            CONTRACTS
+        );
+
+        let profile = NetworkProfile::from_toml(&data, "local")?;
+
+        assert_eq!(profile.mirror_node_url.as_deref(), Some("http://localhost:5551"));
+        assert_eq!(profile.operator.as_ref().map(|o| o.key_env.as_str()), Some("OPERATOR_KEY"));
+        assert_eq!(profile.contracts.asset_factory, ContractId::new(0, 0, 1005));
+        assert!(NetworkProfile::from_toml(&data, "mainnet").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn loads_completed_deployments_from_state() -> CradleResult<()> {
+        let contracts: CradleContractIds = toml::from_str(CONTRACTS).expect("valid contracts");
+        let deployments: Vec<Value> = contracts
+            .entries()
+            .iter()
+            .map(|(env_var, id)| {
+                json!({ "env_var": env_var, "status": "completed", "contract_id": id.to_string() })
+            })
+            .collect();
+
+        let state = json!({ "deployments": deployments }).to_string();
+        let profile = NetworkProfile::from_deployment_state(&state, "testnet")?;
+
+        assert_eq!(profile.network, CradleNetwork::Testnet);
+        assert_eq!(profile.contracts.access_controller_contract_id, ContractId::new(0, 0, 1001));
+
+        let pending = state.replace("\"completed\"", "\"pending\"");
+        assert!(NetworkProfile::from_deployment_state(&pending, "testnet").is_err());
+
+        Ok(())
+    }
+}
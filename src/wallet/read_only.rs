@@ -0,0 +1,54 @@
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::wallet::backend::{
+    CallReceipt, CallRecord, ContractBackend, ContractCallRequest, QueryResponse,
+    TransactionRequest,
+};
+use async_trait::async_trait;
+use hedera::TransactionId;
+use std::sync::{Arc, Mutex};
+
+/// Backend of a read-only wallet. Queries go through, transactions are refused before they
+/// leave the process, so only calls that don't change state succeed. Wrappers may already have
+/// queried by then, so callers that have the `ContractCallInput` refuse it up front with
+/// `is_query` and keep this as a backstop.
+#[derive(Debug)]
+pub struct ReadOnlyBackend {
+    inner: Arc<dyn ContractBackend>,
+    refused: Mutex<Option<String>>,
+}
+
+impl ReadOnlyBackend {
+    pub fn new(inner: Arc<dyn ContractBackend>) -> Self {
+        Self {
+            inner,
+            refused: Mutex::default(),
+        }
+    }
+
+    /// Description of the first transaction refused, if any.
+    pub fn refused(&self) -> Option<String> {
+        self.refused.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl ContractBackend for ReadOnlyBackend {
+    async fn execute(&self, request: &TransactionRequest) -> CradleResult<TransactionId> {
+        let description = request.description();
+        self.refused.lock().unwrap().get_or_insert(description.clone());
+
+        Err(CradleError::config(format!("{} changes state, which is not allowed", description)))
+    }
+
+    async fn query(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse> {
+        self.inner.query(request).await
+    }
+
+    async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt> {
+        self.inner.get_receipt(transaction_id).await
+    }
+
+    async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord> {
+        self.inner.get_record(transaction_id).await
+    }
+}
@@ -0,0 +1,215 @@
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::utils::functions::ContractCallInput;
+use crate::wallet::backend::{
+    CallReceipt, CallRecord, ContractBackend, ContractCallRequest, QueryResponse,
+    TransactionRequest,
+};
+use crate::wallet::keys::ThresholdKey;
+use async_trait::async_trait;
+use hedera::{AccountId, ScheduleId, TransactionId};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use time::OffsetDateTime;
+
+/// How a call is scheduled. By default the schedule runs as soon as enough keys have signed it
+/// and lapses after the network's maximum lifetime.
+#[derive(Clone, Debug, Default)]
+pub struct ScheduleOptions {
+    pub memo: Option<String>,
+    pub expiration_time: Option<OffsetDateTime>,
+    /// Run at `expiration_time` rather than as soon as the signatures are complete, for time
+    /// delayed admin changes.
+    pub wait_for_expiry: bool,
+    /// Key that can delete the schedule. Without one it can't be deleted.
+    pub admin_key: Option<ThresholdKey>,
+    /// Account paying for the scheduled transaction, the operator by default.
+    pub payer_account_id: Option<AccountId>,
+}
+
+/// A scheduled contract call waiting for signatures or its expiry. Serializable so it can be
+/// handed to the other signers.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PendingSchedule {
+    pub schedule_id: String,
+    /// Id the call runs under once executed.
+    pub scheduled_transaction_id: String,
+    pub contract_id: String,
+    pub function_name: String,
+    /// Encoded call data, selector included. Hex encoded.
+    pub parameters: String,
+    /// The scheduled call, decoded again by `ActionWallet::schedule_result`.
+    pub call: ContractCallInput,
+}
+
+impl PendingSchedule {
+    pub fn schedule_id(&self) -> CradleResult<ScheduleId> {
+        ScheduleId::from_str(&self.schedule_id).map_err(|e| {
+            CradleError::config(format!("Invalid schedule id {}: {}", self.schedule_id, e))
+        })
+    }
+
+    pub fn scheduled_transaction_id(&self) -> CradleResult<TransactionId> {
+        TransactionId::from_str(&self.scheduled_transaction_id).map_err(|e| {
+            CradleError::config(format!(
+                "Invalid transaction id {}: {}",
+                self.scheduled_transaction_id, e
+            ))
+        })
+    }
+
+    fn matches(&self, call: &ContractCallRequest) -> bool {
+        call.contract_id.map(|id| id.to_string()).as_ref() == Some(&self.contract_id)
+            && hex::encode(&call.parameters) == self.parameters
+    }
+}
+
+/// Backend of a scheduling wallet. The first contract execute is submitted wrapped in a
+/// `ScheduleCreateTransaction` and the call stops there; queries before it run as usual.
+#[derive(Debug)]
+pub struct ScheduleBackend {
+    inner: Arc<dyn ContractBackend>,
+    options: ScheduleOptions,
+    created: Mutex<Option<(ContractCallRequest, TransactionId)>>,
+}
+
+impl ScheduleBackend {
+    pub fn new(inner: Arc<dyn ContractBackend>, options: ScheduleOptions) -> Self {
+        Self {
+            inner,
+            options,
+            created: Mutex::default(),
+        }
+    }
+
+    /// The scheduled request and the id of the transaction that created the schedule.
+    pub fn created(&self) -> Option<(ContractCallRequest, TransactionId)> {
+        self.created.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl ContractBackend for ScheduleBackend {
+    async fn execute(&self, request: &TransactionRequest) -> CradleResult<TransactionId> {
+        if self.created.lock().unwrap().is_some() {
+            return Err(CradleError::config("Only one transaction can be scheduled per call"));
+        }
+
+        let TransactionRequest::ContractExecute(call) = request else {
+            return Err(CradleError::config(format!(
+                "Only contract calls can be scheduled, not {}",
+                request.description()
+            )));
+        };
+
+        let schedule = TransactionRequest::ScheduleCreate {
+            call: call.clone(),
+            options: self.options.clone(),
+        };
+        let transaction_id = self.inner.execute(&schedule).await?;
+
+        *self.created.lock().unwrap() = Some((call.clone(), transaction_id));
+        Ok(transaction_id)
+    }
+
+    async fn query(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse> {
+        self.inner.query(request).await
+    }
+
+    async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt> {
+        Err(CradleError::config(format!("{} is scheduled, not executed", transaction_id)))
+    }
+
+    async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord> {
+        Err(CradleError::config(format!("{} is scheduled, not executed", transaction_id)))
+    }
+}
+
+/// Backend that answers the contract execute of a wrapper with the id of an executed schedule,
+/// so the wrapper decodes the scheduled transaction's record into its usual output.
+#[derive(Debug)]
+pub struct ScheduledResultBackend {
+    inner: Arc<dyn ContractBackend>,
+    schedule: PendingSchedule,
+}
+
+impl ScheduledResultBackend {
+    pub fn new(inner: Arc<dyn ContractBackend>, schedule: PendingSchedule) -> Self {
+        Self { inner, schedule }
+    }
+}
+
+#[async_trait]
+impl ContractBackend for ScheduledResultBackend {
+    async fn execute(&self, request: &TransactionRequest) -> CradleResult<TransactionId> {
+        match request {
+            TransactionRequest::ContractExecute(call) if self.schedule.matches(call) => {
+                self.schedule.scheduled_transaction_id()
+            }
+            _ => Err(CradleError::config(format!(
+                "The call no longer builds the {} scheduled as {}",
+                self.schedule.function_name, self.schedule.schedule_id
+            ))),
+        }
+    }
+
+    async fn query(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse> {
+        self.inner.query(request).await
+    }
+
+    async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt> {
+        self.inner.get_receipt(transaction_id).await
+    }
+
+    async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord> {
+        self.inner.get_record(transaction_id).await
+    }
+}
+
+#[cfg(test)]
+mod schedule_tests {
+    use super::*;
+    use crate::utils::functions::access_controller::{
+        AccessControllerFunctionsInput, AccessControllerFunctionsOutput, clear_level,
+    };
+    use crate::utils::functions::ContractCallOutput;
+    use crate::wallet::mock_backend::RecordedCall;
+    use crate::wallet::test_fixtures::mock_wallet;
+    use anyhow::Result;
+
+    #[tokio::test]
+    async fn scheduled_admin_calls_decode_their_execution() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+        let clear_level = ContractCallInput::AccessController(
+            AccessControllerFunctionsInput::ClearLevel(clear_level::Args { level: 3 }),
+        );
+
+        let schedule = wallet.schedule(clear_level, ScheduleOptions::default()).await?;
+
+        match backend.last_call().expect("no call recorded") {
+            RecordedCall::Execute(TransactionRequest::ScheduleCreate { call, .. }) => {
+                assert_eq!(call.function_name, "clearLevel");
+            }
+            other => panic!("expected a schedule create, got {:?}", other),
+        }
+        assert_eq!(schedule.function_name, "clearLevel");
+        assert!(schedule.scheduled_transaction_id.ends_with("?scheduled"));
+
+        wallet.sign_schedule(schedule.schedule_id()?).await?;
+        assert!(matches!(
+            backend.last_call(),
+            Some(RecordedCall::Execute(TransactionRequest::ScheduleSign { .. }))
+        ));
+
+        match wallet.decode_schedule(&schedule).await? {
+            ContractCallOutput::AccessController(
+                AccessControllerFunctionsOutput::ClearLevel(output),
+            ) => {
+                assert_eq!(output.transaction_id, schedule.scheduled_transaction_id);
+            }
+            other => panic!("unexpected output {:?}", other),
+        }
+
+        Ok(())
+    }
+}
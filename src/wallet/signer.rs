@@ -0,0 +1,410 @@
+use crate::utils::errors::{CradleError, CradleResult};
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use clap::Parser;
+use hedera::{PrivateKey, PublicKey};
+use rand::RngCore;
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::env;
+use std::fmt::{Debug, Formatter};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use std::sync::Arc;
+use zeroize::Zeroizing;
+
+/// Signs transactions for the operator account. Implementations keep their key material to
+/// themselves: nothing but the public key leaves them, and their `Debug` output never shows
+/// the key.
+pub trait Signer: Send + Sync + Debug {
+    fn public_key(&self) -> PublicKey;
+
+    /// Signature over the body bytes of a transaction.
+    fn sign(&self, message: &[u8]) -> CradleResult<Vec<u8>>;
+}
+
+/// A key held in memory for the life of the process.
+pub struct InMemorySigner {
+    key: PrivateKey,
+}
+
+impl InMemorySigner {
+    pub fn new(key: PrivateKey) -> Self {
+        Self { key }
+    }
+
+    /// Reads the key from `var`. The variable's value is wiped from memory once parsed.
+    pub fn from_env(var: &str) -> CradleResult<Self> {
+        let value = Zeroizing::new(
+            env::var(var).map_err(|_| CradleError::config(format!("{} is not set", var)))?,
+        );
+
+        value.parse()
+    }
+}
+
+impl FromStr for InMemorySigner {
+    type Err = CradleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The parse error can quote the input, so it is left out of the message.
+        let key = PrivateKey::from_str(s.trim())
+            .map_err(|_| CradleError::config("Invalid operator key"))?;
+
+        Ok(Self::new(key))
+    }
+}
+
+impl Signer for InMemorySigner {
+    fn public_key(&self) -> PublicKey {
+        self.key.public_key()
+    }
+
+    fn sign(&self, message: &[u8]) -> CradleResult<Vec<u8>> {
+        Ok(self.key.sign(message))
+    }
+}
+
+impl Debug for InMemorySigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InMemorySigner")
+            .field("public_key", &self.public_key().to_string())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Environment variable holding the keystore password or key encryption key.
+pub const DEFAULT_KEYSTORE_PASSWORD_ENV: &str = "CRADLE_KEYSTORE_PASSWORD";
+
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// A private key encrypted at rest with AES-256-GCM, under a key derived from a password with
+/// scrypt. The password can be typed in or come from an environment variable, e.g. one filled
+/// from a secrets manager.
+///
+/// The file is json and carries the public key in the clear, so it can be checked without the
+/// password.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Keystore {
+    pub version: u32,
+    pub public_key: String,
+    pub kdf: KdfParams,
+    /// Hex encoded.
+    pub nonce: String,
+    /// The DER encoded private key, encrypted. Hex encoded.
+    pub ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KdfParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+    /// Hex encoded.
+    pub salt: String,
+}
+
+impl Keystore {
+    pub fn encrypt(key: &PrivateKey, password: &str) -> CradleResult<Self> {
+        let mut salt = [0u8; 32];
+        let mut nonce = [0u8; 12];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+
+        let kdf = KdfParams {
+            log_n: SCRYPT_LOG_N,
+            r: SCRYPT_R,
+            p: SCRYPT_P,
+            salt: hex::encode(salt),
+        };
+        let cipher = cipher(password, &kdf)?;
+        let plaintext = Zeroizing::new(key.to_bytes_der());
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| CradleError::config("Failed to encrypt key"))?;
+
+        Ok(Self {
+            version: 1,
+            public_key: key.public_key().to_string(),
+            kdf,
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    pub fn decrypt(&self, password: &str) -> CradleResult<PrivateKey> {
+        let invalid = |field: &str| CradleError::config(format!("Invalid keystore {}", field));
+
+        let cipher = cipher(password, &self.kdf)?;
+        let nonce = hex::decode(&self.nonce).map_err(|_| invalid("nonce"))?;
+        let ciphertext = hex::decode(&self.ciphertext).map_err(|_| invalid("ciphertext"))?;
+        if nonce.len() != 12 {
+            return Err(invalid("nonce"));
+        }
+
+        let plaintext = Zeroizing::new(
+            cipher
+                .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+                .map_err(|_| CradleError::config("Wrong keystore password"))?,
+        );
+
+        let key = PrivateKey::from_bytes_der(&plaintext).map_err(|_| invalid("key"))?;
+        if key.public_key().to_string() != self.public_key {
+            return Err(invalid("public key"));
+        }
+
+        Ok(key)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> CradleResult<Self> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path).map_err(|e| {
+            CradleError::config(format!("Failed to read keystore {}: {}", path.display(), e))
+        })?;
+
+        serde_json::from_str(&data)
+            .map_err(|e| CradleError::config(format!("Invalid keystore {}: {}", path.display(), e)))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> CradleResult<()> {
+        let path = path.as_ref();
+        let data = serde_json::to_string_pretty(self)
+            .map_err(|e| CradleError::decoding(format!("Failed to encode keystore: {}", e)))?;
+
+        fs::write(path, data).map_err(|e| {
+            CradleError::config(format!("Failed to write keystore {}: {}", path.display(), e))
+        })
+    }
+}
+
+fn cipher(password: &str, kdf: &KdfParams) -> CradleResult<Aes256Gcm> {
+    let salt = hex::decode(&kdf.salt).map_err(|_| CradleError::config("Invalid keystore salt"))?;
+    let params = scrypt::Params::new(kdf.log_n, kdf.r, kdf.p, 32)
+        .map_err(|e| CradleError::config(format!("Invalid keystore kdf parameters: {}", e)))?;
+
+    let mut kek = Zeroizing::new([0u8; 32]);
+    scrypt::scrypt(password.as_bytes(), &salt, &params, kek.as_mut_slice())
+        .map_err(|e| CradleError::config(format!("Failed to derive keystore key: {}", e)))?;
+
+    Aes256Gcm::new_from_slice(kek.as_slice())
+        .map_err(|_| CradleError::config("Invalid keystore key length"))
+}
+
+/// Signs with a key decrypted from a `Keystore` file.
+pub struct KeystoreSigner {
+    path: PathBuf,
+    inner: InMemorySigner,
+}
+
+impl KeystoreSigner {
+    pub fn open(path: impl Into<PathBuf>, password: &str) -> CradleResult<Self> {
+        let path = path.into();
+        let key = Keystore::load(&path)?.decrypt(password)?;
+
+        Ok(Self {
+            path,
+            inner: InMemorySigner::new(key),
+        })
+    }
+
+    /// Opens the keystore with the password held in `var`.
+    pub fn open_with_env(path: impl Into<PathBuf>, var: &str) -> CradleResult<Self> {
+        let password = Zeroizing::new(
+            env::var(var).map_err(|_| CradleError::config(format!("{} is not set", var)))?,
+        );
+
+        Self::open(path, &password)
+    }
+}
+
+impl Signer for KeystoreSigner {
+    fn public_key(&self) -> PublicKey {
+        self.inner.public_key()
+    }
+
+    fn sign(&self, message: &[u8]) -> CradleResult<Vec<u8>> {
+        self.inner.sign(message)
+    }
+}
+
+impl Debug for KeystoreSigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeystoreSigner")
+            .field("path", &self.path)
+            .field("public_key", &self.public_key().to_string())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Delegates signing to another program, e.g. a bridge to an HSM or a remote signing service.
+/// The program is started for every signature, gets one json line on stdin,
+///
+/// `{"public_key": "<der hex>", "message": "<hex>"}`
+///
+/// and answers on stdout with `{"signature": "<hex>"}`. A non-zero exit status is a refusal.
+#[derive(Clone, Debug)]
+pub struct ExternalSigner {
+    command: Vec<String>,
+    public_key: PublicKey,
+}
+
+#[derive(Deserialize)]
+struct ExternalSignature {
+    signature: String,
+}
+
+impl ExternalSigner {
+    pub fn new(command: Vec<String>, public_key: PublicKey) -> CradleResult<Self> {
+        if command.is_empty() {
+            return Err(CradleError::config("External signer command is empty"));
+        }
+
+        Ok(Self {
+            command,
+            public_key,
+        })
+    }
+
+    /// Signer for a command line such as `"hsm-bridge --slot 2"`, split on whitespace, and a
+    /// DER hex public key.
+    pub fn from_command_line(command: &str, public_key: &str) -> CradleResult<Self> {
+        let public_key = PublicKey::from_str(public_key)
+            .map_err(|e| CradleError::config(format!("Invalid signer public key: {}", e)))?;
+
+        Self::new(command.split_whitespace().map(str::to_string).collect(), public_key)
+    }
+}
+
+impl Signer for ExternalSigner {
+    fn public_key(&self) -> PublicKey {
+        self.public_key.clone()
+    }
+
+    fn sign(&self, message: &[u8]) -> CradleResult<Vec<u8>> {
+        let failed =
+            |reason: String| CradleError::config(format!("External signer failed: {}", reason));
+
+        let mut child = Command::new(&self.command[0])
+            .args(&self.command[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| failed(format!("could not start {}: {}", self.command[0], e)))?;
+
+        let request = json!({
+            "public_key": self.public_key.to_string_der(),
+            "message": hex::encode(message),
+        });
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "{}", request).map_err(|e| failed(e.to_string()))?;
+        }
+
+        let output = child.wait_with_output().map_err(|e| failed(e.to_string()))?;
+        if !output.status.success() {
+            return Err(failed(format!(
+                "{}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        let response: ExternalSignature = serde_json::from_slice(&output.stdout)
+            .map_err(|e| failed(format!("unreadable response: {}", e)))?;
+
+        hex::decode(response.signature.trim_start_matches("0x"))
+            .map_err(|e| failed(format!("invalid signature: {}", e)))
+    }
+}
+
+/// Command line and environment choice of signer: in order of precedence an external signer,
+/// an encrypted keystore or a plain key.
+#[derive(Parser, Clone)]
+pub struct SignerArgs {
+    #[clap(long, env, hide_env_values = true)]
+    operator_key: Option<String>,
+    #[clap(long, env = "CRADLE_KEYSTORE")]
+    keystore: Option<PathBuf>,
+    /// Environment variable holding the keystore password.
+    #[clap(long, env = "CRADLE_KEYSTORE_PASSWORD_ENV")]
+    #[clap(default_value = DEFAULT_KEYSTORE_PASSWORD_ENV)]
+    keystore_password_env: String,
+    /// Program and arguments of an external signer, separated by spaces.
+    #[clap(long, env = "CRADLE_SIGNER_COMMAND")]
+    signer_command: Option<String>,
+    /// Public key of the external signer, DER hex.
+    #[clap(long, env = "CRADLE_SIGNER_PUBLIC_KEY")]
+    signer_public_key: Option<String>,
+}
+
+impl Debug for SignerArgs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SignerArgs")
+            .field("keystore", &self.keystore)
+            .field("keystore_password_env", &self.keystore_password_env)
+            .field("signer_command", &self.signer_command)
+            .field("signer_public_key", &self.signer_public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SignerArgs {
+    pub fn signer(self) -> CradleResult<Arc<dyn Signer>> {
+        if let Some(command) = &self.signer_command {
+            let public_key = self.signer_public_key.as_deref().ok_or_else(|| {
+                CradleError::config("An external signer needs CRADLE_SIGNER_PUBLIC_KEY")
+            })?;
+            return Ok(Arc::new(ExternalSigner::from_command_line(command, public_key)?));
+        }
+
+        if let Some(path) = self.keystore {
+            let signer = KeystoreSigner::open_with_env(path, &self.keystore_password_env)?;
+            return Ok(Arc::new(signer));
+        }
+
+        let key = Zeroizing::new(self.operator_key.ok_or_else(|| {
+            CradleError::config("Set OPERATOR_KEY, CRADLE_KEYSTORE or CRADLE_SIGNER_COMMAND")
+        })?);
+
+        Ok(Arc::new(key.parse::<InMemorySigner>()?))
+    }
+}
+
+#[cfg(test)]
+mod signer_tests {
+    use super::*;
+
+    #[test]
+    fn keystores_round_trip_and_reject_wrong_passwords() -> CradleResult<()> {
+        let key = PrivateKey::generate_ed25519();
+        let keystore = Keystore::encrypt(&key, "correct horse")?;
+
+        assert_eq!(keystore.decrypt("correct horse")?.to_bytes_der(), key.to_bytes_der());
+        assert!(keystore.decrypt("wrong horse").is_err());
+        assert!(!keystore.ciphertext.contains(&hex::encode(key.to_bytes_raw())));
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_output_never_shows_the_key() -> CradleResult<()> {
+        let key = PrivateKey::generate_ed25519();
+        let signer = InMemorySigner::new(key.clone());
+
+        let debug = format!("{:?}", signer);
+        assert!(!debug.contains(&key.to_string_der()));
+        assert!(!debug.contains(&hex::encode(key.to_bytes_raw())));
+        assert!(debug.contains(&key.public_key().to_string()));
+
+        let signature = signer.sign(b"body")?;
+        assert!(key.public_key().verify(b"body", &signature).is_ok());
+
+        Ok(())
+    }
+}
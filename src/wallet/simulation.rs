@@ -0,0 +1,318 @@
+use crate::utils::errors::{ContractRevert, CradleError, CradleResult, RevertReason};
+use crate::utils::functions::ContractCallOutput;
+use crate::utils::mirror::{MirrorCallRequest, MirrorClient};
+use crate::wallet::backend::{
+    CallReceipt, CallRecord, ContractBackend, ContractCallRequest, QueryResponse,
+    TransactionRequest, function_result,
+};
+use async_trait::async_trait;
+use hedera::{AccountId, ContractFunctionResult, ContractId, Hbar, Status, TransactionId};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use time::OffsetDateTime;
+
+/// Where a dry run executes state changing calls.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimulationMode {
+    /// As a `ContractCallQuery` on a consensus node. Costs a query fee and reports gas used.
+    /// Queries carry no hbar, so payable functions that check `msg.value` revert.
+    Query,
+    /// Through the mirror node `contracts/call` endpoint. Free, and reports the mirror node's
+    /// gas estimate.
+    MirrorNode,
+}
+
+/// One state changing call of a dry run.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SimulatedCall {
+    pub contract_id: Option<String>,
+    pub function_name: String,
+    /// False for requests that can't be simulated, such as token associations and schedules,
+    /// which a dry run assumes to succeed.
+    pub simulated: bool,
+    /// Encoded return value, `0x` prefixed.
+    pub return_data: Option<String>,
+    /// Gas used by the query, or the mirror node's estimate.
+    pub gas: Option<u64>,
+    pub gas_limit: u64,
+    pub revert: Option<RevertReason>,
+}
+
+/// Outcome of `ActionWallet::simulate`: the output the call would have produced, or the error it
+/// would have failed with, and every state changing call it made on the way.
+#[derive(Debug)]
+pub struct SimulationReport {
+    pub output: CradleResult<ContractCallOutput>,
+    pub calls: Vec<SimulatedCall>,
+}
+
+impl SimulationReport {
+    pub fn would_succeed(&self) -> bool {
+        self.output.is_ok()
+    }
+
+    /// Reason of the first call that would revert.
+    pub fn revert(&self) -> Option<&RevertReason> {
+        self.calls.iter().find_map(|call| call.revert.as_ref())
+    }
+
+    /// Gas of all simulated calls together.
+    pub fn total_gas(&self) -> u64 {
+        self.calls.iter().filter_map(|call| call.gas).sum()
+    }
+}
+
+#[derive(Debug, Default)]
+struct SimulationState {
+    calls: Vec<SimulatedCall>,
+    results: HashMap<TransactionId, Result<ContractFunctionResult, ContractRevert>>,
+}
+
+/// Backend of a dry run wallet. Runs executes as calls that change nothing and hands their
+/// results back through `get_receipt` and `get_record`, so every wrapper decodes its would-be
+/// output unchanged. Queries go to the wrapped backend as usual.
+///
+/// Each call sees the current state only: a dry run of several dependent writes simulates the
+/// later ones without the effects of the earlier ones.
+#[derive(Debug)]
+pub struct SimulationBackend {
+    inner: Arc<dyn ContractBackend>,
+    mode: SimulationMode,
+    mirror: MirrorClient,
+    operator: AccountId,
+    state: Mutex<SimulationState>,
+}
+
+impl SimulationBackend {
+    pub fn new(
+        inner: Arc<dyn ContractBackend>,
+        mode: SimulationMode,
+        mirror: MirrorClient,
+        operator: AccountId,
+    ) -> Self {
+        Self {
+            inner,
+            mode,
+            mirror,
+            operator,
+            state: Mutex::default(),
+        }
+    }
+
+    pub fn calls(&self) -> Vec<SimulatedCall> {
+        self.state.lock().unwrap().calls.clone()
+    }
+
+    async fn simulate(
+        &self,
+        call: &ContractCallRequest,
+    ) -> CradleResult<(Result<ContractFunctionResult, ContractRevert>, Option<u64>)> {
+        let contract_id = call
+            .contract_id
+            .ok_or_else(|| CradleError::config("Contract id is required"))?;
+
+        let outcome = match self.mode {
+            SimulationMode::Query => self.inner.query(call).await.map(|response| {
+                let gas = response.result.gas_used;
+                (response.result, Some(gas))
+            }),
+            SimulationMode::MirrorNode => self.mirror_call(contract_id, call).await,
+        };
+
+        match outcome {
+            Ok((result, gas)) => Ok((Ok(result), gas)),
+            Err(CradleError::Revert(revert)) => Ok((Err(revert), None)),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn mirror_call(
+        &self,
+        contract_id: ContractId,
+        call: &ContractCallRequest,
+    ) -> CradleResult<(ContractFunctionResult, Option<u64>)> {
+        let request = MirrorCallRequest::for_call(call, self.operator)?;
+
+        let bytes = self.mirror.call(&request).await?;
+
+        let gas = self.mirror.estimate_gas(&request).await.ok();
+
+        Ok((function_result(contract_id, bytes), gas))
+    }
+
+    fn stored(&self, transaction_id: &TransactionId) -> CradleResult<ContractFunctionResult> {
+        let state = self.state.lock().unwrap();
+        let stored = state.results.get(transaction_id).ok_or_else(|| {
+            CradleError::config(format!("{} was not part of this dry run", transaction_id))
+        })?;
+
+        stored.clone().map_err(CradleError::Revert)
+    }
+}
+
+#[async_trait]
+impl ContractBackend for SimulationBackend {
+    async fn execute(&self, request: &TransactionRequest) -> CradleResult<TransactionId> {
+        let transaction_id = TransactionId::generate(self.operator);
+
+        let (simulated, result) = match request {
+            TransactionRequest::ContractExecute(call) => {
+                let (result, gas) = self.simulate(call).await?;
+
+                let simulated = SimulatedCall {
+                    contract_id: call.contract_id.map(|id| id.to_string()),
+                    function_name: call.function_name.clone(),
+                    simulated: true,
+                    return_data: result
+                        .as_ref()
+                        .ok()
+                        .map(|result| format!("0x{}", hex::encode(&result.bytes))),
+                    gas,
+                    gas_limit: call.gas,
+                    revert: result.as_ref().err().map(|revert| revert.reason.clone()),
+                };
+                (simulated, result)
+            }
+            other => {
+                let simulated = SimulatedCall {
+                    contract_id: None,
+                    function_name: other.description(),
+                    simulated: false,
+                    return_data: None,
+                    gas: None,
+                    gas_limit: 0,
+                    revert: None,
+                };
+                (simulated, Ok(function_result(ContractId::new(0, 0, 0), Vec::new())))
+            }
+        };
+
+        let mut state = self.state.lock().unwrap();
+        state.calls.push(simulated);
+        state.results.insert(transaction_id, result);
+
+        Ok(transaction_id)
+    }
+
+    async fn query(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse> {
+        self.inner.query(request).await
+    }
+
+    async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt> {
+        self.stored(transaction_id)?;
+
+        Ok(CallReceipt {
+            transaction_id: *transaction_id,
+            status: Status::Success,
+            schedule_id: None,
+        })
+    }
+
+    async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord> {
+        let result = self.stored(transaction_id)?;
+
+        Ok(CallRecord {
+            receipt: CallReceipt {
+                transaction_id: *transaction_id,
+                status: Status::Success,
+                schedule_id: None,
+            },
+            transaction_fee: Hbar::from_tinybars(0),
+            consensus_timestamp: OffsetDateTime::now_utc(),
+            contract_function_result: Some(result),
+        })
+    }
+}
+
+#[cfg(test)]
+mod simulation_tests {
+    use super::*;
+    use crate::utils::amount::U256;
+    use crate::utils::errors::ERROR_STRING_SELECTOR;
+    use crate::utils::functions::asset_lending_pool::{
+        AssetLendingPoolFunctionsInput, AssetLendingPoolFunctionsOutput, borrow,
+    };
+    use crate::utils::functions::cradle_order_book_settler::{
+        CradleOrderBookSettlerFunctionsInput, settle_order,
+    };
+    use crate::utils::functions::ContractCallInput;
+    use crate::wallet::mock_backend::RecordedCall;
+    use crate::wallet::test_fixtures::{ASSET, USER, mock_wallet};
+    use anyhow::Result;
+    use hedera::ContractFunctionParameters;
+    use num_bigint::BigUint;
+
+    #[tokio::test]
+    async fn dry_run_borrow_is_queried_not_submitted() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+
+        let mut returned = ContractFunctionParameters::new();
+        returned.add_uint256(BigUint::from(1_000_000u64));
+        returned.add_uint256(BigUint::from(250u64));
+        backend.push_return(returned.to_bytes(None));
+
+        let report = wallet
+            .simulate(
+                ContractCallInput::AssetLendingPool(AssetLendingPoolFunctionsInput::Borrow(
+                    borrow::Args {
+                        user: USER.parse()?,
+                        collateral_amount: U256::from(500u64),
+                        collateral_asset: ASSET.parse()?,
+                        contract_id: "0.0.2001".parse()?,
+                    },
+                )),
+                SimulationMode::Query,
+            )
+            .await?;
+
+        let calls = backend.calls();
+        assert_eq!(calls.len(), 1);
+        assert!(matches!(calls[0], RecordedCall::Query(_)));
+        assert_eq!(calls[0].function_name(), Some("borrow"));
+
+        assert_eq!(report.calls.len(), 1);
+        assert_eq!(report.calls[0].gas_limit, 10_000_000);
+        match report.output? {
+            ContractCallOutput::AssetLendingPool(AssetLendingPoolFunctionsOutput::Borrow(o)) => {
+                assert_eq!(o.output.expect("missing output").borrowed_amount, U256::from(250u64));
+            }
+            _ => panic!("unexpected output"),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dry_run_reports_settle_order_revert() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+
+        let mut reason = ContractFunctionParameters::new();
+        reason.add_string("order expired");
+        let mut data = ERROR_STRING_SELECTOR.to_vec();
+        data.extend(reason.to_bytes(None));
+        backend.push_revert(data);
+
+        let report = wallet
+            .simulate(
+                ContractCallInput::OrderBookSettler(
+                    CradleOrderBookSettlerFunctionsInput::SettleOrder(settle_order::Args {
+                        bidder: USER.parse()?,
+                        asker: USER.parse()?,
+                        bid_asset: ASSET.parse()?,
+                        ask_asset: ASSET.parse()?,
+                        bid_asset_amount: U256::from(10u64),
+                        ask_asset_amount: U256::from(20u64),
+                    }),
+                ),
+                SimulationMode::Query,
+            )
+            .await?;
+
+        assert!(!report.would_succeed());
+        assert_eq!(report.revert(), Some(&RevertReason::Message("order expired".to_string())));
+        assert!(backend.calls().iter().all(|call| matches!(call, RecordedCall::Query(_))));
+
+        Ok(())
+    }
+}
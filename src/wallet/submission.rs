@@ -0,0 +1,346 @@
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::utils::functions::{ContractCallInput, ContractCallOutput};
+use crate::wallet::backend::{
+    CallReceipt, CallRecord, ContractBackend, ContractCallRequest, QueryResponse,
+    TransactionRequest,
+};
+use crate::wallet::wallet::ActionWallet;
+use async_trait::async_trait;
+use futures::Stream;
+use futures::stream;
+use hedera::{Status, TransactionId};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// Progress of a submitted call, in the order a `SubmittedCall::statuses` stream yields it.
+#[derive(Debug)]
+pub enum SubmissionStatus {
+    Submitted(TransactionId),
+    /// The receipt is in, with `SUCCESS` or the status the transaction failed with.
+    ReachedConsensus(Status),
+    /// The call failed, either at consensus or while waiting for it. Always the last status.
+    Failed(CradleError),
+    /// The record, decoded by the call's wrapper. Always the last status.
+    RecordAvailable(ContractCallOutput),
+}
+
+impl SubmissionStatus {
+    pub fn is_final(&self) -> bool {
+        matches!(self, SubmissionStatus::Failed(_) | SubmissionStatus::RecordAvailable(_))
+    }
+}
+
+/// A call whose transaction has been submitted but not necessarily reached consensus, from
+/// `ActionWallet::submit`. The queries the wrapper made before submitting are kept, so decoding
+/// the record doesn't pay for them again.
+#[derive(Clone, Debug)]
+pub struct SubmittedCall {
+    wallet: ActionWallet,
+    call: ContractCallInput,
+    request: TransactionRequest,
+    transaction_id: TransactionId,
+    queries: Vec<(ContractCallRequest, QueryResponse)>,
+}
+
+enum Stage {
+    Submitted,
+    Receipt,
+    Record,
+    Failed(CradleError),
+    Done,
+}
+
+impl SubmittedCall {
+    pub fn new(
+        wallet: ActionWallet,
+        call: ContractCallInput,
+        request: TransactionRequest,
+        transaction_id: TransactionId,
+        queries: Vec<(ContractCallRequest, QueryResponse)>,
+    ) -> Self {
+        Self {
+            wallet,
+            call,
+            request,
+            transaction_id,
+            queries,
+        }
+    }
+
+    pub fn transaction_id(&self) -> TransactionId {
+        self.transaction_id
+    }
+
+    pub fn call(&self) -> &ContractCallInput {
+        &self.call
+    }
+
+    pub fn request(&self) -> &TransactionRequest {
+        &self.request
+    }
+
+    /// Waits for consensus. Failed transactions come back as errors.
+    pub async fn receipt(&self) -> CradleResult<CallReceipt> {
+        self.wallet.get_receipt(&self.transaction_id).await
+    }
+
+    /// Waits for the record and decodes it into the output the call would have returned.
+    pub async fn output(&self) -> CradleResult<ContractCallOutput> {
+        let backend = SubmittedResultBackend::new(
+            self.wallet.backend.clone(),
+            self.request.clone(),
+            self.transaction_id,
+            self.queries.clone(),
+        );
+
+        let mut wallet = self.wallet.clone();
+        wallet.backend = Arc::new(backend);
+        wallet.execute(self.call.clone()).await
+    }
+
+    /// Statuses of the call as it progresses, ending with `Failed` or `RecordAvailable`.
+    /// Nothing is queried until the stream is polled, so many calls can be followed from one
+    /// task, e.g. by merging their streams with `futures::stream::select_all`.
+    pub fn statuses(&self) -> impl Stream<Item = SubmissionStatus> + 'static {
+        stream::unfold((self.clone(), Stage::Submitted), |(call, stage)| async move {
+            let (status, next) = match stage {
+                Stage::Submitted => {
+                    (SubmissionStatus::Submitted(call.transaction_id), Stage::Receipt)
+                }
+                Stage::Receipt => match call.receipt().await {
+                    Ok(receipt) => {
+                        (SubmissionStatus::ReachedConsensus(receipt.status), Stage::Record)
+                    }
+                    Err(error) => match consensus_status(&error) {
+                        Some(status) => {
+                            (SubmissionStatus::ReachedConsensus(status), Stage::Failed(error))
+                        }
+                        None => (SubmissionStatus::Failed(error), Stage::Done),
+                    },
+                },
+                Stage::Record => match call.output().await {
+                    Ok(output) => (SubmissionStatus::RecordAvailable(output), Stage::Done),
+                    Err(error) => (SubmissionStatus::Failed(error), Stage::Done),
+                },
+                Stage::Failed(error) => (SubmissionStatus::Failed(error), Stage::Done),
+                Stage::Done => return None,
+            };
+
+            Some((status, (call, next)))
+        })
+    }
+}
+
+/// Status of a transaction that failed at consensus, `None` if it never got a receipt.
+fn consensus_status(error: &CradleError) -> Option<Status> {
+    match error {
+        CradleError::Receipt { status, .. }
+            if !matches!(status, Status::Unknown | Status::ReceiptNotFound) =>
+        {
+            Some(*status)
+        }
+        CradleError::Revert(revert) => Some(revert.status),
+        _ => None,
+    }
+}
+
+/// Backend of a submit-only wallet. The first transaction is submitted and the call stops
+/// there, without waiting for its receipt; queries before it run as usual and are kept.
+#[derive(Debug)]
+pub struct SubmitBackend {
+    inner: Arc<dyn ContractBackend>,
+    submitted: Mutex<Option<(TransactionRequest, TransactionId)>>,
+    queries: Mutex<Vec<(ContractCallRequest, QueryResponse)>>,
+}
+
+impl SubmitBackend {
+    pub fn new(inner: Arc<dyn ContractBackend>) -> Self {
+        Self {
+            inner,
+            submitted: Mutex::default(),
+            queries: Mutex::default(),
+        }
+    }
+
+    pub fn submitted(&self) -> Option<(TransactionRequest, TransactionId)> {
+        self.submitted.lock().unwrap().clone()
+    }
+
+    /// Queries made before the transaction, in order, with their responses.
+    pub fn queries(&self) -> Vec<(ContractCallRequest, QueryResponse)> {
+        self.queries.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl ContractBackend for SubmitBackend {
+    async fn execute(&self, request: &TransactionRequest) -> CradleResult<TransactionId> {
+        if self.submitted.lock().unwrap().is_some() {
+            return Err(CradleError::config(
+                "Only calls submitting a single transaction can be submitted without waiting",
+            ));
+        }
+
+        let transaction_id = self.inner.execute(request).await?;
+
+        *self.submitted.lock().unwrap() = Some((request.clone(), transaction_id));
+        Ok(transaction_id)
+    }
+
+    async fn query(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse> {
+        let response = self.inner.query(request).await?;
+        if self.submitted.lock().unwrap().is_none() {
+            self.queries.lock().unwrap().push((request.clone(), response.clone()));
+        }
+
+        Ok(response)
+    }
+
+    async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt> {
+        Err(CradleError::config(format!("{} was submitted without waiting", transaction_id)))
+    }
+
+    async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord> {
+        Err(CradleError::config(format!("{} was submitted without waiting", transaction_id)))
+    }
+}
+
+/// Backend that answers the transaction of a wrapper with the id it was submitted under, so
+/// the wrapper decodes that transaction's record into its usual output. The queries made
+/// before submitting are answered with their kept responses while the wrapper asks for them
+/// in the same order; anything else goes to the wrapped backend.
+#[derive(Debug)]
+pub struct SubmittedResultBackend {
+    inner: Arc<dyn ContractBackend>,
+    request: TransactionRequest,
+    transaction_id: TransactionId,
+    queries: Mutex<VecDeque<(ContractCallRequest, QueryResponse)>>,
+}
+
+impl SubmittedResultBackend {
+    pub fn new(
+        inner: Arc<dyn ContractBackend>,
+        request: TransactionRequest,
+        transaction_id: TransactionId,
+        queries: Vec<(ContractCallRequest, QueryResponse)>,
+    ) -> Self {
+        Self {
+            inner,
+            request,
+            transaction_id,
+            queries: Mutex::new(queries.into()),
+        }
+    }
+
+    fn kept_response(&self, request: &ContractCallRequest) -> Option<QueryResponse> {
+        let mut queries = self.queries.lock().unwrap();
+        let (kept, _) = queries.front()?;
+        if kept.contract_id != request.contract_id || kept.parameters != request.parameters {
+            queries.clear();
+            return None;
+        }
+
+        queries.pop_front().map(|(_, response)| response)
+    }
+}
+
+#[async_trait]
+impl ContractBackend for SubmittedResultBackend {
+    async fn execute(&self, request: &TransactionRequest) -> CradleResult<TransactionId> {
+        if !self.request.matches(request) {
+            return Err(CradleError::config(format!(
+                "The call no longer builds the {} submitted as {}",
+                self.request.description(),
+                self.transaction_id
+            )));
+        }
+
+        Ok(self.transaction_id)
+    }
+
+    async fn query(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse> {
+        match self.kept_response(request) {
+            Some(response) => Ok(response),
+            None => self.inner.query(request).await,
+        }
+    }
+
+    async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt> {
+        self.inner.get_receipt(transaction_id).await
+    }
+
+    async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord> {
+        self.inner.get_record(transaction_id).await
+    }
+}
+
+#[cfg(test)]
+mod submission_tests {
+    use super::*;
+    use crate::wallet::test_fixtures::{mock_wallet, settle_order};
+    use anyhow::Result;
+    use futures::StreamExt;
+    use hedera::ContractId;
+
+    #[tokio::test]
+    async fn submitted_calls_stream_their_progress() -> Result<()> {
+        let (backend, wallet) = mock_wallet();
+
+        let submitted = wallet.submit(settle_order()?).await?;
+        assert_eq!(backend.calls().len(), 1);
+
+        let statuses: Vec<SubmissionStatus> = submitted.statuses().collect().await;
+        let transaction_id = submitted.transaction_id();
+        assert!(matches!(statuses[0], SubmissionStatus::Submitted(id) if id == transaction_id));
+        assert!(matches!(statuses[1], SubmissionStatus::ReachedConsensus(Status::Success)));
+        assert!(matches!(
+            &statuses[2],
+            SubmissionStatus::RecordAvailable(ContractCallOutput::OrderBookSettler(_))
+        ));
+        assert_eq!(statuses.len(), 3);
+        assert_eq!(backend.calls().len(), 1);
+
+        backend.push_revert(Vec::new());
+        let reverted = wallet.submit(settle_order()?).await?;
+        let statuses: Vec<SubmissionStatus> = reverted.statuses().collect().await;
+        assert!(matches!(
+            statuses[1],
+            SubmissionStatus::ReachedConsensus(Status::ContractRevertExecuted)
+        ));
+        assert!(matches!(statuses[2], SubmissionStatus::Failed(CradleError::Revert(_))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn queries_before_submitting_are_paid_for_once() -> Result<()> {
+        let (backend, _) = mock_wallet();
+
+        let mut query = ContractCallRequest::new();
+        query.contract_id(ContractId::new(0, 0, 1001)).function("getLevel");
+        let mut call = ContractCallRequest::new();
+        call.contract_id(ContractId::new(0, 0, 1001)).function("clearLevel");
+        let request = TransactionRequest::ContractExecute(call);
+
+        let submitter = SubmitBackend::new(backend.clone());
+        submitter.query(&query).await?;
+        let transaction_id = submitter.execute(&request).await?;
+        assert_eq!(submitter.queries().len(), 1);
+        assert_eq!(backend.calls().len(), 2);
+
+        let result = SubmittedResultBackend::new(
+            backend.clone(),
+            request.clone(),
+            transaction_id,
+            submitter.queries(),
+        );
+        result.query(&query).await?;
+        assert_eq!(result.execute(&request).await?, transaction_id);
+        assert_eq!(backend.calls().len(), 2);
+
+        result.query(&query).await?;
+        assert_eq!(backend.calls().len(), 3);
+
+        Ok(())
+    }
+}
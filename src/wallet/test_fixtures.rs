@@ -0,0 +1,51 @@
+use crate::utils::amount::U256;
+use crate::utils::functions::ContractCallInput;
+use crate::utils::functions::cradle_order_book_settler::{
+    CradleOrderBookSettlerFunctionsInput, settle_order,
+};
+use crate::wallet::contracts::CradleContractIds;
+use crate::wallet::mock_backend::MockBackend;
+use crate::wallet::network::CradleNetwork;
+use crate::wallet::wallet::ActionWallet;
+use anyhow::Result;
+use hedera::ContractId;
+use std::sync::Arc;
+
+pub const USER: &str = "0x0000000000000000000000000000000000000457";
+pub const ASSET: &str = "0x0000000000000000000000000000000000000458";
+
+pub fn contract_ids() -> CradleContractIds {
+    CradleContractIds {
+        access_controller_contract_id: ContractId::new(0, 0, 1001),
+        bridged_asset_issuer_contract_id: ContractId::new(0, 0, 1002),
+        native_asset_issuer_contract_id: ContractId::new(0, 0, 1003),
+        cradle_account_factory_contract_id: ContractId::new(0, 0, 1004),
+        asset_factory: ContractId::new(0, 0, 1005),
+        cradle_order_book_settler_contract_id: ContractId::new(0, 0, 1006),
+        asset_lending_pool_factory: ContractId::new(0, 0, 1007),
+        cradle_listing_factory_contract_id: ContractId::new(0, 0, 1008),
+    }
+}
+
+/// A wallet of operator `0.0.2` on a mock backend, with the ids of `contract_ids`.
+pub fn mock_wallet() -> (Arc<MockBackend>, ActionWallet) {
+    let backend = Arc::new(MockBackend::new());
+    let wallet =
+        ActionWallet::with_backend("0.0.2".to_string(), CradleNetwork::Testnet, backend.clone())
+            .with_contract_ids(contract_ids());
+
+    (backend, wallet)
+}
+
+pub fn settle_order() -> Result<ContractCallInput> {
+    Ok(ContractCallInput::OrderBookSettler(
+        CradleOrderBookSettlerFunctionsInput::SettleOrder(settle_order::Args {
+            bidder: USER.parse()?,
+            asker: USER.parse()?,
+            bid_asset: ASSET.parse()?,
+            ask_asset: ASSET.parse()?,
+            bid_asset_amount: U256::from(10u64),
+            ask_asset_amount: U256::from(20u64),
+        }),
+    ))
+}
@@ -1,73 +1,834 @@
+use std::collections::HashMap;
+use std::env;
+use std::future::Future;
 use std::iter;
+use std::path::PathBuf;
 use std::str::FromStr;
-use hedera::{AccountId, Client, PrivateKey};
-use anyhow::{anyhow, Result};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use hedera::{AccountId, ContractId, ScheduleId, Status, TransactionId};
+use anyhow::anyhow;
+use crate::utils::address::CradleAddress;
+use crate::utils::amount::{TokenAmount, U256};
 use crate::utils::functions::{ContractCallInput, ContractCallOutput};
-use crate::utils::functions::commons::ContractFunctionProcessor;
-use crate::utils::functions::cradle_account::CradleAccountFunctionInput;
+use crate::utils::errors::{CradleError, CradleResult};
+use crate::utils::functions::commons::{
+    ContractFunctionProcessor, get_contract_id_from_evm_address,
+};
+use crate::utils::functions::dynamic::DynamicCallArgs;
+use crate::utils::functions::FunctionCallOutput;
+use crate::utils::mirror::{MirrorCallRequest, MirrorClient, ScheduleInfo};
+use crate::utils::poll::{PollOptions, poll_until};
+use crate::wallet::batch::{BatchItem, BatchOptions, BatchOutcome, execute_batch};
+use crate::wallet::backend::{
+    CallReceipt, CallRecord, ContractBackend, ContractCallRequest, HederaBackend, QueryResponse,
+    TransactionRequest,
+};
+use crate::wallet::contract_id_cache::ContractIdCache;
 use crate::wallet::contracts::CradleContractIds;
+use crate::wallet::gas::GasPolicy;
+use crate::wallet::journal::{Journal, JournalBackend, JournalEntry, JournalStatus, reconcile};
+use crate::wallet::network::CradleNetwork;
+use crate::wallet::offline::{FreezeBackend, FreezeOptions, OfflineTransaction, SignedBackend};
+use crate::wallet::policy::CallPolicy;
+use crate::wallet::profile::{DEFAULT_PROFILES_FILE, NetworkProfile};
+use crate::wallet::schedule::{
+    PendingSchedule, ScheduleBackend, ScheduleOptions, ScheduledResultBackend,
+};
+use crate::wallet::signer::{InMemorySigner, Signer, SignerArgs};
+use crate::wallet::simulation::{
+    SimulatedCall, SimulationBackend, SimulationMode, SimulationReport,
+};
+use crate::wallet::submission::{SubmitBackend, SubmittedCall};
 use clap::Parser;
+use serde_json::Value;
+use tokio::time;
+use zeroize::Zeroizing;
 
-#[derive(Parser)]
+/// Command line and environment choice of operator: a profile when one is named, otherwise the
+/// operator account, signer and network.
+#[derive(Parser, Clone, Debug)]
 pub struct ActionWalletArgs {
+    /// Profile to load instead of the operator flags.
+    #[clap(long, env = "CRADLE_PROFILE")]
+    profile: Option<String>,
+    #[clap(long, env = "CRADLE_PROFILES_FILE", default_value = DEFAULT_PROFILES_FILE)]
+    profiles_file: PathBuf,
     #[clap(long, env)]
-    operator_account_id: String,
+    operator_account_id: Option<String>,
+    #[clap(flatten)]
+    signer: SignerArgs,
     #[clap(long, env)]
-    operator_key: String,
-    #[clap(long, env)]
-    network: String
+    network: Option<String>,
 }
 
 #[derive(Clone, Debug)]
 pub struct ActionWallet {
     pub account_id: String,
-    private_key: String,
-    pub network: String,
-    pub client: Client
+    pub network: CradleNetwork,
+    pub backend: Arc<dyn ContractBackend>,
+    contract_ids: Option<CradleContractIds>,
+    mirror_node_url: Option<String>,
+    contract_id_cache: Arc<ContractIdCache>,
+    simulation: Option<Arc<SimulationBackend>>,
+    signer: Option<Arc<dyn Signer>>,
+    call_policy: CallPolicy,
+    gas_policy: GasPolicy,
+    gas_used: Arc<Mutex<HashMap<String, u64>>>,
+    /// Function name and gas limit of each execute whose record hasn't been read yet.
+    submitted_functions: Arc<Mutex<HashMap<TransactionId, (String, u64)>>>,
+    token_decimals: Arc<Mutex<HashMap<CradleAddress, u8>>>,
 }
 
 impl ActionWallet {
 
-    pub fn new(account_id: String, key: String, network: String) -> Self {
-        let operator_account_id = AccountId::from_str(&account_id).unwrap();
-        let operator_key = PrivateKey::from_str(&key).unwrap();
+    pub fn new(account_id: String, key: String, network: String) -> CradleResult<Self> {
+        Self::for_network(account_id, key, network.parse()?)
+    }
+
+    /// Wallet whose consensus client and mirror node both come from `network`. The key string
+    /// is wiped once parsed.
+    pub fn for_network(account_id: String, key: String, network: CradleNetwork) -> CradleResult<Self> {
+        let signer: InMemorySigner = Zeroizing::new(key).parse()?;
+
+        Self::with_signer(account_id, Arc::new(signer), network)
+    }
+
+    /// Wallet signing through `signer`, such as a keystore or an external signer process.
+    pub fn with_signer(
+        account_id: String,
+        signer: Arc<dyn Signer>,
+        network: CradleNetwork,
+    ) -> CradleResult<Self> {
+        Self::connect(account_id, signer, network, CallPolicy::default())
+    }
+
+    fn connect(
+        account_id: String,
+        signer: Arc<dyn Signer>,
+        network: CradleNetwork,
+        policy: CallPolicy,
+    ) -> CradleResult<Self> {
+        let operator_account_id = AccountId::from_str(&account_id).map_err(|e| {
+            CradleError::config(format!("Invalid operator account id {}: {}", account_id, e))
+        })?;
+
+        let client = network.client()?;
+        client.set_request_timeout(Some(policy.execute_timeout));
+        client.set_max_backoff(policy.max_backoff);
+
+        let backend = HederaBackend::new(client)
+            .with_policy(policy.clone())
+            .with_operator(operator_account_id, signer.clone());
+        let mut wallet = Self::with_backend(account_id, network, Arc::new(backend))
+            .with_call_policy(policy);
+        wallet.signer = Some(signer);
+
+        wallet.with_env_contract_ids().with_default_contract_id_cache()
+    }
+
+    /// Wallet for an operator whose key is kept elsewhere, such as on an air-gapped machine. It
+    /// can `freeze` transactions for the operator; anything the node charges for fails.
+    pub fn offline(account_id: String, network: CradleNetwork) -> CradleResult<Self> {
+        let backend = HederaBackend::new(network.client()?);
+
+        Self::with_backend(account_id, network, Arc::new(backend))
+            .with_env_contract_ids()
+            .with_default_contract_id_cache()
+    }
+
+    /// Contract ids from the *_CONTRACT_ID variables, for deployments configured through them.
+    fn with_env_contract_ids(self) -> Self {
+        match CradleContractIds::from_env() {
+            Ok(contract_ids) => self.with_contract_ids(contract_ids),
+            Err(_) => self,
+        }
+    }
+
+    /// Wallet for a network profile. Contract ids set in the environment still take precedence
+    /// over the profile's.
+    pub fn from_profile(profile: &NetworkProfile) -> CradleResult<Self> {
+        let (account_id, signer) = profile.operator_signer()?;
+        let wallet =
+            Self::connect(account_id, signer, profile.network.clone(), profile.policy.clone())?;
+
+        let wallet = wallet
+            .with_contract_ids(profile.contracts.clone().with_env_overrides()?)
+            .with_gas_policy(profile.gas.clone());
 
-        let client = Client::for_name(&network).unwrap();
-        client.set_operator(operator_account_id.clone(), operator_key.clone());
+        match &profile.mirror_node_url {
+            Some(url) => wallet
+                .with_mirror_node_url(Some(url.clone()))
+                .with_default_contract_id_cache(),
+            None => Ok(wallet),
+        }
+    }
 
+    pub fn with_backend(account_id: String, network: CradleNetwork, backend: Arc<dyn ContractBackend>) -> Self {
         Self {
             account_id,
-            private_key: key,
             network,
-            client
+            backend,
+            contract_ids: None,
+            mirror_node_url: None,
+            contract_id_cache: Arc::default(),
+            simulation: None,
+            signer: None,
+            call_policy: CallPolicy::default(),
+            gas_policy: GasPolicy::default(),
+            gas_used: Arc::default(),
+            submitted_functions: Arc::default(),
+            token_decimals: Arc::default(),
+        }
+    }
+
+    pub fn with_contract_ids(mut self, contract_ids: CradleContractIds) -> Self {
+        self.contract_ids = Some(contract_ids);
+        self
+    }
+
+    pub fn with_mirror_node_url(mut self, mirror_node_url: Option<String>) -> Self {
+        self.mirror_node_url = mirror_node_url;
+        self
+    }
+
+    /// Base url of the mirror node rest api, the network's own unless overridden.
+    pub fn mirror_node_url(&self) -> String {
+        self.mirror_node_url.clone().unwrap_or_else(|| self.network.mirror_node_url())
+    }
+
+    pub fn mirror(&self) -> MirrorClient {
+        MirrorClient::new(self.mirror_node_url())
+    }
+
+    pub fn with_contract_id_cache(mut self, cache: ContractIdCache) -> Self {
+        self.contract_id_cache = Arc::new(cache);
+        self
+    }
+
+    /// Cache file from `CRADLE_CONTRACT_ID_CACHE`, scoped to this wallet's mirror node. Without
+    /// it resolved ids are only kept in memory.
+    fn with_default_contract_id_cache(self) -> CradleResult<Self> {
+        let Ok(path) = env::var("CRADLE_CONTRACT_ID_CACHE") else {
+            return Ok(self);
+        };
+        let cache = ContractIdCache::load(path, &self.mirror_node_url())?;
+
+        Ok(self.with_contract_id_cache(cache))
+    }
+
+    /// Contract id behind an evm address, such as a pool returned by the factory. Waits for
+    /// the mirror node to index new contracts; known and long-zero addresses need no lookup.
+    pub async fn resolve_contract_id(&self, address: &CradleAddress) -> CradleResult<ContractId> {
+        if let Some(contract_id) = self.contract_id_cache.get(address) {
+            return Ok(contract_id);
+        }
+
+        let contract_id =
+            get_contract_id_from_evm_address(&self.mirror_node_url(), &address.to_evm_address())
+                .await?;
+        self.contract_id_cache.insert(address, contract_id)?;
+
+        Ok(contract_id)
+    }
+
+
+    /// Loads the profile named by `CRADLE_PROFILE` from `CRADLE_PROFILES_FILE` (`cradle.toml` by
+    /// default) when set, otherwise takes the operator from the environment. The process
+    /// arguments are never read, so binaries parsing their own can call it.
+    pub fn from_env() -> CradleResult<Self> {
+        let args = ActionWalletArgs::try_parse_from(iter::empty::<String>())
+            .map_err(|e| CradleError::config(e.to_string()))?;
+
+        Self::from_args(args)
+    }
+
+    /// Wallet for operator flags already parsed, e.g. flattened into a binary's own arguments.
+    pub fn from_args(args: ActionWalletArgs) -> CradleResult<Self> {
+        if let Some(name) = &args.profile {
+            return Self::from_profile(&NetworkProfile::load(&args.profiles_file, name)?);
+        }
+
+        let account_id = args.operator_account_id.ok_or_else(|| {
+            CradleError::config("Set OPERATOR_ACCOUNT_ID or CRADLE_PROFILE")
+        })?;
+        let network = args
+            .network
+            .ok_or_else(|| CradleError::config("Set NETWORK or CRADLE_PROFILE"))?;
+
+        Self::with_signer(account_id, args.signer.signer()?, network.parse()?)
+    }
+
+    /// Signer of the operator account. Wallets built on a custom backend have none.
+    pub fn signer(&self) -> Option<&Arc<dyn Signer>> {
+        self.signer.as_ref()
+    }
+
+    pub fn get_contract_ids(&self) -> CradleResult<CradleContractIds> {
+        self.contract_ids.clone().ok_or_else(|| {
+            CradleError::config("No contract ids, load a profile or set the contract id variables")
+        })
+    }
+
+    /// Executes with the gas limit picked by the wallet's `GasPolicy`.
+    pub async fn execute_contract(&self, request: &ContractCallRequest) -> CradleResult<TransactionId> {
+        let mut request = request.clone();
+        request.gas = self.gas_limit(&request).await;
+
+        let transaction = TransactionRequest::ContractExecute(request.clone());
+        let transaction_id = self
+            .with_retries(
+                "contract execute",
+                self.call_policy.execute_timeout,
+                CallPolicy::retries_transaction,
+                || self.backend.execute(&transaction),
+            )
+            .await?;
+        self.submitted_functions
+            .lock()
+            .unwrap()
+            .insert(transaction_id, (request.function_name, request.gas));
+
+        Ok(transaction_id)
+    }
+
+    pub async fn execute_transaction(&self, request: &TransactionRequest) -> CradleResult<TransactionId> {
+        match request {
+            TransactionRequest::ContractExecute(call) => self.execute_contract(call).await,
+            _ => {
+                self.with_retries(
+                    "transaction",
+                    self.call_policy.execute_timeout,
+                    CallPolicy::retries_transaction,
+                    || self.backend.execute(request),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn query_contract(&self, request: &ContractCallRequest) -> CradleResult<QueryResponse> {
+        let mut request = request.clone();
+        request.gas = self.gas_limit(&request).await;
+        if request.payment_amount.is_none() {
+            request.payment_amount = self.gas_policy.query_payment();
+        }
+
+        let response = self
+            .with_retries(
+                "contract query",
+                self.call_policy.query_timeout,
+                CallPolicy::retries_query,
+                || self.backend.query(&request),
+            )
+            .await
+            .inspect_err(|e| self.observe_failure(&request.function_name, request.gas, e))?;
+        self.observe_gas(&request.function_name, response.result.gas_used);
+
+        Ok(response)
+    }
+
+    /// Ends the tracking of the transaction either way, as callers reading only receipts never
+    /// ask for its record.
+    pub async fn get_receipt(&self, transaction_id: &TransactionId) -> CradleResult<CallReceipt> {
+        let receipt = self
+            .with_retries(
+                "receipt",
+                self.call_policy.receipt_timeout,
+                CallPolicy::retries_query,
+                || self.backend.get_receipt(transaction_id),
+            )
+            .await;
+
+        if let (Some((function_name, gas_limit)), Err(e)) =
+            (self.take_submitted(transaction_id), &receipt)
+        {
+            self.observe_failure(&function_name, gas_limit, e);
+        }
+
+        receipt
+    }
+
+    pub async fn get_record(&self, transaction_id: &TransactionId) -> CradleResult<CallRecord> {
+        let record = self
+            .with_retries(
+                "record",
+                self.call_policy.receipt_timeout,
+                CallPolicy::retries_query,
+                || self.backend.get_record(transaction_id),
+            )
+            .await;
+
+        if let Some((function_name, gas_limit)) = self.take_submitted(transaction_id) {
+            match &record {
+                Ok(record) => {
+                    if let Some(result) = &record.contract_function_result {
+                        self.observe_gas(&function_name, result.gas_used);
+                    }
+                }
+                Err(e) => self.observe_failure(&function_name, gas_limit, e),
+            }
+        }
+
+        record
+    }
+
+    /// Retries `call` while `retry` allows it, each attempt bounded by `timeout`.
+    async fn with_retries<T, F, Fut>(
+        &self,
+        what: &str,
+        timeout: Duration,
+        retry: fn(&CradleError) -> bool,
+        mut call: F,
+    ) -> CradleResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = CradleResult<T>>,
+    {
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+
+            let result = match time::timeout(timeout, call()).await {
+                Ok(result) => result,
+                Err(_) => Err(CradleError::Timeout(format!("{} after {:?}", what, timeout))),
+            };
+
+            match result {
+                Err(error) if attempt < self.call_policy.max_attempts && retry(&error) => {
+                    time::sleep(self.call_policy.backoff(attempt)).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Policy for every call made through this wallet. Clone the wallet to change it for one
+    /// call only, or use `execute_with_policy`.
+    pub fn with_call_policy(mut self, call_policy: CallPolicy) -> Self {
+        self.call_policy = call_policy;
+        self
+    }
+
+    pub fn call_policy(&self) -> &CallPolicy {
+        &self.call_policy
+    }
+
+    pub fn with_gas_policy(mut self, gas_policy: GasPolicy) -> Self {
+        self.gas_policy = gas_policy;
+        self
+    }
+
+    pub fn gas_policy(&self) -> &GasPolicy {
+        &self.gas_policy
+    }
+
+    /// Most gas each function has used so far, keyed by function name. Can be saved and handed
+    /// to `with_gas_usage` so a new process doesn't start from the wrappers' defaults.
+    pub fn gas_usage(&self) -> HashMap<String, u64> {
+        self.gas_used.lock().unwrap().clone()
+    }
+
+    pub fn with_gas_usage(self, gas_usage: HashMap<String, u64>) -> Self {
+        self.gas_used.lock().unwrap().extend(gas_usage);
+        self
+    }
+
+    /// Fixed limit, then the gas used before, then the mirror node estimate, then the limit the
+    /// wrapper asked for.
+    async fn gas_limit(&self, request: &ContractCallRequest) -> u64 {
+        let function_name = request.function_name.as_str();
+        if let Some(gas) = self.gas_policy.fixed_gas(function_name) {
+            return gas;
         }
+
+        let used = self.gas_used.lock().unwrap().get(function_name).copied();
+        if let Some(used) = used {
+            return self.gas_policy.with_margin(function_name, used);
+        }
+
+        if self.gas_policy.mirror_estimate {
+            if let Ok(estimate) = self.estimate_gas(request).await {
+                return self.gas_policy.with_margin(function_name, estimate);
+            }
+        }
+
+        self.gas_policy.clamp(function_name, request.gas)
+    }
+
+    async fn estimate_gas(&self, request: &ContractCallRequest) -> CradleResult<u64> {
+        let request = MirrorCallRequest::for_call(request, self.operator_account_id()?)?;
+        self.mirror().estimate_gas(&request).await
+    }
+
+    /// Results without gas information report zero, which says nothing about the function.
+    fn observe_gas(&self, function_name: &str, gas_used: u64) {
+        if function_name.is_empty() || gas_used == 0 {
+            return;
+        }
+
+        let mut gas_used_by_function = self.gas_used.lock().unwrap();
+        let most_used = gas_used_by_function.entry(function_name.to_string()).or_default();
+        *most_used = (*most_used).max(gas_used);
+    }
+
+    /// A call that ran out of gas needed at least its whole limit, so the next one gets the
+    /// limit plus the policy's margin instead of failing the same way.
+    fn observe_failure(&self, function_name: &str, gas_limit: u64, error: &CradleError) {
+        if error.status() == Some(Status::InsufficientGas) {
+            self.observe_gas(function_name, gas_limit);
+        }
+    }
+
+    /// Function name and gas limit of a transaction executed by this wallet, forgotten once read.
+    fn take_submitted(&self, transaction_id: &TransactionId) -> Option<(String, u64)> {
+        self.submitted_functions.lock().unwrap().remove(transaction_id)
+    }
+
+    fn operator_account_id(&self) -> CradleResult<AccountId> {
+        AccountId::from_str(&self.account_id).map_err(|e| {
+            CradleError::config(format!("Invalid operator account id {}: {}", self.account_id, e))
+        })
+    }
+
+
+    /// Executes with `policy` in place of the wallet's own, e.g. tighter timeouts for settlement.
+    pub async fn execute_with_policy(
+        &self,
+        args: ContractCallInput,
+        policy: CallPolicy,
+    ) -> CradleResult<ContractCallOutput> {
+        let mut wallet = self.clone().with_call_policy(policy);
+        wallet.execute(args).await
+    }
+
+    pub async fn execute(&mut self, args: ContractCallInput)->CradleResult<ContractCallOutput> {
+        let mut wallet_clone = self.clone();
+        let output = args.process(&mut wallet_clone).await?;
+        Ok(output)
+    }
+
+    /// Runs many calls concurrently; see `batch::execute_batch`.
+    pub async fn execute_batch(
+        &self,
+        items: Vec<BatchItem>,
+        options: &BatchOptions,
+    ) -> CradleResult<Vec<BatchOutcome>> {
+        execute_batch(self, items, options).await
+    }
+
+    /// Submits the transaction of `args` and returns without waiting for its receipt. Follow it
+    /// with `SubmittedCall::statuses`, or wait with `receipt` and `output`. Calls submitting more
+    /// than one transaction can't be submitted this way.
+    pub async fn submit(&self, args: ContractCallInput) -> CradleResult<SubmittedCall> {
+        let submitter = Arc::new(SubmitBackend::new(self.backend.clone()));
+
+        let mut wallet = self.clone();
+        wallet.backend = submitter.clone() as Arc<dyn ContractBackend>;
+
+        // The wrapper stops with an error once its transaction is submitted
+        let result = wallet.execute(args.clone()).await;
+
+        match (submitter.submitted(), result) {
+            (Some((request, transaction_id)), _) => Ok(SubmittedCall::new(
+                self.clone(),
+                args,
+                request,
+                transaction_id,
+                submitter.queries(),
+            )),
+            (None, Err(e)) => Err(e),
+            (None, Ok(_)) => Err(CradleError::config("The call submits no transaction")),
+        }
+    }
+
+    /// Executes `args` at most once per `key`, journaling each transaction before it is
+    /// submitted; see `JournalBackend`. The decoded output is journaled once the call succeeds,
+    /// so running the key again answers with it instead of submitting the call twice, even after
+    /// the nodes no longer keep the record.
+    pub async fn execute_once(
+        &self,
+        journal: &Arc<Journal>,
+        key: &str,
+        args: ContractCallInput,
+    ) -> CradleResult<ContractCallOutput> {
+        let call = serde_json::to_value(&args)
+            .map_err(|e| CradleError::decoding(format!("Failed to encode the call: {}", e)))?;
+        if let Some(entry) = journal.completed(key) {
+            if entry.call.as_ref() != Some(&call) {
+                return Err(CradleError::config(format!("Key {} was used for another call", key)));
+            }
+
+            return serde_json::from_value(entry.output.unwrap_or_default()).map_err(|e| {
+                CradleError::decoding(format!("Invalid journaled output of {}: {}", key, e))
+            });
+        }
+
+        let backend = JournalBackend::new(self.backend.clone(), journal.clone(), self.mirror(), key);
+
+        let mut wallet = self.clone();
+        wallet.backend = Arc::new(backend) as Arc<dyn ContractBackend>;
+        let output = wallet.execute(args).await?;
+
+        let encoded = serde_json::to_value(&output)
+            .map_err(|e| CradleError::decoding(format!("Failed to encode the output: {}", e)))?;
+        journal.complete(key, call, encoded)?;
+
+        Ok(output)
+    }
+
+    /// Settles the pending entries of `journal` from receipts and the mirror node, e.g. on start
+    /// up after a crash. Returns the entries whose outcome still isn't known.
+    pub async fn reconcile_journal(&self, journal: &Journal) -> CradleResult<Vec<JournalEntry>> {
+        let mirror = self.mirror();
+        let mut pending = Vec::new();
+        for entry in journal.pending() {
+            let entry = reconcile(&*self.backend, &mirror, journal, entry).await?;
+            if entry.status == JournalStatus::Pending {
+                pending.push(entry);
+            }
+        }
+
+        Ok(pending)
+    }
+
+    /// A copy of the wallet that runs state changing calls without submitting them. Outputs are
+    /// decoded from the simulated return values; see `simulated_calls` for gas and reverts.
+    pub fn dry_run(&self, mode: SimulationMode) -> CradleResult<Self> {
+        let simulation = Arc::new(SimulationBackend::new(
+            self.backend.clone(),
+            mode,
+            self.mirror(),
+            self.operator_account_id()?,
+        ));
+
+        let mut wallet = self.clone();
+        wallet.backend = simulation.clone() as Arc<dyn ContractBackend>;
+        wallet.simulation = Some(simulation);
+        Ok(wallet)
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.simulation.is_some()
+    }
+
+    /// Calls simulated so far by a `dry_run` wallet.
+    pub fn simulated_calls(&self) -> Vec<SimulatedCall> {
+        self.simulation
+            .as_ref()
+            .map(|simulation| simulation.calls())
+            .unwrap_or_default()
+    }
+
+    /// Runs `args` as a dry run and reports the would-be output, gas and revert reason.
+    pub async fn simulate(&self, args: ContractCallInput, mode: SimulationMode) -> CradleResult<SimulationReport> {
+        let mut wallet = self.dry_run(mode)?;
+        let output = wallet.execute(args).await;
+
+        Ok(SimulationReport {
+            output,
+            calls: wallet.simulated_calls(),
+        })
+    }
+
+    /// Builds and freezes the transaction `args` would submit, for signing elsewhere with
+    /// `OfflineTransaction::sign`. Queries the call needs first are made as usual; a call that
+    /// submits more than one transaction can't be frozen.
+    pub async fn freeze(
+        &self,
+        args: ContractCallInput,
+        options: FreezeOptions,
+    ) -> CradleResult<OfflineTransaction> {
+        let required_signatures = options.required_signatures.clone();
+        let freezer = Arc::new(FreezeBackend::new(
+            self.backend.clone(),
+            self.network.client()?,
+            self.operator_account_id()?,
+            options,
+        ));
+
+        let mut wallet = self.clone();
+        wallet.backend = freezer.clone() as Arc<dyn ContractBackend>;
+
+        // The wrapper stops with an error once its transaction is frozen
+        let result = wallet.execute(args.clone()).await;
+
+        match (freezer.frozen(), result) {
+            (Some((request, transaction_id, bytes)), _) => {
+                let mut offline = OfflineTransaction::new(
+                    self.network.clone(),
+                    args,
+                    &request,
+                    transaction_id,
+                    &bytes,
+                );
+                offline.required_signatures = required_signatures;
+                Ok(offline)
+            }
+            (None, Err(e)) => Err(e),
+            (None, Ok(_)) => Err(CradleError::config("The call submits no transaction to freeze")),
+        }
+    }
+
+    /// Submits a signed offline transaction and decodes its result like `execute` would. Fails
+    /// while required signatures are missing.
+    pub async fn submit_signed(&self, offline: &OfflineTransaction) -> CradleResult<ContractCallOutput> {
+        if offline.network != self.network {
+            return Err(CradleError::config(format!(
+                "Transaction was frozen for {}, not {}",
+                offline.network, self.network
+            )));
+        }
+        offline.verify()?;
+        let missing = offline.missing_signatures()?;
+        if missing > 0 {
+            return Err(CradleError::config(format!("{} more signatures are needed", missing)));
+        }
+
+        let signed =
+            SignedBackend::new(self.backend.clone(), self.network.client()?, offline.clone());
+
+        let mut wallet = self.clone();
+        wallet.backend = Arc::new(signed);
+        wallet.execute(offline.call.clone()).await
+    }
+
+    /// Submits the transaction `args` would make as a pending schedule, e.g. an admin change
+    /// other signers approve with `sign_schedule`. Queries the call needs first are made as
+    /// usual; a call that submits more than one transaction can't be scheduled.
+    pub async fn schedule(
+        &self,
+        args: ContractCallInput,
+        options: ScheduleOptions,
+    ) -> CradleResult<PendingSchedule> {
+        let scheduler = Arc::new(ScheduleBackend::new(self.backend.clone(), options));
+
+        let mut wallet = self.clone();
+        wallet.backend = scheduler.clone() as Arc<dyn ContractBackend>;
+
+        // The wrapper stops with an error once its transaction is scheduled
+        let result = wallet.execute(args.clone()).await;
+
+        let (request, transaction_id) = match (scheduler.created(), result) {
+            (Some(created), _) => created,
+            (None, Err(e)) => return Err(e),
+            (None, Ok(_)) => {
+                return Err(CradleError::config("The call submits no transaction to schedule"));
+            }
+        };
+
+        let receipt = self.get_receipt(&transaction_id).await?;
+        let schedule_id = receipt
+            .schedule_id
+            .ok_or_else(|| CradleError::decoding("Schedule id missing from receipt"))?;
+        let scheduled_transaction_id = TransactionId {
+            scheduled: true,
+            ..transaction_id
+        };
+
+        Ok(PendingSchedule {
+            schedule_id: schedule_id.to_string(),
+            scheduled_transaction_id: scheduled_transaction_id.to_string(),
+            contract_id: request.contract_id.map(|id| id.to_string()).unwrap_or_default(),
+            function_name: request.function_name,
+            parameters: hex::encode(&request.parameters),
+            call: args,
+        })
+    }
+
+    /// Adds the operator's signature to a schedule. It runs once the signatures are complete,
+    /// unless it waits for its expiry.
+    pub async fn sign_schedule(&self, schedule_id: ScheduleId) -> CradleResult<CallReceipt> {
+        let transaction_id = self
+            .execute_transaction(&TransactionRequest::ScheduleSign { schedule_id })
+            .await?;
+        self.get_receipt(&transaction_id).await
+    }
+
+    /// Deletes a schedule before it runs. Needs the schedule's admin key.
+    pub async fn delete_schedule(&self, schedule_id: ScheduleId) -> CradleResult<CallReceipt> {
+        let transaction_id = self
+            .execute_transaction(&TransactionRequest::ScheduleDelete { schedule_id })
+            .await?;
+        self.get_receipt(&transaction_id).await
+    }
+
+    /// State and signatures of a schedule, from the mirror node.
+    pub async fn schedule_info(&self, schedule_id: ScheduleId) -> CradleResult<ScheduleInfo> {
+        self.mirror().schedule(schedule_id).await
+    }
+
+    /// Output of a scheduled call, or `None` while it hasn't run. Deleted schedules are an error.
+    pub async fn schedule_result(&self, schedule: &PendingSchedule) -> CradleResult<Option<ContractCallOutput>> {
+        let info = self.schedule_info(schedule.schedule_id()?).await?;
+        if info.deleted {
+            return Err(CradleError::config(format!("{} was deleted", schedule.schedule_id)));
+        }
+        if !info.is_executed() {
+            return Ok(None);
+        }
+
+        self.decode_schedule(schedule).await.map(Some)
     }
-    
-    
-    pub fn from_env()->Self {
-        #[cfg(test)]
-        let args = ActionWalletArgs::parse_from(iter::empty::<String>());
 
-        #[cfg(not(test))]
-        let args = ActionWalletArgs::parse();
-        
-        Self::new(args.operator_account_id, args.operator_key, args.network)
+    /// Waits for a scheduled call to run and decodes its output.
+    pub async fn wait_for_schedule(
+        &self,
+        schedule: &PendingSchedule,
+        options: &PollOptions,
+    ) -> CradleResult<ContractCallOutput> {
+        let what = format!("{} to execute", schedule.schedule_id);
+        poll_until(&what, options, || self.schedule_result(schedule)).await
+    }
+
+    /// Decodes the record of an executed schedule into the output of its call.
+    pub async fn decode_schedule(&self, schedule: &PendingSchedule) -> CradleResult<ContractCallOutput> {
+        let backend = ScheduledResultBackend::new(self.backend.clone(), schedule.clone());
+
+        let mut wallet = self.clone();
+        wallet.backend = Arc::new(backend);
+        wallet.execute(schedule.call.clone()).await
     }
 
-    pub fn get_contract_ids(&self) -> Result<CradleContractIds> {
-        #[cfg(test)]
-        let ids = CradleContractIds::parse_from(iter::empty::<String>());
+    /// Decimals of an HTS or ERC-20 token. HTS tokens answer the ERC-20 `decimals()` call
+    /// through their token facade, so both are read with a query. Looked up once and shared by
+    /// clones of the wallet.
+    pub async fn token_decimals(&self, token: &CradleAddress) -> CradleResult<u8> {
+        if let Some(decimals) = self.token_decimals.lock().unwrap().get(token) {
+            return Ok(*decimals);
+        }
+
+        let mut query = ContractCallRequest::new();
+        query.contract_id(token.to_contract_id()?);
+        query.gas(100_000);
+        query.function("decimals");
+
+        let response = self.query_contract(&query).await?;
+        let decimals = response
+            .result
+            .get_u8(0)
+            .ok_or_else(|| CradleError::decoding(format!("Failed to get decimals of {}", token)))?;
 
-        #[cfg(not(test))]
-        let ids = CradleContractIds::parse();
+        self.token_decimals.lock().unwrap().insert(*token, decimals);
+        Ok(decimals)
+    }
 
-        Ok(ids)
+    /// Parses a human amount such as `12.5` in units of `token`.
+    pub async fn token_amount(&self, token: &CradleAddress, value: &str) -> CradleResult<TokenAmount> {
+        TokenAmount::from_human(value, self.token_decimals(token).await?)
     }
 
+    /// Attaches the decimals of `token` to a raw amount for display.
+    pub async fn format_amount(&self, token: &CradleAddress, raw: &U256) -> CradleResult<TokenAmount> {
+        Ok(TokenAmount::new(raw.clone(), self.token_decimals(token).await?))
+    }
 
-    pub async fn execute(&mut self, args: ContractCallInput)->Result<ContractCallOutput> {
+    /// Calls a function by name from the artifact abi, with json arguments. Return values are
+    /// keyed by their abi output name.
+    pub async fn call_dynamic(&mut self, args: DynamicCallArgs) -> CradleResult<FunctionCallOutput<Value>> {
         let mut wallet_clone = self.clone();
         let output = args.process(&mut wallet_clone).await?;
         Ok(output)
     }
-}
\ No newline at end of file
+}